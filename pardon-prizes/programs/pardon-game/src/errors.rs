@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Unauthorized: Only the game admin or judge can call this function")]
+    Unauthorized,
+    #[msg("Invalid program data: Not this program's upgradeable program data")]
+    InvalidProgramData,
+    #[msg("Pool not run by the game: Its poster role must be the game authority PDA")]
+    PoolNotRunByGame,
+    #[msg("Attempt in progress: The judge hasn't scored the last attempt yet")]
+    AttemptInProgress,
+    #[msg("No attempts left: The wallet used every attempt for this pool")]
+    NoAttemptsLeft,
+    #[msg("Attempt cooldown: The wallet's previous attempt at this pool started too recently")]
+    AttemptCooldown,
+    #[msg("No attempt in progress: Start an attempt before recording its result")]
+    NoAttemptInProgress,
+    #[msg("Session mismatch: The session belongs to another pool")]
+    SessionPoolMismatch,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AttemptStarted {
+    pub wallet: Pubkey,
+    pub prize_pool: Pubkey,
+    /// 1-based attempt number within the pool
+    pub attempt: u16,
+    /// Set on the wallet's first attempt, which also registers it with the pool
+    pub registered: bool,
+    pub week_id: String,
+}
+
+#[event]
+pub struct ResultRecorded {
+    pub wallet: Pubkey,
+    pub prize_pool: Pubkey,
+    pub attempt: u16,
+    pub raw_score: u16,
+    pub best_raw_score: u16,
+    /// The result beat the wallet's previous best and was submitted to the pool
+    pub submitted: bool,
+    pub week_id: String,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::program::PardonGame;
+use crate::state::GameConfig;
+
+#[derive(Accounts)]
+pub struct InitializeGame<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + GameConfig::INIT_SPACE,
+        seeds = [b"game"],
+        bump
+    )]
+    pub game: Account<'info, GameConfig>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::InvalidProgramData
+    )]
+    pub program: Program<'info, PardonGame>,
+    
+    /// Only the upgrade authority may claim the admin role
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeGame>, judge: Pubkey, max_attempts: u16) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.admin = ctx.accounts.admin.key();
+    game.judge = judge;
+    game.max_attempts = max_attempts;
+    game.attempt_cooldown_slots = 0;
+    game.bump = ctx.bumps.game;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+// Each module exposes its own `handler`; lib.rs always calls it by module path
+#![allow(ambiguous_glob_reexports)]
+
+pub mod initialize_game;
+pub mod record_result;
+pub mod start_attempt;
+pub mod update_game;
+
+pub use initialize_game::*;
+pub use record_result::*;
+pub use start_attempt::*;
+pub use update_game::*;
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use pardon_prizes::cpi::accounts::SubmitScore;
+use pardon_prizes::program::PardonPrizes;
+use pardon_prizes::PrizePool;
+
+use crate::errors::ErrorCode;
+use crate::events::ResultRecorded;
+use crate::state::{GameConfig, Session};
+use crate::GAME_AUTHORITY_SEED;
+
+/**
+ * New bests are submitted to the pool through submit_score, signed by the
+ * game authority PDA as pool authority. The PDA pays the pool Entry's rent,
+ * so it has to hold some SOL.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecordResult<'info> {
+    #[account(seeds = [b"game"], bump = game.bump, has_one = judge @ ErrorCode::Unauthorized)]
+    pub game: Account<'info, GameConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", prize_pool.key().as_ref(), session.wallet.as_ref()],
+        bump = session.bump,
+        has_one = prize_pool @ ErrorCode::SessionPoolMismatch
+    )]
+    pub session: Account<'info, Session>,
+    
+    pub prize_pool: Account<'info, PrizePool>,
+    
+    #[account(mut, seeds = [GAME_AUTHORITY_SEED], bump)]
+    pub game_authority: SystemAccount<'info>,
+    
+    /// CHECK: pardon-prizes' config, checked by submit_score
+    pub prizes_config: UncheckedAccount<'info>,
+    
+    /// CHECK: the wallet's pool Entry, created or updated by submit_score
+    #[account(mut)]
+    pub entry: UncheckedAccount<'info>,
+    
+    /// CHECK: the wallet's pool Registration, checked by submit_score
+    pub registration: UncheckedAccount<'info>,
+    
+    /// CHECK: the instructions sysvar, read by submit_score's CPI caller check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub judge: Signer<'info>,
+    
+    pub pardon_prizes_program: Program<'info, PardonPrizes>,
+    
+    /// CHECK: pardon-prizes' event authority, checked by submit_score
+    pub prizes_event_authority: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordResult>, raw_score: u16) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let submitted = session.record_result(raw_score)?;
+    let wallet = session.wallet;
+
+    if submitted {
+        let seeds = &[GAME_AUTHORITY_SEED, &[ctx.bumps.game_authority]];
+        let signer = &[&seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.pardon_prizes_program.to_account_info(),
+            SubmitScore {
+                config: ctx.accounts.prizes_config.to_account_info(),
+                prize_pool: ctx.accounts.prize_pool.to_account_info(),
+                entry: ctx.accounts.entry.to_account_info(),
+                authority: ctx.accounts.game_authority.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                registration: Some(ctx.accounts.registration.to_account_info()),
+                event_authority: ctx.accounts.prizes_event_authority.to_account_info(),
+                program: ctx.accounts.pardon_prizes_program.to_account_info(),
+            },
+            signer,
+        );
+        pardon_prizes::cpi::submit_score(cpi_ctx, wallet, raw_score)?;
+    }
+
+    let session = &ctx.accounts.session;
+    emit_cpi!(ResultRecorded {
+        wallet,
+        prize_pool: session.prize_pool,
+        attempt: session.scored,
+        raw_score,
+        best_raw_score: session.best_raw_score,
+        submitted,
+        week_id: session.week_id.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use pardon_prizes::cpi::accounts::RegisterPlayer;
+use pardon_prizes::program::PardonPrizes;
+use pardon_prizes::PrizePool;
+
+use crate::errors::ErrorCode;
+use crate::events::AttemptStarted;
+use crate::state::{GameConfig, Session};
+use crate::GAME_AUTHORITY_SEED;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct StartAttempt<'info> {
+    #[account(seeds = [b"game"], bump = game.bump)]
+    pub game: Account<'info, GameConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + Session::INIT_SPACE,
+        seeds = [b"session", prize_pool.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub session: Account<'info, Session>,
+    
+    #[account(
+        mut,
+        constraint = prize_pool.roles.poster == game_authority.key() @ ErrorCode::PoolNotRunByGame
+    )]
+    pub prize_pool: Account<'info, PrizePool>,
+    
+    /// CHECK: PDA only compared against the pool's poster role
+    #[account(seeds = [GAME_AUTHORITY_SEED], bump)]
+    pub game_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: created by register_player, which checks its address
+    #[account(mut)]
+    pub registration: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub pardon_prizes_program: Program<'info, PardonPrizes>,
+    
+    /// CHECK: pardon-prizes' event authority, checked by register_player
+    pub prizes_event_authority: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<StartAttempt>) -> Result<()> {
+    let player = ctx.accounts.player.key();
+    let clock = Clock::get()?;
+    let prize_pool = &ctx.accounts.prize_pool;
+    let session = &mut ctx.accounts.session;
+
+    // A new session is the wallet's first attempt at this pool: sign it up there too
+    let registered = session.wallet == Pubkey::default();
+    if registered {
+        session.wallet = player;
+        session.prize_pool = prize_pool.key();
+        session.week_id = prize_pool.week_id.clone();
+        session.started_at = clock.unix_timestamp;
+        session.bump = ctx.bumps.session;
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.pardon_prizes_program.to_account_info(),
+            RegisterPlayer {
+                prize_pool: prize_pool.to_account_info(),
+                registration: ctx.accounts.registration.to_account_info(),
+                player: ctx.accounts.player.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                nft_token_account: None,
+                nft_metadata: None,
+                nft_mint: None,
+                token_program: None,
+                player_token_account: None,
+                prize_pool_token_account: None,
+                gateway_token: None,
+                player_profile: None,
+                bond_vault: None,
+                event_authority: ctx.accounts.prizes_event_authority.to_account_info(),
+                program: ctx.accounts.pardon_prizes_program.to_account_info(),
+            },
+        );
+        pardon_prizes::cpi::register_player(cpi_ctx)?;
+    }
+    let game = &ctx.accounts.game;
+    session.start_attempt(game.max_attempts, game.attempt_cooldown_slots, clock.slot)?;
+
+    emit_cpi!(AttemptStarted {
+        wallet: player,
+        prize_pool: session.prize_pool,
+        attempt: session.attempts,
+        registered,
+        week_id: session.week_id.clone(),
+    });
+
+    Ok(())
+}
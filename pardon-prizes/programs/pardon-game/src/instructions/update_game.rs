@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::GameConfig;
+
+#[derive(Accounts)]
+pub struct UpdateGame<'info> {
+    #[account(
+        mut,
+        seeds = [b"game"],
+        bump = game.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game: Account<'info, GameConfig>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateGame>,
+    judge: Pubkey,
+    max_attempts: u16,
+    attempt_cooldown_slots: u64,
+) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.judge = judge;
+    game.max_attempts = max_attempts;
+    game.attempt_cooldown_slots = attempt_cooldown_slots;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+
+pub use errors::ErrorCode;
+pub use events::*;
+pub use instructions::*;
+pub use state::*;
+
+declare_id!("CkEnb3TVYwKhWamWqhyaVit95UDpgvqjquVDisWUhyKF");
+
+/// Seed of the PDA that must be the authority of every pool the game runs
+pub const GAME_AUTHORITY_SEED: &[u8] = b"game_authority";
+
+#[program]
+pub mod pardon_game {
+    use super::*;
+
+    /**
+     * Create the game config
+     * Only callable by the program upgrade authority, who becomes admin
+     */
+    pub fn initialize_game(
+        ctx: Context<InitializeGame>,
+        judge: Pubkey,
+        max_attempts: u16,
+    ) -> Result<()> {
+        instructions::initialize_game::handler(ctx, judge, max_attempts)
+    }
+
+    /**
+     * Replace the judge, the attempts allowed per wallet and pool (0 = unlimited)
+     * and the slots a wallet waits between attempts (0 = none)
+     * Only callable by the game admin
+     */
+    pub fn update_game(
+        ctx: Context<UpdateGame>,
+        judge: Pubkey,
+        max_attempts: u16,
+        attempt_cooldown_slots: u64,
+    ) -> Result<()> {
+        instructions::update_game::handler(ctx, judge, max_attempts, attempt_cooldown_slots)
+    }
+
+    /**
+     * Open the player's next attempt at a pool the game runs; the first one
+     * creates the session and registers the player with the pool
+     * Signed by the player
+     */
+    pub fn start_attempt(ctx: Context<StartAttempt>) -> Result<()> {
+        instructions::start_attempt::handler(ctx)
+    }
+
+    /**
+     * Score the attempt in play; a new best is submitted to the pool
+     * Only callable by the judge
+     */
+    pub fn record_result(ctx: Context<RecordResult>, raw_score: u16) -> Result<()> {
+        instructions::record_result::handler(ctx, raw_score)
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use pardon_prizes::MAX_WEEK_ID_LEN;
+
+use crate::errors::ErrorCode;
+
+/**
+ * Game-wide settings
+ * Seeds: ["game"]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct GameConfig {
+    pub admin: Pubkey,
+    /// Only key that can record the results of attempts
+    pub judge: Pubkey,
+    /// Attempts each wallet gets per pool, 0 for unlimited
+    pub max_attempts: u16,
+    /// Slots a wallet must wait between attempts at a pool, 0 for none
+    pub attempt_cooldown_slots: u64,
+    pub bump: u8,
+}
+
+/**
+ * One wallet's play in one pool, i.e. one week of one category
+ * Seeds: ["session", prize_pool, wallet]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Session {
+    pub wallet: Pubkey,
+    pub prize_pool: Pubkey,
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub week_id: String,
+    /// Attempts started so far
+    pub attempts: u16,
+    /// Attempts the judge has scored; one more than this means an attempt is in play
+    pub scored: u16,
+    /// Judge's raw score of the latest scored attempt
+    pub last_raw_score: u16,
+    /// Best raw score so far, the one entered into the prize pool
+    pub best_raw_score: u16,
+    pub started_at: i64,
+    /// Slot the latest attempt started in
+    pub last_attempt_slot: u64,
+    pub bump: u8,
+}
+
+impl Session {
+    /**
+     * Opens the next attempt in `slot`; one at a time, at most `max_attempts`
+     * (0 = unlimited), and `cooldown_slots` after the previous one started
+     */
+    pub fn start_attempt(
+        &mut self,
+        max_attempts: u16,
+        cooldown_slots: u64,
+        slot: u64,
+    ) -> Result<()> {
+        require!(self.scored == self.attempts, ErrorCode::AttemptInProgress);
+        require!(
+            max_attempts == 0 || self.attempts < max_attempts,
+            ErrorCode::NoAttemptsLeft
+        );
+        require!(
+            self.attempts == 0 || slot >= self.last_attempt_slot.saturating_add(cooldown_slots),
+            ErrorCode::AttemptCooldown
+        );
+        self.attempts += 1;
+        self.last_attempt_slot = slot;
+        Ok(())
+    }
+
+    /// Scores the attempt in play, returning whether it is a new best
+    pub fn record_result(&mut self, raw_score: u16) -> Result<bool> {
+        require!(self.scored < self.attempts, ErrorCode::NoAttemptInProgress);
+        let improved = self.scored == 0 || raw_score > self.best_raw_score;
+        self.scored += 1;
+        self.last_raw_score = raw_score;
+        if improved {
+            self.best_raw_score = raw_score;
+        }
+        Ok(improved)
+    }
+}
@@ -0,0 +1,76 @@
+//! Property tests for the attempt bookkeeping on Session.
+
+use anchor_lang::prelude::Pubkey;
+use pardon_game::Session;
+use proptest::prelude::*;
+
+fn session() -> Session {
+    Session {
+        wallet: Pubkey::new_unique(),
+        prize_pool: Pubkey::new_unique(),
+        week_id: "2025-W01".to_string(),
+        attempts: 0,
+        scored: 0,
+        last_raw_score: 0,
+        best_raw_score: 0,
+        started_at: 0,
+        last_attempt_slot: 0,
+        bump: 0,
+    }
+}
+
+proptest! {
+    #[test]
+    fn best_score_is_the_max_of_all_results(scores in prop::collection::vec(any::<u16>(), 1..20)) {
+        let mut session = session();
+        for &score in &scores {
+            session.start_attempt(0, 0, 0).unwrap();
+            session.record_result(score).unwrap();
+        }
+        prop_assert_eq!(session.best_raw_score, *scores.iter().max().unwrap());
+        prop_assert_eq!(session.last_raw_score, *scores.last().unwrap());
+        prop_assert_eq!(session.scored as usize, scores.len());
+    }
+
+    #[test]
+    fn attempts_stop_at_the_limit(max_attempts in 1..10u16) {
+        let mut session = session();
+        for _ in 0..max_attempts {
+            session.start_attempt(max_attempts, 0, 0).unwrap();
+            session.record_result(50).unwrap();
+        }
+        prop_assert!(session.start_attempt(max_attempts, 0, 0).is_err());
+        prop_assert_eq!(session.attempts, max_attempts);
+    }
+
+    #[test]
+    fn attempts_wait_out_the_cooldown(cooldown in 1..1_000u64, first in 0..1_000_000u64) {
+        let mut session = session();
+        session.start_attempt(0, cooldown, first).unwrap();
+        session.record_result(50).unwrap();
+        prop_assert!(session.start_attempt(0, cooldown, first + cooldown - 1).is_err());
+        session.start_attempt(0, cooldown, first + cooldown).unwrap();
+        prop_assert_eq!(session.last_attempt_slot, first + cooldown);
+    }
+}
+
+#[test]
+fn one_attempt_in_play_at_a_time() {
+    let mut session = session();
+    assert!(session.record_result(70).is_err());
+    session.start_attempt(3, 0, 0).unwrap();
+    assert!(session.start_attempt(3, 0, 0).is_err());
+    assert!(session.record_result(70).unwrap());
+    assert!(session.record_result(80).is_err());
+}
+
+#[test]
+fn only_improvements_are_submitted() {
+    let mut session = session();
+    session.start_attempt(0, 0, 0).unwrap();
+    assert!(session.record_result(0).unwrap());
+    session.start_attempt(0, 0, 0).unwrap();
+    assert!(!session.record_result(0).unwrap());
+    session.start_attempt(0, 0, 0).unwrap();
+    assert!(session.record_result(1).unwrap());
+}
@@ -0,0 +1,31 @@
+//! Finds the program that invoked us through the instructions sysvar, so
+//! fund_pool and submit_score can refuse CPIs from unapproved programs.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::ErrorCode;
+use crate::state::Config;
+
+/**
+ * Program of the top-level instruction being executed: this program for a
+ * direct call, otherwise the outermost program of the CPI chain
+ * A program reached through another one (A -> B -> us) is reported as A.
+ */
+pub fn top_level_program(instructions_sysvar: &AccountInfo) -> Result<Pubkey> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let ix = load_instruction_at_checked(current as usize, instructions_sysvar)?;
+    Ok(ix.program_id)
+}
+
+/// Direct calls always pass; CPIs must come from a program on the config's allowlist
+pub fn require_allowed_caller(config: &Config, instructions_sysvar: &AccountInfo) -> Result<()> {
+    let caller = top_level_program(instructions_sysvar)?;
+    require!(
+        caller == crate::ID || config.accepts_caller(&caller),
+        ErrorCode::CpiCallerNotAllowed
+    );
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! Reads Civic gateway tokens, so pools can require a proof of personhood
+//! without pulling in the solana-gateway crate.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+use crate::errors::ErrorCode;
+
+/// Civic gateway program, same address on mainnet-beta and devnet
+pub const GATEWAY_PROGRAM_ID: Pubkey = pubkey!("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs");
+
+/// GatewayTokenState::Active; the others are Revoked and Frozen
+const STATE_ACTIVE: u8 = 0;
+
+/// A gateway token account's data, as the gateway program lays it out (borsh)
+#[derive(AnchorDeserialize)]
+pub struct GatewayToken {
+    pub features: u8,
+    pub parent_gateway_token: Option<Pubkey>,
+    pub owner_wallet: Pubkey,
+    pub owner_identity: Option<Pubkey>,
+    pub gatekeeper_network: Pubkey,
+    pub issuing_gatekeeper: Pubkey,
+    /// GatewayTokenState tag
+    pub state: u8,
+    pub expire_time: Option<i64>,
+}
+
+impl GatewayToken {
+    /// Proves `wallet` passed `network` and hasn't been revoked, frozen or expired by `now`
+    pub fn is_valid_for(&self, wallet: &Pubkey, network: &Pubkey, now: i64) -> bool {
+        self.owner_wallet == *wallet
+            && self.gatekeeper_network == *network
+            && self.state == STATE_ACTIVE
+            && match self.expire_time {
+                Some(expire_time) => now < expire_time,
+                None => true,
+            }
+    }
+}
+
+/// Parses a gateway token account's data, None if it isn't laid out as expected
+pub fn parse_gateway_token(mut data: &[u8]) -> Option<GatewayToken> {
+    GatewayToken::deserialize(&mut data).ok()
+}
+
+/// `gateway_token` must be `wallet`'s live pass in the gatekeeper `network`
+pub fn check_gateway_token(
+    gateway_token: Option<&AccountInfo>,
+    wallet: &Pubkey,
+    network: &Pubkey,
+) -> Result<()> {
+    let info = gateway_token.ok_or(ErrorCode::GatewayTokenRequired)?;
+    require_keys_eq!(*info.owner, GATEWAY_PROGRAM_ID, ErrorCode::InvalidGatewayToken);
+    let token = parse_gateway_token(&info.try_borrow_data()?)
+        .ok_or(ErrorCode::InvalidGatewayToken)?;
+    require!(
+        token.is_valid_for(wallet, network, Clock::get()?.unix_timestamp),
+        ErrorCode::InvalidGatewayToken
+    );
+    Ok(())
+}
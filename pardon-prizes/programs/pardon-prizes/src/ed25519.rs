@@ -0,0 +1,68 @@
+//! Checks on native ed25519 sig-verify instructions, so handlers can trust
+//! a message signed off-chain by a wallet that isn't in the transaction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::ErrorCode;
+
+/// num_signatures (u8) and padding (u8)
+const HEADER_LEN: usize = 2;
+
+/// Seven u16 offsets per signature
+const OFFSETS_LEN: usize = 14;
+
+/// Instruction index meaning "this instruction's own data"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/**
+ * Requires the instruction right before the current one to be an ed25519
+ * sig-verify of exactly one signature by `signer` over `message`
+ * The runtime has already checked the signature by the time we run.
+ */
+pub fn verify_previous_ix(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let index = current
+        .checked_sub(1)
+        .ok_or(ErrorCode::InvalidEd25519Signature)?;
+    let ix = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+    require!(
+        signs_message(&ix, signer, message),
+        ErrorCode::InvalidEd25519Signature
+    );
+    Ok(())
+}
+
+/// True if `ix` verifies one signature by `signer` over `message`, all in its own data
+pub fn signs_message(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> bool {
+    if ix.program_id != ed25519_program::ID {
+        return false;
+    }
+    let data = &ix.data;
+    if data.len() < HEADER_LEN + OFFSETS_LEN || data[0] != 1 {
+        return false;
+    }
+    let offset = |i: usize| {
+        let at = HEADER_LEN + i * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    // Offsets pointing into other instructions could sign data we never see
+    if [offset(1), offset(3), offset(6)]
+        .iter()
+        .any(|&index| index != CURRENT_INSTRUCTION)
+    {
+        return false;
+    }
+    let slice = |start: u16, len: usize| data.get(start as usize..start as usize + len);
+    slice(offset(2), 32) == Some(signer.as_ref())
+        && offset(5) as usize == message.len()
+        && slice(offset(4), message.len()) == Some(message)
+}
@@ -0,0 +1,423 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Unauthorized: Only authority can call this function")]
+    Unauthorized,
+    #[msg("Invalid rank: Rank must be between 1 and the pool's max_winners")]
+    InvalidRank,
+    #[msg("Score too low: Winner must have at least 80 points")]
+    ScoreTooLow,
+    #[msg("Insufficient funds in prize pool")]
+    InsufficientFunds,
+    #[msg("Stale distribution: Nonce does not match the prize pool")]
+    StaleDistributionNonce,
+    #[msg("Invalid max_winners: Must be between 1 and 10")]
+    InvalidMaxWinners,
+    #[msg("Empty winners list: At least one winner is required")]
+    EmptyWinners,
+    #[msg("Too many winners: List is longer than the pool's max_winners")]
+    TooManyWinners,
+    #[msg("Missing winner account: Remaining accounts do not cover every winner")]
+    MissingWinnerAccount,
+    #[msg("Winner token account has the wrong owner or mint")]
+    WinnerAccountMismatch,
+    #[msg("Invalid team: Needs 1-5 unique members whose shares sum to 10000 bps")]
+    InvalidTeamMembers,
+    #[msg("Team mismatch: Team account does not match the winner entry")]
+    TeamMismatch,
+    #[msg("Invalid split: Share must be at most 10000 bps")]
+    InvalidSplitBps,
+    #[msg("Player profile account is not the winner's profile PDA")]
+    PlayerProfileMismatch,
+    #[msg("Invalid creator share: Must be at most 10000 bps")]
+    InvalidCreatorShare,
+    #[msg("Creator token account has the wrong owner or mint")]
+    CreatorAccountMismatch,
+    #[msg("Invalid amount: Must be greater than zero")]
+    InvalidAmount,
+    #[msg("Memo too long: At most 64 bytes")]
+    MemoTooLong,
+    #[msg("Funder not whitelisted: This pool only accepts approved sponsors")]
+    FunderNotWhitelisted,
+    #[msg("Funder cap exceeded: Deposit would pass the per-wallet limit")]
+    FunderCapExceeded,
+    #[msg("Pool name too long: At most 64 bytes")]
+    PoolNameTooLong,
+    #[msg("Metadata URI too long: At most 200 bytes")]
+    MetadataUriTooLong,
+    #[msg("Invalid category: Must be 1 to 32 bytes")]
+    InvalidCategory,
+    #[msg("Invalid rank shares: Need one share per paid rank, summing to at most 10000 bps")]
+    InvalidRankShares,
+    #[msg("Week winner account is not the winner's marker PDA for this week")]
+    WeekWinnerMismatch,
+    #[msg("Already won this week: Wallet won another category")]
+    AlreadyWonCategory,
+    #[msg("Invalid cooldown rank: Must be at most 10")]
+    InvalidCooldownRank,
+    #[msg("Win cooldown: Winner placed in the top 3 last week")]
+    WinCooldownActive,
+    #[msg("Player profile required: The win cooldown needs the winner's history")]
+    PlayerProfileRequired,
+    #[msg("Week id is not an ISO week (YYYY-Www)")]
+    InvalidWeekId,
+    #[msg("Invalid score scale: Raw maximum must be greater than zero")]
+    InvalidScoreScale,
+    #[msg("Score out of range: Above the pool's raw maximum")]
+    ScoreOutOfRange,
+    #[msg("Entry finalized: Scores and votes are locked")]
+    EntryFinalized,
+    #[msg("Vote weight exceeds balance: Voters can weigh in up to the tokens they hold")]
+    VoteWeightExceedsBalance,
+    #[msg("Votes locked: Escrowed tokens unlock once the pool pays out or is cancelled")]
+    VotesLocked,
+    #[msg("Votes still escrowed: Voters must unlock before the pool closes")]
+    VotesStillEscrowed,
+    #[msg("Snapshots closed: Outside the pool's snapshot window")]
+    SnapshotsClosed,
+    #[msg("Balance snapshot account is not the winner's snapshot PDA")]
+    BalanceSnapshotMismatch,
+    #[msg("Insufficient holding: Winner's snapshot is below the pool minimum")]
+    InsufficientHolding,
+    #[msg("Invalid stake tiers: At most 5, ascending, each between 1x and 2x")]
+    InvalidStakeTiers,
+    #[msg("Stake mint locked: Tokens are still staked under the current mint")]
+    StakeMintLocked,
+    #[msg("Staking disabled: The admin has not set a stake mint")]
+    StakingDisabled,
+    #[msg("Stake locked: Tokens unlock a week after the last deposit")]
+    StakeLocked,
+    #[msg("Insufficient stake: Cannot unstake more than is staked")]
+    InsufficientStake,
+    #[msg("Stake account is not the winner's stake PDA")]
+    StakeAccountMismatch,
+    #[msg("Invalid staker share: Must be at most 10000 bps")]
+    InvalidStakerShare,
+    #[msg("Missing staker rewards vault: Stakers are owed a share of this pool")]
+    StakerRewardsVaultRequired,
+    #[msg("No staking rewards to claim")]
+    NoStakingRewards,
+    #[msg("Invalid signature: Expected an ed25519 sig-verify by the wallet over this message")]
+    InvalidEd25519Signature,
+    #[msg("Pool not empty: Funds leave only through distribution or an emergency withdrawal")]
+    PoolNotEmpty,
+    #[msg("No emergency withdrawal has been announced for this pool")]
+    EmergencyNotAnnounced,
+    #[msg("Emergency timelock: The announced withdrawal is not executable yet")]
+    EmergencyTimelockActive,
+    #[msg("Destination does not match the announced emergency withdrawal")]
+    EmergencyDestinationMismatch,
+    #[msg("Unknown feature: Must be exactly one known FEATURE_* flag")]
+    UnknownFeature,
+    #[msg("Mint mismatch: Token account or mint is not the expected mint")]
+    MintMismatch,
+    #[msg("Wrong token account owner: Must belong to the signing wallet")]
+    TokenOwnerMismatch,
+    #[msg("Vault mismatch: Token account is not the prize pool's vault")]
+    VaultMismatch,
+    #[msg("Duplicate winner: A wallet appears more than once in the winners list")]
+    DuplicateWinner,
+    #[msg("Program data account does not belong to this program")]
+    InvalidProgramData,
+    #[msg("Account is not the instructions sysvar")]
+    InvalidInstructionsSysvar,
+    #[msg("Winners must be listed in strictly ascending rank order")]
+    WinnersNotSorted,
+    #[msg("Rank gap: Winners must have consecutive ranks unless the pool allows gaps")]
+    RankGap,
+    #[msg("Leaderboard already finalized for this pool")]
+    LeaderboardFinalized,
+    #[msg("Leaderboard must have at least one entry")]
+    EmptyLeaderboard,
+    #[msg("Feature disabled: Enable it in the program state first")]
+    FeatureDisabled,
+    #[msg("Leaderboard not finalized for this pool")]
+    LeaderboardNotFinalized,
+    #[msg("Invalid Merkle proof for this leaderboard entry")]
+    InvalidMerkleProof,
+    #[msg("Claims are not supported on exclusive or USD-priced pools")]
+    ClaimsUnsupported,
+    #[msg("Pool already paid out through distribute_prizes")]
+    PoolAlreadyDistributed,
+    #[msg("Winners are claiming this pool; distribute_prizes is closed")]
+    ClaimsStarted,
+    #[msg("Mint not allowed: Not on the config's funding allowlist")]
+    MintNotAllowed,
+    #[msg("Invalid funding mints: At most MAX_FUNDING_MINTS, without duplicates")]
+    InvalidFundingMints,
+    #[msg("Registration required: Player must register for this pool first")]
+    RegistrationRequired,
+    #[msg("Collection NFT required: Pass the player's NFT token account and metadata")]
+    CollectionNftRequired,
+    #[msg("NFT is not held by the player or not in the pool's verified collection")]
+    InvalidCollectionNft,
+    #[msg("Ticket burn requires the ticket mint and the token program")]
+    TicketBurnAccountsRequired,
+    #[msg("Invalid rake: Must be at most 10000 basis points")]
+    InvalidRake,
+    #[msg("Entry fee locked: Fees were already collected or entries are closed")]
+    EntryFeeLocked,
+    #[msg("Registration closed: Entries for this pool are closed")]
+    RegistrationClosed,
+    #[msg("Entries still open: Close entries before paying out an entry-fee pool")]
+    EntriesOpen,
+    #[msg("Entry fee requires the player's token account, the pool vault and the token program")]
+    EntryFeeAccountsRequired,
+    #[msg("Treasury token account must belong to the config treasury")]
+    TreasuryAccountMismatch,
+    #[msg("Invalid jackpot share: Must be at most 10000 basis points")]
+    InvalidJackpotShare,
+    #[msg("Invalid trigger score: Must be between 1 and 100")]
+    InvalidTriggerScore,
+    #[msg("Jackpot vault required: This pool feeds the jackpot")]
+    JackpotVaultRequired,
+    #[msg("Jackpot already drawn for this pool")]
+    JackpotAlreadyDrawn,
+    #[msg("No entries qualify for the jackpot")]
+    NoJackpotQualifiers,
+    #[msg("Entry did not reach the jackpot trigger score when finalized")]
+    BelowJackpotTrigger,
+    #[msg("Entry does not belong to this pool")]
+    JackpotEntryMismatch,
+    #[msg("Jackpot entries must be passed in strictly ascending address order")]
+    JackpotEntriesNotSorted,
+    #[msg("Randomness required: Several entries qualify for the jackpot")]
+    RandomnessRequired,
+    #[msg("Randomness account is not this draw's VRF request")]
+    InvalidRandomness,
+    #[msg("Randomness not fulfilled yet")]
+    RandomnessNotFulfilled,
+    #[msg("Invalid bonus multiplier: Must be 0 or between 10000 and 30000 basis points")]
+    InvalidBonusMultiplier,
+    #[msg("Bonus locked: Already funded or payouts have started")]
+    BonusLocked,
+    #[msg("Only the pool's bonus sponsor can do this")]
+    BonusSponsorMismatch,
+    #[msg("Bonus underfunded: The sponsor's deposit doesn't cover the multiplier")]
+    BonusUnderfunded,
+    #[msg("Payouts have not started for this pool")]
+    PayoutsNotStarted,
+    #[msg("Invalid happy hours: At most 4 ascending, non-overlapping windows, each 1x-2x")]
+    InvalidHappyHours,
+    #[msg("Invalid streak bonus: At most 10000 basis points over a streak of at least 2 weeks")]
+    InvalidStreakBonus,
+    #[msg("Streak vault required: This pool pays streak bonuses")]
+    StreakVaultRequired,
+    #[msg("Pool archived: Its claim receipts have been closed")]
+    PoolArchived,
+    #[msg("Archive receipt mismatch: Not a claim receipt of this pool")]
+    ArchiveReceiptMismatch,
+    #[msg("Archive winner mismatch: Each receipt must be followed by its winner's wallet")]
+    ArchiveWinnerMismatch,
+    #[msg("Pool not archived: Archive the week before closing its registrations")]
+    PoolNotArchived,
+    #[msg("Registration mismatch: Not a registration of this pool")]
+    RegistrationMismatch,
+    #[msg("Registration player mismatch: Each registration must be followed by its player")]
+    RegistrationPlayerMismatch,
+    #[msg("Invalid auto-close delay: Must not be negative")]
+    InvalidAutoClose,
+    #[msg("Auto-close disabled: The config admin hasn't set a retention period")]
+    AutoCloseDisabled,
+    #[msg("Retention period active: The pool can't be closed by anyone yet")]
+    RetentionPeriodActive,
+    #[msg("Invalid CPI callers: At most MAX_CPI_CALLERS other programs, without duplicates")]
+    InvalidCpiCallers,
+    #[msg("CPI caller not allowed: The calling program isn't on the config's allowlist")]
+    CpiCallerNotAllowed,
+    #[msg("Invalid bet close: Must be in the future and before the leaderboard is final")]
+    InvalidBetClose,
+    #[msg("Betting closed: The market no longer takes bets")]
+    BettingClosed,
+    #[msg("Betting still open: Bets can't be settled before the market closes")]
+    BettingStillOpen,
+    #[msg("Bet player mismatch: A bettor backs a single player per market")]
+    BetPlayerMismatch,
+    #[msg("Bets already settled for this market")]
+    BetsAlreadySettled,
+    #[msg("Bets not settled: Wait for settle_bets")]
+    BetsNotSettled,
+    #[msg("Bet lost: The backed player didn't win")]
+    BetLost,
+    #[msg("Treasury token account required: There is a rake to pay")]
+    TreasuryAccountRequired,
+    #[msg("Week cancelled: Bets on this pool can only be refunded")]
+    WeekCancelled,
+    #[msg("Bet not refundable: The market can still be settled on the leaderboard")]
+    BetNotRefundable,
+    #[msg("Invalid USD prizes: Price age and confidence limits must be positive, confidence at most 10000 bps")]
+    InvalidUsdPrizes,
+    #[msg("USD prizes locked: Payouts have started")]
+    UsdPrizesLocked,
+    #[msg("Price update required: This pool's prizes are in USD")]
+    PriceUpdateRequired,
+    #[msg("Invalid price update: Not a fully verified Pyth PriceUpdateV2 account")]
+    InvalidPriceUpdate,
+    #[msg("Price feed mismatch: The update is for another feed")]
+    PriceFeedMismatch,
+    #[msg("Stale price: The price update is older than the pool allows")]
+    StalePrice,
+    #[msg("Price too uncertain: The confidence interval is wider than the pool allows")]
+    PriceTooUncertain,
+    #[msg("Invalid delegation: Unknown instruction bits, or a delegate without a key or already expired")]
+    InvalidDelegation,
+    #[msg("Preview unsupported: USD prizes are only priced at distribution")]
+    PreviewUnsupported,
+    #[msg("Gateway token required: This pool requires a Civic pass from its gatekeeper network")]
+    GatewayTokenRequired,
+    #[msg("Invalid gateway token: Not the wallet's active, unexpired pass in the pool's network")]
+    InvalidGatewayToken,
+    #[msg("Invalid wallet age: Attested first activity must be in the past, requirements need a registrar and days")]
+    InvalidWalletAge,
+    #[msg("Wallet age attestation required: This pool requires the player profile attested by its registrar")]
+    WalletAgeAttestationRequired,
+    #[msg("Wallet too new: The wallet's first activity is more recent than the pool allows")]
+    WalletTooNew,
+    #[msg("Entry bond locked: Bonds were already posted or entries are closed")]
+    EntryBondLocked,
+    #[msg("Entry bond requires the player's token account, the bond vault and the token program")]
+    EntryBondAccountsRequired,
+    #[msg("No bond held: The registration's bond was already refunded or slashed, or never posted")]
+    NoBondHeld,
+    #[msg("Bonds still held: Players must take back their entry bonds before the pool closes")]
+    BondsStillHeld,
+    #[msg("Entry bond required: Cheat reports pay their bounty from the reported player's entry bond")]
+    EntryBondRequired,
+    #[msg("Invalid report bounty: Must be at most 10000 bps")]
+    InvalidReportBounty,
+    #[msg("Reports disabled: This pool takes no cheat reports")]
+    ReportsDisabled,
+    #[msg("Invalid report: Players can't report themselves")]
+    InvalidReport,
+    #[msg("Report pending: The guardian hasn't ruled on every report against this player")]
+    ReportPending,
+    #[msg("Report already resolved")]
+    ReportAlreadyResolved,
+    #[msg("Insurance vault required: This pool feeds the insurance fund")]
+    InsuranceVaultRequired,
+    #[msg("Invalid insurance share: Must be at most 10000 basis points")]
+    InvalidInsuranceShare,
+    #[msg("Too many unpaid prizes: More payouts failed than one distribution can record")]
+    TooManyUnpaidPrizes,
+    #[msg("Unpaid prize not found: No unpaid leg at that index of the distribution")]
+    UnpaidPrizeNotFound,
+    #[msg("Unpaid prize already paid")]
+    UnpaidPrizeAlreadyPaid,
+    #[msg("Invalid winner list entries: Expected whole ListedWinner records")]
+    InvalidWinnerListEntries,
+    #[msg("Winner list full: A list holds at most MAX_RANK winners")]
+    WinnerListFull,
+    #[msg("Winners listed twice: Pass either the winners argument or a winner list, not both")]
+    WinnersListedTwice,
+    #[msg("Week schedule unset: The admin hasn't set the epoch numbered weeks count from")]
+    WeekScheduleUnset,
+    #[msg("Week schedule locked: Moving the epoch would renumber existing pools")]
+    WeekScheduleLocked,
+    #[msg("Invalid week schedule: The epoch start must be a positive unix timestamp")]
+    InvalidWeekSchedule,
+    #[msg("Week not open: Pools can only be created for the current or the next week")]
+    WeekNotOpen,
+    #[msg("Rollover disabled: The admin hasn't set pool defaults")]
+    RolloverDisabled,
+    #[msg("Week not over: The pool's week hasn't ended yet")]
+    WeekNotOver,
+    #[msg("Not a numbered pool: Only pools made by initialize_weekly_pool roll over")]
+    NotNumberedPool,
+    #[msg("Rollover source mismatch: Not an earlier pool of this category and mint")]
+    RolloverSourceMismatch,
+    #[msg("Rollover source unsettled: Only archived, uncancelled weeks carry their surplus over")]
+    RolloverSourceUnsettled,
+    #[msg("Airdrop mode locked: The leaderboard is final or payouts have started")]
+    AirdropModeLocked,
+    #[msg("Invalid airdrop threshold: Must be at most MAX_SCORE")]
+    InvalidAirdropThreshold,
+    #[msg("Airdrop pool: Qualifiers claim their equal share through claim_prize")]
+    AirdropPool,
+    #[msg("Below airdrop threshold: The score doesn't qualify for a share")]
+    BelowAirdropThreshold,
+    #[msg("Airdrop exhausted: Every share under the leaderboard has been claimed")]
+    AirdropExhausted,
+    #[msg("Invalid qualification tiers: Too many, out of order or out of range")]
+    InvalidQualificationTiers,
+    #[msg("Airdrop weight unset: Tiered airdrops need set_airdrop_weight before claims")]
+    AirdropWeightUnset,
+    #[msg("Airdrop weight locked: Set it once the leaderboard is final, before claims start")]
+    AirdropWeightLocked,
+    #[msg("Invalid airdrop weight: Must lie between 1x and the top tier for every qualifier")]
+    InvalidAirdropWeight,
+    #[msg("Scoring transcript already posted: The transcript can't change once published")]
+    ScoringTranscriptPosted,
+    #[msg("Scoring transcript too late: Payouts have already started")]
+    ScoringTranscriptTooLate,
+    #[msg("Invalid scoring transcript: Needs a hash and a URI of at most 200 bytes")]
+    InvalidScoringTranscript,
+    #[msg("Scoring transcript required: The judge's transcript must be posted before payouts")]
+    ScoringTranscriptRequired,
+    #[msg("Scoring inputs missing: Finalize the leaderboard and post the scoring transcript first")]
+    ScoringInputsMissing,
+    #[msg("Invalid scoring proof: The proof doesn't verify against the scoring verifier")]
+    InvalidScoringProof,
+    #[msg("Enclave signer unset: No judge enclave is configured to authorize distributions")]
+    EnclaveSignerUnset,
+    #[msg("Invalid enclave signer: Needs a key, an enclave measurement and a report hash")]
+    InvalidEnclaveSigner,
+    #[msg("Invalid judges: Up to 7 distinct judges, with a quorum of 3 up to their count")]
+    InvalidJudges,
+    #[msg("Judges locked: The judge panel can't change once results are posted")]
+    JudgesLocked,
+    #[msg("Judge quorum pool: Scores come from the judges through aggregate_scores")]
+    JudgeQuorumPool,
+    #[msg("Judge already scored: Each judge scores a player once")]
+    JudgeAlreadyScored,
+    #[msg("Scores aggregated: The player's final score is already recorded")]
+    ScoresAggregated,
+    #[msg("Quorum not reached: Not enough of the pool's judges have scored this player")]
+    QuorumNotReached,
+    #[msg("Invalid snapshot window: Needs a start slot from now on, before its end slot")]
+    InvalidSnapshotWindow,
+    #[msg("Snapshot window open: The holder requirement can't change once snapshots open")]
+    SnapshotWindowOpen,
+    #[msg("Jackpot required: Entries are checked against the configured jackpot trigger")]
+    JackpotRequired,
+    #[msg("Results final: Entries can't be finalized once the leaderboard or payouts are out")]
+    ResultsFinal,
+    #[msg("Results pending: The jackpot is drawn once the leaderboard or payouts are out")]
+    ResultsPending,
+    #[msg("Jackpot qualifiers missing: Every qualifying entry of the pool must be passed")]
+    JackpotQualifiersMissing,
+    #[msg("Claim window closed: The pool's claim deadline has passed")]
+    ClaimWindowClosed,
+    #[msg("Claim window open: Winners can still claim until the pool's claim deadline")]
+    ClaimWindowOpen,
+    #[msg("Judging open: Waiting for the whole panel or the end of judging")]
+    JudgingOpen,
+    #[msg("Judging closed: The pool's judging deadline has passed")]
+    JudgingClosed,
+    #[msg("Pool cancelled: An emergency withdrawal has been announced")]
+    PoolCancelled,
+    #[msg("Prize claimed: The winner's claim receipt exists")]
+    PrizeClaimed,
+    #[msg("Expired prizes out of order: Report ranks in ascending order, each once")]
+    ExpiredPrizeOrder,
+    #[msg("Expired receipt mismatch: Pass each expired prize's claim receipt address, in order")]
+    ExpiredReceiptMismatch,
+    #[msg("Invalid claim batch: Pass at least one claim and its accounts")]
+    InvalidClaimBatch,
+    #[msg("Claim account mismatch: A claim receipt or week stats account isn't the pool's")]
+    ClaimAccountMismatch,
+    #[msg("Overflow: A pool counter would overflow or go below zero")]
+    Overflow,
+    #[msg("Token account opener required: Pass the mint and associated token program to open it")]
+    TokenAccountOpenerRequired,
+    #[msg("Split account required: Pass the split recipient's token account for the pool mint")]
+    SplitAccountRequired,
+    #[msg("Results not settled: Prizes are still owed, or claims aren't swept or archived")]
+    ResultsNotSettled,
+    #[msg("Invalid bridge config: The Wormhole bridge account holds no message fee")]
+    InvalidBridgeConfig,
+    #[msg("Settle deadline passed: Bets on this market are refunded instead")]
+    SettleDeadlinePassed,
+    #[msg("Rank already paid: An earlier distribute_prizes pass paid this rank")]
+    RankAlreadyPaid,
+}
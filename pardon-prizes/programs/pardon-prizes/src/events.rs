@@ -0,0 +1,630 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AuthorityKind, PoolRole};
+
+#[event]
+pub struct PoolFunded {
+    pub funder: Pubkey,
+    /// The pool mint, or an allowlisted mint held in its own sub-vault
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub memo: String,
+    pub total_funded: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct PoolMetadataUpdated {
+    pub week_id: String,
+    pub category: String,
+    pub name: String,
+    pub metadata_uri: String,
+}
+
+#[event]
+pub struct PoolRoleChanged {
+    pub role: PoolRole,
+    pub previous: Pubkey,
+    pub key: Pubkey,
+    pub changed_at: i64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct ConfigKeyChanged {
+    /// Guardian, Treasury or Creator
+    pub kind: AuthorityKind,
+    pub previous: Pubkey,
+    pub key: Pubkey,
+    pub changed_at: i64,
+}
+
+#[event]
+pub struct AuthorityDelegated {
+    /// Ignored when allowed_ixs is 0, which revokes the delegate
+    pub key: Pubkey,
+    pub expires_at: i64,
+    pub allowed_ixs: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct ScoreSubmitted {
+    pub player: Pubkey,
+    pub raw_score: u16,
+    pub score: u8,
+    /// Happy hour multiplier applied to score, 10000 = none
+    pub multiplier_bps: u16,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct VoteCast {
+    pub voter: Pubkey,
+    pub player: Pubkey,
+    pub weight: u64,
+    pub counted: u64,
+    /// Entry's vote total after this vote
+    pub votes: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct VotesUnlocked {
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct EntryFinalized {
+    pub player: Pubkey,
+    pub score: u8,
+    pub votes: u64,
+    pub final_score: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct Staked {
+    pub wallet: Pubkey,
+    pub amount: u64,
+    /// Wallet's stake after this deposit
+    pub staked: u64,
+    pub locked_until: i64,
+}
+
+#[event]
+pub struct Unstaked {
+    pub wallet: Pubkey,
+    pub amount: u64,
+    /// Wallet's stake after this withdrawal
+    pub staked: u64,
+}
+
+#[event]
+pub struct StakerRewardsAdded {
+    pub amount: u64,
+    /// Config.reward_per_share after this pool's share
+    pub reward_per_share: u128,
+    pub total_staked: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct StakingRewardsClaimed {
+    pub wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PayoutWalletAuthorized {
+    pub wallet: Pubkey,
+    pub payout_wallet: Pubkey,
+    /// Nonce the winner signed
+    pub payout_nonce: u64,
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct PrizeDistributed {
+    pub winner: Pubkey,
+    pub rank: u8,
+    pub score: u8,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+    pub distribution_nonce: u64,
+    /// Wallet whose token account got the prize, the winner's unless redirected
+    pub paid_to: Pubkey,
+    /// Secondary leg routed by the winner's PlayerProfile, included in `amount`
+    pub split_recipient: Option<Pubkey>,
+    pub split_amount: u64,
+    /// Multiplier from the winner's stake tier, 10000 = no boost
+    pub stake_multiplier_bps: u16,
+    /// Extra paid for the stake tier, included in `amount`
+    pub stake_bonus: u64,
+    /// Pool's sponsored multiplier, 10000 or 0 = none
+    pub bonus_multiplier_bps: u16,
+    /// Extra paid from the sponsor's deposit, included in `amount`
+    pub sponsor_bonus: u64,
+    /// Paid from the streak vault on top of `amount`
+    pub streak_bonus: u64,
+}
+
+#[event]
+pub struct UsdPrizesPriced {
+    pub usd_cents: u64,
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    /// Pool mint amount the ranks' shares were computed from
+    pub amount: u64,
+    pub distribution_nonce: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct CreatorRoyaltyPaid {
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct TeamShareDistributed {
+    pub team: Pubkey,
+    pub member: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// A payout leg distribute_prizes skipped, left in the vault for retry_unpaid
+#[event]
+pub struct PrizeUnpaid {
+    pub winner: Pubkey,
+    /// Wallet whose token account was closed or frozen
+    pub owner: Pubkey,
+    pub rank: u8,
+    pub amount: u64,
+    pub distribution_nonce: u64,
+    /// Position in the DistributionRecord's unpaid list
+    pub index: u8,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct UnpaidPrizeRetried {
+    pub winner: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub distribution_nonce: u64,
+    pub index: u8,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct EmergencyWithdrawAnnounced {
+    pub guardian: Pubkey,
+    pub destination: Pubkey,
+    /// What the withdrawal would take at announcement, reserves excluded
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct EmergencyWithdrawn {
+    pub guardian: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct FeatureFlagSet {
+    pub feature: u64,
+    pub enabled: bool,
+    /// All enabled flags after this change
+    pub features: u64,
+    pub version: String,
+}
+
+#[event]
+pub struct LeaderboardFinalized {
+    pub root: [u8; 32],
+    pub entry_count: u32,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct ResultsAttested {
+    /// This program's Wormhole emitter
+    pub emitter: Pubkey,
+    /// Sequence of the posted message, to fetch its VAA
+    pub sequence: u64,
+    pub leaderboard_root: [u8; 32],
+    /// PrizePool::winners_hash, zero for pools paid through claims
+    pub winners_hash: [u8; 32],
+    pub total_distributed: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct PlayerRegistered {
+    pub player: Pubkey,
+    pub nft_mint: Option<Pubkey>,
+    pub ticket_burned: bool,
+    pub entry_fee: u64,
+    /// Refundable bond held in the bond vault
+    pub bond: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct PrizeClaimed {
+    pub winner: Pubkey,
+    pub rank: u8,
+    pub score: u8,
+    pub amount: u64,
+    /// Wallet whose token account got the prize, the winner's unless redirected
+    pub paid_to: Pubkey,
+    /// Secondary leg routed by the winner's PlayerProfile, included in `amount`
+    pub split_recipient: Option<Pubkey>,
+    pub split_amount: u64,
+    pub bonus_multiplier_bps: u16,
+    /// Extra paid from the sponsor's deposit, included in `amount`
+    pub sponsor_bonus: u64,
+    /// Paid from the streak vault on top of `amount`
+    pub streak_bonus: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct MintVaultSwept {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct EntriesClosed {
+    /// Entry fees collected over the week
+    pub entry_fees: u64,
+    /// House cut sent to the treasury
+    pub rake: u64,
+    pub treasury: Pubkey,
+    /// Vault balance left for winners
+    pub pool_size: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct JackpotContributed {
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct InsuranceContributed {
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct JackpotWon {
+    pub winner: Pubkey,
+    pub amount: u64,
+    /// Entries that reached the trigger score; more than one means a VRF draw
+    pub qualifiers: u32,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct BonusFunded {
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub bonus_reserve: u64,
+    pub bonus_multiplier_bps: u16,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct BonusRefunded {
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct WeekArchived {
+    pub prize_pool: Pubkey,
+    /// Receipts closed by this call
+    pub receipts: u32,
+    pub lamports_reclaimed: u64,
+    /// Running totals of the pool's WeekArchive
+    pub receipts_archived: u32,
+    pub receipts_hash: [u8; 32],
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct RegistrationsClosed {
+    pub prize_pool: Pubkey,
+    pub registrations: u32,
+    /// Rent returned to the players who paid for them
+    pub lamports_reclaimed: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct BetPlaced {
+    pub prize_pool: Pubkey,
+    pub bettor: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    /// Staked on this player so far, by everyone
+    pub player_total: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct BetsSettled {
+    pub prize_pool: Pubkey,
+    pub winner: Pubkey,
+    pub total_staked: u64,
+    pub winning_stake: u64,
+    pub rake: u64,
+    pub payout_pool: u64,
+}
+
+#[event]
+pub struct BetWinningsClaimed {
+    pub prize_pool: Pubkey,
+    pub bettor: Pubkey,
+    pub stake: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BetRefunded {
+    pub prize_pool: Pubkey,
+    pub bettor: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AccountingOk {
+    pub prize_pool: Pubkey,
+    pub balance: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct AccountingMismatch {
+    pub prize_pool: Pubkey,
+    /// None if the pool's counters record more going out than came in
+    pub expected: Option<u64>,
+    /// Above expected when tokens were sent to the vault outside fund_pool
+    pub balance: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct WalletAgeAttested {
+    pub wallet: Pubkey,
+    pub registrar: Pubkey,
+    /// Unix timestamp of the wallet's first on-chain activity
+    pub first_seen_at: i64,
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct EntryBondSlashed {
+    pub prize_pool: Pubkey,
+    pub player: Pubkey,
+    pub guardian: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct EntryBondRefunded {
+    pub prize_pool: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct CheaterReported {
+    pub prize_pool: Pubkey,
+    pub wallet: Pubkey,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub bond: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct CheatReportResolved {
+    pub prize_pool: Pubkey,
+    pub wallet: Pubkey,
+    pub reporter: Pubkey,
+    pub upheld: bool,
+    /// Entry bond slashed from the reported player, 0 if rejected or already slashed
+    pub slashed: u64,
+    /// Paid to the reporter out of `slashed`
+    pub bounty: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+#[event]
+pub struct InsuranceClaimPaid {
+    /// Pool whose payout the program or judge failure affected
+    pub prize_pool: Pubkey,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    /// Hash of the incident report the guardian approved the claim on
+    pub incident_hash: [u8; 32],
+    pub guardian: Pubkey,
+    /// Left in the insurance vault after this claim
+    pub remaining: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// post_scoring_transcript published the judge's transcript for a pool
+#[event]
+pub struct ScoringTranscriptPosted {
+    pub prize_pool: Pubkey,
+    pub transcript_hash: [u8; 32],
+    pub uri: String,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// verify_scoring_proof checked a Groth16 proof of the pool's results
+#[event]
+pub struct ScoringProofVerified {
+    pub prize_pool: Pubkey,
+    pub leaderboard_root: [u8; 32],
+    pub transcript_hash: [u8; 32],
+    pub week_id: String,
+    pub category: String,
+}
+
+/// One of a pool's judges scored a player
+#[event]
+pub struct JudgeScoreSubmitted {
+    pub judge: Pubkey,
+    pub player: Pubkey,
+    pub raw_score: u16,
+    /// Scores held for the player so far, this one included
+    pub submissions: u8,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// aggregate_scores recorded a player's trimmed mean as their entry's raw score
+#[event]
+pub struct ScoresAggregated {
+    pub player: Pubkey,
+    /// Judge scores combined, the discarded ones included
+    pub submissions: u8,
+    /// The single highest and lowest submissions, left out of the mean
+    pub discarded_high: u16,
+    pub discarded_low: u16,
+    pub raw_score: u16,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// rollover_week opened the next numbered week's pool
+#[event]
+pub struct WeekStarted {
+    pub prize_pool: Pubkey,
+    pub week_index: u32,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    /// Archived pool whose surplus was carried in, if one was passed
+    pub rollover_source: Option<Pubkey>,
+    /// Surplus carried in from rollover_source
+    pub rolled_over: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// sweep_expired found a winner who didn't claim before the claim deadline
+#[event]
+pub struct PrizeExpired {
+    pub winner: Pubkey,
+    /// 0 for an airdrop share
+    pub rank: u8,
+    pub score: u8,
+    /// The prize the claim would have paid, before any sponsor or streak bonus
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// The first sweep_expired sent what the winners left unclaimed to the treasury
+#[event]
+pub struct ExpiredPrizesSwept {
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// sweep_expired closed the receipts of claimed leaves into the WeekArchive
+#[event]
+pub struct StaleReceiptsClosed {
+    pub prize_pool: Pubkey,
+    /// Receipts closed by this call
+    pub receipts: u32,
+    pub lamports_reclaimed: u64,
+    /// Running totals of the pool's WeekArchive
+    pub receipts_archived: u32,
+    pub receipts_hash: [u8; 32],
+    pub week_id: String,
+    pub category: String,
+}
+
+/// fund_rent_rebates set a pool's rent rebate cap and topped up its reserve
+#[event]
+pub struct RentRebatesFunded {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub rent_rebate_cap: u64,
+    pub rent_rebate_reserve: u64,
+    pub week_id: String,
+    pub category: String,
+}
+
+/// A claim paid the winner back some of the rent it cost them
+#[event]
+pub struct RentRebatePaid {
+    pub winner: Pubkey,
+    pub amount: u64,
+    pub rent_rebate_reserve: u64,
+    pub week_id: String,
+    pub category: String,
+}
@@ -0,0 +1,99 @@
+//! Groth16 verification over BN254 through the alt_bn128 syscalls, as used by
+//! verify_scoring_proof. Points use the EIP-197 big-endian encoding: G1 is
+//! x || y, G2 is x.c1 || x.c0 || y.c1 || y.c0.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::prelude::{
+    alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing,
+};
+
+use crate::errors::ErrorCode;
+use crate::state::{ScoringVerifyingKey, SCORING_PROOF_INPUTS};
+
+/// BN254 base field modulus, big-endian
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// A Groth16 proof as produced by snarkjs or arkworks, re-encoded per EIP-197
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScoringProof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
+
+/**
+ * The public inputs a scoring proof is checked against: the leaderboard root,
+ * then the scoring transcript hash, each split into its high and low 128 bits
+ * so every input stays below the scalar field modulus
+ */
+pub fn scoring_inputs(
+    leaderboard_root: &[u8; 32],
+    transcript_hash: &[u8; 32],
+) -> [[u8; 32]; SCORING_PROOF_INPUTS] {
+    let mut inputs = [[0; 32]; SCORING_PROOF_INPUTS];
+    for (i, half) in leaderboard_root
+        .chunks(16)
+        .chain(transcript_hash.chunks(16))
+        .enumerate()
+    {
+        inputs[i][16..].copy_from_slice(half);
+    }
+    inputs
+}
+
+/// -P for a G1 point: (x, p - y), leaving the point at infinity as is
+pub fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    if point.iter().all(|&byte| byte == 0) {
+        return *point;
+    }
+    let mut negated = *point;
+    let mut borrow = 0;
+    for i in (0..32).rev() {
+        let diff = FIELD_MODULUS[i] as i16 - point[32 + i] as i16 - borrow;
+        negated[32 + i] = diff.rem_euclid(256) as u8;
+        borrow = (diff < 0) as i16;
+    }
+    negated
+}
+
+/// ic[0] + inputs[0] * ic[1] + ... + inputs[n - 1] * ic[n]
+pub fn input_point(ic: &[[u8; 64]], inputs: &[[u8; 32]]) -> Result<[u8; 64]> {
+    require!(ic.len() == inputs.len() + 1, ErrorCode::InvalidScoringProof);
+    let mut acc = ic[0];
+    for (point, input) in ic[1..].iter().zip(inputs) {
+        let product = alt_bn128_multiplication(&[&point[..], &input[..]].concat())
+            .map_err(|_| ErrorCode::InvalidScoringProof)?;
+        let sum = alt_bn128_addition(&[&acc[..], &product[..]].concat())
+            .map_err(|_| ErrorCode::InvalidScoringProof)?;
+        acc.copy_from_slice(&sum);
+    }
+    Ok(acc)
+}
+
+/**
+ * e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+ * Malformed points fail with InvalidScoringProof rather than returning false.
+ */
+pub fn verify(
+    key: &ScoringVerifyingKey,
+    proof: &ScoringProof,
+    inputs: &[[u8; 32]],
+) -> Result<bool> {
+    let vk_x = input_point(&key.ic, inputs)?;
+    let pairing_input = [
+        &negate_g1(&proof.a)[..],
+        &proof.b[..],
+        &key.alpha_g1[..],
+        &key.beta_g2[..],
+        &vk_x[..],
+        &key.gamma_g2[..],
+        &proof.c[..],
+        &key.delta_g2[..],
+    ]
+    .concat();
+    let result = alt_bn128_pairing(&pairing_input).map_err(|_| ErrorCode::InvalidScoringProof)?;
+    Ok(result.last() == Some(&1) && result[..result.len() - 1].iter().all(|&byte| byte == 0))
+}
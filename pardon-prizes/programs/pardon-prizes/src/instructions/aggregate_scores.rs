@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ScoresAggregated;
+use crate::instructions::submit_score::record_score;
+use crate::math::trimmed_mean;
+use crate::state::{Entry, JudgeScores, PrizePool, Registration};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AggregateScores<'info> {
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"judge_scores", prize_pool.key().as_ref(), judge_scores.player.as_ref()],
+        bump = judge_scores.bump
+    )]
+    pub judge_scores: Account<'info, JudgeScores>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Entry::INIT_SPACE,
+        seeds = [b"entry", prize_pool.key().as_ref(), judge_scores.player.as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, Entry>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Required on collection- and gatekeeper-gated pools
+    #[account(
+        seeds = [b"registration", prize_pool.key().as_ref(), judge_scores.player.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Option<Account<'info, Registration>>,
+}
+
+pub fn handler(ctx: Context<AggregateScores>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let judge_scores = &mut ctx.accounts.judge_scores;
+    require!(!judge_scores.aggregated, ErrorCode::ScoresAggregated);
+
+    // Only judges still on the panel count towards the quorum
+    let mut raw_scores: Vec<u16> = judge_scores
+        .scores
+        .iter()
+        .filter(|score| prize_pool.judges.contains(&score.judge))
+        .map(|score| score.raw_score)
+        .collect();
+    require!(
+        !prize_pool.judges.is_empty() && raw_scores.len() >= prize_pool.judge_quorum as usize,
+        ErrorCode::QuorumNotReached
+    );
+    // A quorum alone isn't final until judging ends, so late judges still count
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        raw_scores.len() == prize_pool.judges.len() || now >= prize_pool.judging_ends_at,
+        ErrorCode::JudgingOpen
+    );
+    let raw_score = trimmed_mean(&raw_scores).ok_or(ErrorCode::QuorumNotReached)?;
+    raw_scores.sort_unstable();
+
+    let player = judge_scores.player;
+    let event = record_score(
+        prize_pool,
+        &mut ctx.accounts.entry,
+        player,
+        raw_score,
+        judge_scores.multiplier_bps,
+        ctx.accounts.registration.is_some(),
+    )?;
+    judge_scores.aggregated = true;
+    ctx.accounts.entry.bump = ctx.bumps.entry;
+    emit_cpi!(event);
+
+    emit_cpi!(ScoresAggregated {
+        player,
+        submissions: raw_scores.len() as u8,
+        discarded_high: raw_scores[raw_scores.len() - 1],
+        discarded_low: raw_scores[0],
+        raw_score,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
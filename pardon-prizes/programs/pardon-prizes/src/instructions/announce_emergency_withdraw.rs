@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawAnnounced;
+use crate::state::{Config, PrizePool, EMERGENCY_TIMELOCK_SECONDS};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AnnounceEmergencyWithdraw<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(constraint = destination.mint == prize_pool.mint @ ErrorCode::MintMismatch)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub guardian: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AnnounceEmergencyWithdraw>) -> Result<()> {
+    let unlock_at = Clock::get()?.unix_timestamp + EMERGENCY_TIMELOCK_SECONDS;
+    let destination = ctx.accounts.destination.key();
+
+    // Announcing again restarts the timelock for the new destination
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.emergency_destination = Some(destination);
+    prize_pool.emergency_unlock_at = unlock_at;
+
+    emit_cpi!(EmergencyWithdrawAnnounced {
+        guardian: ctx.accounts.guardian.key(),
+        destination,
+        amount: prize_pool.emergency_withdrawable(ctx.accounts.prize_pool_token_account.amount),
+        unlock_at,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
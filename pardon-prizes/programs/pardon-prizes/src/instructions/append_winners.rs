@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::MAX_RANK;
+use crate::state::{ListedWinner, PoolRole, PrizePool, WinnerList, DELEGATE_DISTRIBUTE_PRIZES};
+
+#[derive(Accounts)]
+pub struct AppendWinners<'info> {
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Distribution,
+            DELEGATE_DISTRIBUTE_PRIZES,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, seeds = [b"winner_list", prize_pool.key().as_ref()], bump)]
+    pub winner_list: AccountLoader<'info, WinnerList>,
+    
+    /// The pool's distributor or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+}
+
+/**
+ * `entries` is packed ListedWinner records back to back, ListedWinner::INIT_SPACE
+ * bytes each; distribute_prizes checks their order when it pays them
+ */
+pub fn handler(ctx: Context<AppendWinners>, entries: Vec<u8>) -> Result<()> {
+    require!(
+        !entries.is_empty()
+            && entries.chunks_exact(ListedWinner::INIT_SPACE).remainder().is_empty(),
+        ErrorCode::InvalidWinnerListEntries
+    );
+
+    let mut winner_list = ctx.accounts.winner_list.load_mut()?;
+    let start = winner_list.count as usize;
+    let end = start + entries.len() / ListedWinner::INIT_SPACE;
+    require!(end <= MAX_RANK as usize, ErrorCode::WinnerListFull);
+
+    for (slot, raw) in winner_list.entries[start..end]
+        .iter_mut()
+        .zip(entries.chunks_exact(ListedWinner::INIT_SPACE))
+    {
+        let winner: ListedWinner = bytemuck::pod_read_unaligned(raw);
+        require!(winner.is_well_formed(), ErrorCode::InvalidWinnerListEntries);
+        *slot = winner;
+    }
+    winner_list.count = end as u8;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::WeekArchived;
+use crate::state::{ClaimRecord, PoolRole, PrizePool, WeekArchive, DELEGATE_ARCHIVE_WEEK};
+
+/**
+ * Remaining accounts are (claim_record, winner) pairs: each receipt is folded
+ * into the pool's WeekArchive and closed, refunding its rent to the winner
+ * who paid for it, less any rent rebate the pool paid them, which goes back
+ * to the pool. Can be called in batches until every receipt is gone.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ArchiveWeek<'info> {
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Closing,
+            DELEGATE_ARCHIVE_WEEK,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + WeekArchive::INIT_SPACE,
+        seeds = [b"week_archive", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub week_archive: Account<'info, WeekArchive>,
+    
+    /// The pool's closer or its delegate, see PoolRoles
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ArchiveWeek<'info>>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(!prize_pool.payouts_pending(), ErrorCode::PayoutsNotStarted);
+    // A distributed pool takes no claims; a claimed one must let every winner claim first
+    require!(
+        prize_pool.distribution_nonce > 0
+            || Clock::get()?.unix_timestamp >= prize_pool.claim_deadline,
+        ErrorCode::ClaimWindowOpen
+    );
+    require!(
+        ctx.remaining_accounts.chunks_exact(2).remainder().is_empty(),
+        ErrorCode::ArchiveWinnerMismatch
+    );
+
+    let prize_pool_key = prize_pool.key();
+    let accounts = &mut *ctx.accounts;
+    let archive = &mut accounts.week_archive;
+    archive.open(prize_pool_key, &accounts.prize_pool, ctx.bumps.week_archive);
+
+    let mut receipts = 0;
+    let mut lamports_reclaimed = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (info, winner) = (&pair[0], &pair[1]);
+        let record = Account::<ClaimRecord>::try_from(info)?;
+        require_keys_eq!(record.prize_pool, prize_pool_key, ErrorCode::ArchiveReceiptMismatch);
+        require_keys_eq!(winner.key(), record.wallet, ErrorCode::ArchiveWinnerMismatch);
+
+        lamports_reclaimed += close_claim_record(record, winner, &mut accounts.prize_pool, archive)?;
+        receipts += 1;
+    }
+
+    // Closed receipts no longer stop a second claim, so claims end here
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    if !prize_pool.archived {
+        prize_pool.archived = true;
+        prize_pool.archived_at = Clock::get()?.unix_timestamp;
+    }
+
+    let archive = &ctx.accounts.week_archive;
+    emit_cpi!(WeekArchived {
+        prize_pool: prize_pool.key(),
+        receipts,
+        lamports_reclaimed,
+        receipts_archived: archive.receipts_archived,
+        receipts_hash: archive.receipts_hash,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
+
+/**
+ * Folds a claim receipt into the week's archive and closes it. The rent the
+ * pool rebated for it goes back to the pool's reserve, the rest to the winner
+ * who paid for it. Returns what the winner got back.
+ */
+pub(crate) fn close_claim_record<'info>(
+    record: Account<'info, ClaimRecord>,
+    winner: &AccountInfo<'info>,
+    prize_pool: &mut Account<'info, PrizePool>,
+    archive: &mut WeekArchive,
+) -> Result<u64> {
+    let info = record.to_account_info();
+    // Rent the pool already paid back goes to the pool, not to the winner again
+    let returned = record.rent_rebate.min(info.lamports());
+    if returned > 0 {
+        info.sub_lamports(returned)?;
+        prize_pool.add_lamports(returned)?;
+        prize_pool.rent_rebate_reserve += returned;
+    }
+    let lamports = info.lamports();
+    archive.record(record.archive_leaf(), record.amount, lamports);
+    record.close(winner.clone())?;
+    Ok(lamports)
+}
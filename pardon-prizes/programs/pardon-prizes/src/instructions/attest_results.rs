@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::ResultsAttested;
+use crate::state::PrizePool;
+use crate::wormhole::{
+    message_fee, next_sequence, post_message, results_payload, PostMessage, WORMHOLE_PROGRAM_ID,
+};
+
+/**
+ * Wormhole accounts are checked by seeds against the core bridge; the bridge
+ * itself validates their contents during the CPI
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AttestResults<'info> {
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// CHECK: Core bridge config, seeds ["Bridge"]
+    #[account(mut, seeds = [b"Bridge"], bump, seeds::program = WORMHOLE_PROGRAM_ID)]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+    
+    /// CHECK: Created by the core bridge; one attestation per pool
+    #[account(mut, seeds = [b"attestation", prize_pool.key().as_ref()], bump)]
+    pub wormhole_message: UncheckedAccount<'info>,
+    
+    /// CHECK: This program's emitter, only used as a signer
+    #[account(seeds = [b"emitter"], bump)]
+    pub wormhole_emitter: UncheckedAccount<'info>,
+    
+    /// CHECK: Emitter sequence tracked by the core bridge
+    #[account(
+        mut,
+        seeds = [b"Sequence", wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = WORMHOLE_PROGRAM_ID
+    )]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the message fee
+    #[account(mut, seeds = [b"fee_collector"], bump, seeds::program = WORMHOLE_PROGRAM_ID)]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Core bridge program
+    #[account(address = WORMHOLE_PROGRAM_ID)]
+    pub wormhole_program: UncheckedAccount<'info>,
+    
+    pub clock: Sysvar<'info, Clock>,
+    
+    pub rent: Sysvar<'info, Rent>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AttestResults>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let root = prize_pool
+        .leaderboard_root
+        .ok_or(ErrorCode::LeaderboardNotFinalized)?;
+    // The message is posted once, so the totals it carries must be final
+    require!(prize_pool.results_settled(), ErrorCode::ResultsNotSettled);
+    let payload = results_payload(prize_pool, &root);
+
+    let fee = message_fee(&ctx.accounts.wormhole_bridge)?;
+    if fee > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, fee)?;
+    }
+
+    let sequence = next_sequence(&ctx.accounts.wormhole_sequence)?;
+    let prize_pool_key = prize_pool.key();
+    let message_seeds = &[
+        b"attestation",
+        prize_pool_key.as_ref(),
+        &[ctx.bumps.wormhole_message],
+    ];
+    let emitter_seeds = &[b"emitter".as_ref(), &[ctx.bumps.wormhole_emitter]];
+    post_message(
+        PostMessage {
+            bridge: &ctx.accounts.wormhole_bridge,
+            message: &ctx.accounts.wormhole_message,
+            emitter: &ctx.accounts.wormhole_emitter,
+            sequence: &ctx.accounts.wormhole_sequence,
+            payer: &ctx.accounts.payer,
+            fee_collector: &ctx.accounts.wormhole_fee_collector,
+            clock: &ctx.accounts.clock.to_account_info(),
+            rent: &ctx.accounts.rent.to_account_info(),
+            system_program: &ctx.accounts.system_program,
+            wormhole_program: &ctx.accounts.wormhole_program,
+        },
+        payload,
+        &[&message_seeds[..], &emitter_seeds[..]],
+    )?;
+
+    emit_cpi!(ResultsAttested {
+        emitter: ctx.accounts.wormhole_emitter.key(),
+        sequence,
+        leaderboard_root: root,
+        winners_hash: prize_pool.winners_hash,
+        total_distributed: prize_pool.total_distributed,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::ed25519;
+use crate::errors::ErrorCode;
+use crate::events::WalletAgeAttested;
+use crate::state::PlayerProfile;
+
+/// Prefix of the message a registrar signs to attest a wallet's age
+pub const WALLET_AGE_PREFIX: &[u8] = b"pardon-prizes:wallet-age";
+
+/**
+ * Bytes the registrar signs: the prefix, the program id, then `wallet` and
+ * the unix timestamp of its first on-chain activity (i64 LE)
+ * A wallet's first activity never changes, so replaying one is harmless.
+ */
+pub fn wallet_age_message(wallet: &Pubkey, first_seen_at: i64) -> Vec<u8> {
+    [
+        WALLET_AGE_PREFIX,
+        crate::ID.as_ref(),
+        wallet.as_ref(),
+        &first_seen_at.to_le_bytes(),
+    ]
+    .concat()
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AttestWalletAge<'info> {
+    /// Created here so wallets can be attested before they ever sign
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", wallet.as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    /// CHECK: the instructions sysvar, holding the registrar's ed25519 sig-verify
+    #[account(address = instructions_sysvar::ID @ ErrorCode::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AttestWalletAge>,
+    wallet: Pubkey,
+    registrar: Pubkey,
+    first_seen_at: i64,
+) -> Result<()> {
+    require!(
+        first_seen_at > 0 && first_seen_at <= Clock::get()?.unix_timestamp,
+        ErrorCode::InvalidWalletAge
+    );
+    let message = wallet_age_message(&wallet, first_seen_at);
+    ed25519::verify_previous_ix(&ctx.accounts.instructions.to_account_info(), &registrar, &message)?;
+
+    let profile = &mut ctx.accounts.player_profile;
+    if profile.wallet == Pubkey::default() {
+        profile.init(wallet, ctx.bumps.player_profile);
+    }
+    profile.first_seen_at = first_seen_at;
+    profile.age_registrar = registrar;
+
+    emit_cpi!(WalletAgeAttested {
+        wallet,
+        registrar,
+        first_seen_at,
+        relayer: ctx.accounts.relayer.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::ed25519;
+use crate::errors::ErrorCode;
+use crate::events::PayoutWalletAuthorized;
+use crate::state::PlayerProfile;
+
+/// Prefix of the message a winner signs to redirect their prizes
+pub const PAYOUT_AUTHORIZATION_PREFIX: &[u8] = b"pardon-prizes:payout";
+
+/**
+ * Bytes the winner signs: the prefix, the program id, then `wallet`,
+ * `payout_wallet` and the profile's current payout_nonce (u64 LE)
+ */
+pub fn payout_authorization_message(
+    wallet: &Pubkey,
+    payout_wallet: &Pubkey,
+    nonce: u64,
+) -> Vec<u8> {
+    [
+        PAYOUT_AUTHORIZATION_PREFIX,
+        crate::ID.as_ref(),
+        wallet.as_ref(),
+        payout_wallet.as_ref(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AuthorizePayoutWallet<'info> {
+    /// Created here for wallets that can't sign create_player_profile
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", wallet.as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    /// CHECK: the instructions sysvar, holding the winner's ed25519 sig-verify
+    #[account(address = instructions_sysvar::ID @ ErrorCode::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AuthorizePayoutWallet>,
+    wallet: Pubkey,
+    payout_wallet: Pubkey,
+) -> Result<()> {
+    let profile = &mut ctx.accounts.player_profile;
+    if profile.wallet == Pubkey::default() {
+        profile.init(wallet, ctx.bumps.player_profile);
+    }
+
+    let message = payout_authorization_message(&wallet, &payout_wallet, profile.payout_nonce);
+    ed25519::verify_previous_ix(&ctx.accounts.instructions.to_account_info(), &wallet, &message)?;
+
+    let payout_nonce = profile.payout_nonce;
+    profile.payout_wallet = Some(payout_wallet);
+    profile.payout_nonce += 1;
+
+    emit_cpi!(PayoutWalletAuthorized {
+        wallet,
+        payout_wallet,
+        payout_nonce,
+        relayer: ctx.accounts.relayer.key(),
+    });
+
+    Ok(())
+}
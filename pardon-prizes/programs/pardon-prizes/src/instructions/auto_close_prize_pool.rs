@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, PrizePool};
+
+/// Permissionless close_prize_pool for archived pools past the retention period;
+/// the rent still goes to the pool authority
+#[derive(Accounts)]
+pub struct AutoClosePrizePool<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        close = authority,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    
+    pub cranker: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AutoClosePrizePool>) -> Result<()> {
+    let retention = ctx.accounts.config.auto_close_after_secs;
+    require!(retention > 0, ErrorCode::AutoCloseDisabled);
+
+    // Archiving is what settles a week: claims end and receipts are folded away
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(prize_pool.archived, ErrorCode::PoolNotArchived);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= prize_pool.archived_at.saturating_add(retention),
+        ErrorCode::RetentionPeriodActive
+    );
+
+    require!(prize_pool.votes_escrowed == 0, ErrorCode::VotesStillEscrowed);
+    require!(prize_pool.bonds_held == 0, ErrorCode::BondsStillHeld);
+    require!(
+        ctx.accounts.prize_pool_token_account.amount == 0,
+        ErrorCode::PoolNotEmpty
+    );
+
+    Ok(())
+}
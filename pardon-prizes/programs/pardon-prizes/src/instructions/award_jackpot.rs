@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::JackpotWon;
+use crate::state::{Entry, Jackpot, PoolRole, PrizePool, DELEGATE_AWARD_JACKPOT};
+use crate::vrf::{
+    fulfilled_randomness, jackpot_draw_seed, pick_index, randomness_address, ORAO_VRF_PROGRAM_ID,
+};
+
+/**
+ * Every entry of the pool that finalize_entry marked jackpot_qualified is
+ * passed as a remaining account, in ascending address order. With more than one, the
+ * winner is drawn from the ORAO randomness requested with
+ * jackpot_draw_seed(prize_pool).
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AwardJackpot<'info> {
+    #[account(mut, seeds = [b"jackpot"], bump = jackpot.bump)]
+    pub jackpot: Account<'info, Jackpot>,
+    
+    #[account(mut, address = jackpot.vault @ ErrorCode::VaultMismatch)]
+    pub jackpot_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Distribution,
+            DELEGATE_AWARD_JACKPOT,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        constraint = winner_token_account.mint == jackpot_vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+    
+    /// The pool's distributor or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    /// CHECK: Owner and address checked against the draw's VRF request in the handler
+    pub randomness: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AwardJackpot<'info>>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(!prize_pool.jackpot_drawn, ErrorCode::JackpotAlreadyDrawn);
+    require!(prize_pool.results_final(), ErrorCode::ResultsPending);
+    let amount = ctx.accounts.jackpot_vault.amount;
+    require!(amount > 0, ErrorCode::InsufficientFunds);
+
+    let mut qualifiers = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut previous: Option<Pubkey> = None;
+    for info in ctx.remaining_accounts {
+        let entry = Account::<Entry>::try_from(info)?;
+        require_keys_eq!(entry.prize_pool, prize_pool.key(), ErrorCode::JackpotEntryMismatch);
+        require!(entry.jackpot_qualified, ErrorCode::BelowJackpotTrigger);
+        // Ascending addresses rule out passing one entry twice to tilt the draw
+        if let Some(previous) = previous {
+            require!(info.key() > previous, ErrorCode::JackpotEntriesNotSorted);
+        }
+        previous = Some(info.key());
+        qualifiers.push(entry.player);
+    }
+    require!(!qualifiers.is_empty(), ErrorCode::NoJackpotQualifiers);
+    // Leaving a qualifier out would shrink the draw to the caller's favourites
+    require!(
+        qualifiers.len() == prize_pool.jackpot_qualifiers as usize,
+        ErrorCode::JackpotQualifiersMissing
+    );
+
+    let index = if qualifiers.len() == 1 {
+        0
+    } else {
+        let randomness = ctx
+            .accounts
+            .randomness
+            .as_ref()
+            .ok_or(ErrorCode::RandomnessRequired)?;
+        require_keys_eq!(*randomness.owner, ORAO_VRF_PROGRAM_ID, ErrorCode::InvalidRandomness);
+        require_keys_eq!(
+            randomness.key(),
+            randomness_address(&jackpot_draw_seed(&prize_pool.key())),
+            ErrorCode::InvalidRandomness
+        );
+        let value = fulfilled_randomness(&randomness.try_borrow_data()?)
+            .ok_or(ErrorCode::RandomnessNotFulfilled)?;
+        pick_index(&value, qualifiers.len())
+    };
+    let winner = qualifiers[index];
+    require_keys_eq!(
+        ctx.accounts.winner_token_account.owner,
+        winner,
+        ErrorCode::TokenOwnerMismatch
+    );
+
+    let seeds = &[b"jackpot".as_ref(), &[ctx.accounts.jackpot.bump]];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.jackpot_vault.to_account_info(),
+        to: ctx.accounts.winner_token_account.to_account_info(),
+        authority: ctx.accounts.jackpot.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    ctx.accounts.jackpot.total_awarded += amount;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.jackpot_drawn = true;
+
+    emit_cpi!(JackpotWon {
+        winner,
+        amount,
+        qualifiers: qualifiers.len() as u32,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
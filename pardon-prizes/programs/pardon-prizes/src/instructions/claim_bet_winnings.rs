@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::settle_bets::pay_from_bet_vault;
+use crate::errors::ErrorCode;
+use crate::events::BetWinningsClaimed;
+use crate::math::parimutuel_payout;
+use crate::state::{Bet, BetMarket};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBetWinnings<'info> {
+    #[account(
+        seeds = [b"bet_market", bet_market.prize_pool.as_ref()],
+        bump = bet_market.bump
+    )]
+    pub bet_market: Account<'info, BetMarket>,
+    
+    #[account(mut, address = bet_market.vault @ ErrorCode::VaultMismatch)]
+    pub bet_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        close = bettor,
+        seeds = [b"bet", bet_market.key().as_ref(), bettor.key().as_ref()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        mut,
+        constraint = bettor_token_account.mint == bet_vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub bettor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimBetWinnings>) -> Result<()> {
+    let market = &ctx.accounts.bet_market;
+    let winner = market.winner.ok_or(ErrorCode::BetsNotSettled)?;
+    let stake = ctx.accounts.bet.amount;
+    let amount = if market.winning_stake == 0 {
+        stake
+    } else {
+        require_keys_eq!(ctx.accounts.bet.player, winner, ErrorCode::BetLost);
+        parimutuel_payout(stake, market.payout_pool, market.winning_stake)
+    };
+
+    if amount > 0 {
+        let to = ctx.accounts.bettor_token_account.to_account_info();
+        pay_from_bet_vault(
+            &ctx.accounts.bet_market,
+            &ctx.accounts.bet_vault,
+            &to,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    }
+
+    emit_cpi!(BetWinningsClaimed {
+        prize_pool: market.prize_pool,
+        bettor: ctx.accounts.bettor.key(),
+        stake,
+        amount,
+    });
+
+    Ok(())
+}
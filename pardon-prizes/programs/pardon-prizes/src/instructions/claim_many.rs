@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::claim_prize::{
+    claim_rent, open_winner_token_account, pay_claim, pay_rent_rebate, PrizeClaim,
+};
+use crate::errors::ErrorCode;
+use crate::state::{
+    BalanceSnapshot, ClaimRecord, ClaimRequest, Config, GlobalStats, MintStats, PlayerProfile,
+    PrizePool, ProgramState, WeekStats, FEATURE_CLAIMS,
+};
+
+/// Remaining accounts claim_many takes per claim
+pub const CLAIM_MANY_ACCOUNTS: usize = 5;
+
+/**
+ * Claims the signer's prizes in several pools of one mint at once. Remaining
+ * accounts hold CLAIM_MANY_ACCOUNTS per claim, in the order of `claims`:
+ * prize_pool, its vault, the ["claim", prize_pool, winner] receipt to create,
+ * the pool's ["week_stats", prize_pool], and the winner's
+ * ["snapshot", prize_pool, winner] on holder-gated pools, else any account.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = program_state.is_enabled(FEATURE_CLAIMS) @ ErrorCode::FeatureDisabled
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    /// CHECK: A token account of the winner's payout wallet for `mint`, checked by
+    /// open_winner_token_account, which opens it if it is the winner's associated token
+    /// account and doesn't exist yet
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == config.creator @ ErrorCode::CreatorAccountMismatch,
+        constraint = creator_token_account.mint == mint.key() @ ErrorCode::MintMismatch
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub winner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    #[account(
+        mut,
+        seeds = [b"mint_stats", mint.key().as_ref()],
+        bump = mint_stats.bump
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+    
+    /// Required once stakers are owed a share of pools in the stake mint
+    #[account(mut, seeds = [b"staker_rewards"], bump)]
+    pub staker_rewards_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the config feeds pools in this mint to the jackpot
+    #[account(mut, seeds = [b"jackpot_vault"], bump)]
+    pub jackpot_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Tracks the winner's placing streak; without it the claims earn no streak bonus
+    #[account(
+        mut,
+        seeds = [b"player", winner.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Option<Account<'info, PlayerProfile>>,
+    
+    /// Required with player_profile while the config pays streak bonuses in this mint
+    #[account(mut, seeds = [b"streak_vault"], bump)]
+    pub streak_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Checked by check_gateway_token; required with gatekeeper-gated pools
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    
+    /// Required while the config feeds pools in this mint to the insurance fund
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Every pool claimed from pays out in this mint
+    pub mint: Box<Account<'info, Mint>>,
+    
+    /// Required when the claims open the winner's token account
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
+    /// Required while the winner's PlayerProfile splits prizes: the recipient's token account
+    #[account(mut)]
+    pub split_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Must not exist; a team's prize is split by distribute_prizes, not claimed
+    #[account(seeds = [b"team", winner.key().as_ref()], bump)]
+    pub team: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>,
+    claims: Vec<ClaimRequest>,
+) -> Result<()> {
+    require!(
+        !claims.is_empty() && ctx.remaining_accounts.len() == claims.len() * CLAIM_MANY_ACCOUNTS,
+        ErrorCode::InvalidClaimBatch
+    );
+
+    let wallet = ctx.accounts.winner.key();
+    let mint = ctx.accounts.mint.key();
+    let paid_to = ctx
+        .accounts
+        .player_profile
+        .as_ref()
+        .and_then(|profile| profile.payout_wallet)
+        .unwrap_or(wallet);
+    // Only the first claim can have paid for the winner's token account
+    let mut opened_token_account = open_winner_token_account(
+        &ctx.accounts.winner_token_account,
+        &ctx.accounts.winner,
+        &paid_to,
+        &mint,
+        Some(&ctx.accounts.mint),
+        ctx.accounts.associated_token_program.as_ref(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+    for (claim, group) in claims
+        .into_iter()
+        .zip(ctx.remaining_accounts.chunks(CLAIM_MANY_ACCOUNTS))
+    {
+        let mut prize_pool = Account::<PrizePool>::try_from(&group[0])?;
+        let prize_pool_key = prize_pool.key();
+        require_keys_eq!(group[1].key(), prize_pool.vault, ErrorCode::VaultMismatch);
+        let vault = Account::<TokenAccount>::try_from(&group[1])?;
+        require_keys_eq!(prize_pool.mint, mint, ErrorCode::MintMismatch);
+
+        let receipt_seeds: &[&[u8]] = &[b"claim", prize_pool_key.as_ref(), wallet.as_ref()];
+        let receipt = &group[2];
+        let receipt_bump = create_pda(
+            &ctx.accounts.winner,
+            receipt,
+            &ctx.accounts.system_program,
+            receipt_seeds,
+            8 + ClaimRecord::INIT_SPACE,
+        )?;
+
+        let stats_seeds: &[&[u8]] = &[b"week_stats", prize_pool_key.as_ref()];
+        let stats_info = &group[3];
+        let opened_week_stats = stats_info.data_is_empty();
+        let mut week_stats = if opened_week_stats {
+            let bump = create_pda(
+                &ctx.accounts.winner,
+                stats_info,
+                &ctx.accounts.system_program,
+                stats_seeds,
+                8 + WeekStats::INIT_SPACE,
+            )?;
+            // Zeroed until exit writes it, discriminator included
+            let mut week_stats = Account::<WeekStats>::try_from_unchecked(stats_info)?;
+            week_stats.open(prize_pool_key, &prize_pool, bump);
+            week_stats
+        } else {
+            let (expected, _) = Pubkey::find_program_address(stats_seeds, &crate::ID);
+            require_keys_eq!(stats_info.key(), expected, ErrorCode::ClaimAccountMismatch);
+            Account::<WeekStats>::try_from(stats_info)?
+        };
+
+        let balance_snapshot = if prize_pool.min_holding > 0 {
+            let info = &group[4];
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"snapshot", prize_pool_key.as_ref(), wallet.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(info.key(), expected, ErrorCode::BalanceSnapshotMismatch);
+            require!(!info.data_is_empty(), ErrorCode::InsufficientHolding);
+            Some(Account::<BalanceSnapshot>::try_from(info)?)
+        } else {
+            None
+        };
+
+        let accounts = &mut *ctx.accounts;
+        let events = pay_claim(
+            PrizeClaim {
+                config: &mut accounts.config,
+                prize_pool: &mut prize_pool,
+                prize_pool_token_account: &vault,
+                creator_token_account: &accounts.creator_token_account,
+                winner_token_account: &accounts.winner_token_account,
+                split_token_account: accounts.split_token_account.as_ref(),
+                team: &accounts.team,
+                token_program: &accounts.token_program,
+                staker_rewards_vault: accounts.staker_rewards_vault.as_ref(),
+                jackpot_vault: accounts.jackpot_vault.as_ref(),
+                insurance_vault: accounts.insurance_vault.as_ref(),
+                streak_vault: accounts.streak_vault.as_ref(),
+                player_profile: accounts.player_profile.as_deref_mut(),
+                gateway_token: accounts.gateway_token.as_deref(),
+                balance_snapshot: balance_snapshot.as_deref(),
+                week_stats: &mut week_stats,
+                global_stats: &mut accounts.global_stats,
+                mint_stats: &mut accounts.mint_stats,
+            },
+            wallet,
+            claim.rank,
+            claim.score,
+            claim.proof,
+        )?;
+        let rent = claim_rent(opened_week_stats, opened_token_account)?;
+        opened_token_account = false;
+        let winner = ctx.accounts.winner.to_account_info();
+        let rent_rebate = pay_rent_rebate(&mut prize_pool, &winner, rent)?;
+
+        let record = ClaimRecord {
+            prize_pool: prize_pool_key,
+            wallet,
+            rank: events.claimed.rank,
+            score: claim.score,
+            amount: events.claimed.amount,
+            paid_to: events.claimed.paid_to,
+            split_recipient: events.claimed.split_recipient,
+            split_amount: events.claimed.split_amount,
+            rent_rebate: rent_rebate.as_ref().map_or(0, |event| event.amount),
+            bump: receipt_bump,
+        };
+        record.try_serialize(&mut &mut receipt.try_borrow_mut_data()?[..])?;
+        prize_pool.exit(&crate::ID)?;
+        week_stats.exit(&crate::ID)?;
+
+        if let Some(event) = events.fees.royalty {
+            emit_cpi!(event);
+        }
+        if let Some(event) = events.fees.staker_rewards {
+            emit_cpi!(event);
+        }
+        if let Some(event) = events.fees.jackpot {
+            emit_cpi!(event);
+        }
+        if let Some(event) = events.fees.insurance {
+            emit_cpi!(event);
+        }
+        emit_cpi!(events.claimed);
+        if let Some(event) = rent_rebate {
+            emit_cpi!(event);
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the program-owned PDA at `seeds`, paid for by `payer`, returning
+/// its bump; fails if `target` isn't that address or already exists
+fn create_pda<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    system: &Program<'info, System>,
+    seeds: &[&[u8]],
+    space: usize,
+) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(target.key(), expected, ErrorCode::ClaimAccountMismatch);
+
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+    let signer = [signer_seeds.as_slice()];
+    let cpi_ctx = CpiContext::new_with_signer(
+        system.to_account_info(),
+        CreateAccount {
+            from: payer.to_account_info(),
+            to: target.clone(),
+        },
+        &signer,
+    );
+    system_program::create_account(
+        cpi_ctx,
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+    Ok(bump)
+}
@@ -0,0 +1,632 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken, Create};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::distribute_prizes::{pay_from_config_vault, pay_from_vault, take_pool_fees};
+use crate::civic::check_gateway_token;
+use crate::errors::ErrorCode;
+use crate::events::{
+    CreatorRoyaltyPaid, InsuranceContributed, JackpotContributed, PrizeClaimed, RentRebatePaid,
+    StakerRewardsAdded,
+};
+use crate::math::{bps_of, calculate_prize_with, stake_bonus, tier_multiplier_bps, weighted_share};
+use crate::merkle::{leaderboard_leaf, verify_proof};
+use crate::state::{
+    BalanceSnapshot, ClaimRecord, Config, GlobalStats, MintStats, PlayerProfile, PrizePool,
+    ProgramState, WeekStats, FEATURE_CLAIMS,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPrize<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        constraint = program_state.is_enabled(FEATURE_CLAIMS) @ ErrorCode::FeatureDisabled
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = winner,
+        space = 8 + ClaimRecord::INIT_SPACE,
+        seeds = [b"claim", prize_pool.key().as_ref(), winner.key().as_ref()],
+        bump
+    )]
+    pub claim_record: Account<'info, ClaimRecord>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == config.creator @ ErrorCode::CreatorAccountMismatch,
+        constraint = creator_token_account.mint == prize_pool.mint @ ErrorCode::CreatorAccountMismatch
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: A token account of the winner's payout wallet (the winner unless redirected) for
+    /// the pool mint, checked by open_winner_token_account, which opens it if it is the
+    /// winner's associated token account and doesn't exist yet
+    #[account(mut)]
+    pub winner_token_account: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub winner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Required once stakers are owed a share of pools in the stake mint
+    #[account(mut, seeds = [b"staker_rewards"], bump)]
+    pub staker_rewards_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the config feeds pools in this mint to the jackpot
+    #[account(mut, seeds = [b"jackpot_vault"], bump)]
+    pub jackpot_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Tracks the winner's placing streak; without it the claim earns no streak bonus
+    #[account(
+        mut,
+        seeds = [b"player", winner.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Option<Account<'info, PlayerProfile>>,
+    
+    /// Required with player_profile while the config pays streak bonuses in this mint
+    #[account(mut, seeds = [b"streak_vault"], bump)]
+    pub streak_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Checked by check_gateway_token; required on gatekeeper-gated pools
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    
+    /// Required while the config feeds pools in this mint to the insurance fund
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the pool has a min_holding
+    #[account(
+        seeds = [b"snapshot", prize_pool.key().as_ref(), winner.key().as_ref()],
+        bump = balance_snapshot.bump
+    )]
+    pub balance_snapshot: Option<Account<'info, BalanceSnapshot>>,
+    
+    #[account(
+        init_if_needed,
+        payer = winner,
+        space = 8 + WeekStats::INIT_SPACE,
+        seeds = [b"week_stats", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub week_stats: Account<'info, WeekStats>,
+    
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    #[account(mut, seeds = [b"mint_stats", prize_pool.mint.as_ref()], bump = mint_stats.bump)]
+    pub mint_stats: Account<'info, MintStats>,
+    
+    /// Required with associated_token_program when the claim opens the winner's token account
+    #[account(address = prize_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: Option<Box<Account<'info, Mint>>>,
+    
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
+    /// Required while the winner's PlayerProfile splits prizes: the recipient's token account
+    #[account(mut)]
+    pub split_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Must not exist; a team's prize is split by distribute_prizes, not claimed
+    #[account(seeds = [b"team", winner.key().as_ref()], bump)]
+    pub team: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimPrize>,
+    rank: u32,
+    score: u8,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let prize_pool_key = ctx.accounts.prize_pool.key();
+    let wallet = ctx.accounts.winner.key();
+    let paid_to = ctx
+        .accounts
+        .player_profile
+        .as_ref()
+        .and_then(|profile| profile.payout_wallet)
+        .unwrap_or(wallet);
+    let opened_token_account = open_winner_token_account(
+        &ctx.accounts.winner_token_account,
+        &ctx.accounts.winner,
+        &paid_to,
+        &ctx.accounts.prize_pool.mint,
+        ctx.accounts.mint.as_deref(),
+        ctx.accounts.associated_token_program.as_ref(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+    let rent = claim_rent(
+        ctx.accounts.week_stats.prize_pool == Pubkey::default(),
+        opened_token_account,
+    )?;
+    ctx.accounts
+        .week_stats
+        .open(prize_pool_key, &ctx.accounts.prize_pool, ctx.bumps.week_stats);
+
+    let accounts = &mut *ctx.accounts;
+    let events = pay_claim(
+        PrizeClaim {
+            config: &mut accounts.config,
+            prize_pool: &mut accounts.prize_pool,
+            prize_pool_token_account: &accounts.prize_pool_token_account,
+            creator_token_account: &accounts.creator_token_account,
+            winner_token_account: &accounts.winner_token_account,
+            split_token_account: accounts.split_token_account.as_ref(),
+            team: &accounts.team,
+            token_program: &accounts.token_program,
+            staker_rewards_vault: accounts.staker_rewards_vault.as_ref(),
+            jackpot_vault: accounts.jackpot_vault.as_ref(),
+            insurance_vault: accounts.insurance_vault.as_ref(),
+            streak_vault: accounts.streak_vault.as_ref(),
+            player_profile: accounts.player_profile.as_deref_mut(),
+            gateway_token: accounts.gateway_token.as_deref(),
+            balance_snapshot: accounts.balance_snapshot.as_deref(),
+            week_stats: &mut accounts.week_stats,
+            global_stats: &mut accounts.global_stats,
+            mint_stats: &mut accounts.mint_stats,
+        },
+        wallet,
+        rank,
+        score,
+        proof,
+    )?;
+    let winner = ctx.accounts.winner.to_account_info();
+    let rent_rebate = pay_rent_rebate(&mut ctx.accounts.prize_pool, &winner, rent)?;
+
+    let record = &mut ctx.accounts.claim_record;
+    record.prize_pool = prize_pool_key;
+    record.wallet = wallet;
+    record.rank = events.claimed.rank;
+    record.score = score;
+    record.amount = events.claimed.amount;
+    record.paid_to = events.claimed.paid_to;
+    record.split_recipient = events.claimed.split_recipient;
+    record.split_amount = events.claimed.split_amount;
+    record.rent_rebate = rent_rebate.as_ref().map_or(0, |event| event.amount);
+    record.bump = ctx.bumps.claim_record;
+
+    if let Some(event) = events.fees.royalty {
+        emit_cpi!(event);
+    }
+    if let Some(event) = events.fees.staker_rewards {
+        emit_cpi!(event);
+    }
+    if let Some(event) = events.fees.jackpot {
+        emit_cpi!(event);
+    }
+    if let Some(event) = events.fees.insurance {
+        emit_cpi!(event);
+    }
+    emit_cpi!(events.claimed);
+    if let Some(event) = rent_rebate {
+        emit_cpi!(event);
+    }
+
+    Ok(())
+}
+
+/// The accounts one claim checks, pays from and counts in, shared by
+/// claim_prize and claim_many
+pub(crate) struct PrizeClaim<'a, 'info> {
+    pub config: &'a mut Account<'info, Config>,
+    pub prize_pool: &'a mut Account<'info, PrizePool>,
+    pub prize_pool_token_account: &'a Account<'info, TokenAccount>,
+    pub creator_token_account: &'a Account<'info, TokenAccount>,
+    pub winner_token_account: &'a AccountInfo<'info>,
+    pub split_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub team: &'a AccountInfo<'info>,
+    pub token_program: &'a Program<'info, Token>,
+    pub staker_rewards_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub jackpot_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub insurance_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub streak_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub player_profile: Option<&'a mut PlayerProfile>,
+    pub gateway_token: Option<&'a AccountInfo<'info>>,
+    pub balance_snapshot: Option<&'a BalanceSnapshot>,
+    pub week_stats: &'a mut WeekStats,
+    pub global_stats: &'a mut GlobalStats,
+    pub mint_stats: &'a mut MintStats,
+}
+
+/// Events of the pool's cuts, taken once per pool and emitted by the calling instruction
+#[derive(Default)]
+pub(crate) struct FeeEvents {
+    pub royalty: Option<CreatorRoyaltyPaid>,
+    pub staker_rewards: Option<StakerRewardsAdded>,
+    pub jackpot: Option<JackpotContributed>,
+    pub insurance: Option<InsuranceContributed>,
+}
+
+/// Events of one claim, emitted by the calling instruction
+pub(crate) struct ClaimEvents {
+    pub fees: FeeEvents,
+    pub claimed: PrizeClaimed,
+}
+
+/**
+ * Checks `wallet`'s leaf against the pool's leaderboard root and pays its
+ * prize, taking the pool's cuts on its first claim; the caller writes the
+ * claim receipt
+ */
+pub(crate) fn pay_claim(
+    accounts: PrizeClaim,
+    wallet: Pubkey,
+    rank: u32,
+    score: u8,
+    proof: Vec<[u8; 32]>,
+) -> Result<ClaimEvents> {
+    let PrizeClaim {
+        config,
+        prize_pool,
+        prize_pool_token_account,
+        creator_token_account,
+        winner_token_account,
+        split_token_account,
+        team,
+        token_program,
+        staker_rewards_vault,
+        jackpot_vault,
+        insurance_vault,
+        streak_vault,
+        player_profile,
+        gateway_token,
+        balance_snapshot,
+        week_stats,
+        global_stats,
+        mint_stats,
+    } = accounts;
+    let root = prize_pool
+        .leaderboard_root
+        .ok_or(ErrorCode::LeaderboardNotFinalized)?;
+    // Stake boosts and team splits need the winners list distribute_prizes is given
+    require!(
+        !prize_pool.exclusive_winners
+            && prize_pool.usd_prizes.is_none()
+            && config.stake_tiers.is_empty(),
+        ErrorCode::ClaimsUnsupported
+    );
+    require!(team.data_is_empty(), ErrorCode::ClaimsUnsupported);
+    require!(
+        prize_pool.distribution_nonce == 0,
+        ErrorCode::PoolAlreadyDistributed
+    );
+    require!(!prize_pool.archived, ErrorCode::PoolArchived);
+    require!(
+        Clock::get()?.unix_timestamp < prize_pool.claim_deadline,
+        ErrorCode::ClaimWindowClosed
+    );
+    require!(
+        !config.require_scoring_transcripts || prize_pool.scoring_transcript.is_some(),
+        ErrorCode::ScoringTranscriptRequired
+    );
+    require!(
+        prize_pool.entry_fee == 0 || prize_pool.entries_closed,
+        ErrorCode::EntriesOpen
+    );
+
+    // The pass must still be live at claim time, not just when the player registered
+    if let Some(network) = prize_pool.gatekeeper_network {
+        check_gateway_token(gateway_token, &wallet, &network)?;
+    }
+    require!(
+        verify_proof(&proof, &root, leaderboard_leaf(&wallet, rank, score)),
+        ErrorCode::InvalidMerkleProof
+    );
+    if prize_pool.min_holding > 0 {
+        let snapshot = balance_snapshot.ok_or(ErrorCode::InsufficientHolding)?;
+        require!(
+            snapshot.amount >= prize_pool.min_holding,
+            ErrorCode::InsufficientHolding
+        );
+    }
+    let airdrop_threshold = prize_pool.airdrop_threshold;
+    let (rank, airdrop_weight) = match airdrop_threshold {
+        // An airdrop's rank is just the leaf's position; its share depends on the score's tier
+        Some(threshold) => {
+            require!(score >= threshold, ErrorCode::BelowAirdropThreshold);
+            let total_weight = prize_pool.airdrop_total_weight();
+            require!(total_weight > 0, ErrorCode::AirdropWeightUnset);
+            let weight = tier_multiplier_bps(score, &prize_pool.qualification_tiers) as u64;
+            require!(
+                prize_pool.airdrop_weight_claimed + weight <= total_weight,
+                ErrorCode::AirdropExhausted
+            );
+            (0, weight)
+        }
+        None => {
+            require!(
+                rank > 0 && rank <= prize_pool.max_winners as u32,
+                ErrorCode::InvalidRank
+            );
+            require!(score >= 80, ErrorCode::ScoreTooLow);
+            (rank as u8, 0)
+        }
+    };
+
+    let week_id = prize_pool.week_id.clone();
+    let category = prize_pool.category.clone();
+
+    // The first claim takes the pool's cuts and fixes the balance every rank's share comes from,
+    // so the order winners claim in doesn't change what they get
+    let mut fees = FeeEvents::default();
+    if prize_pool.claim_basis == 0 {
+        fees = fix_claim_basis(
+            config,
+            prize_pool,
+            prize_pool_token_account,
+            creator_token_account,
+            staker_rewards_vault,
+            jackpot_vault,
+            insurance_vault,
+            token_program,
+        )?;
+        require!(
+            prize_pool.bonus_reserve
+                >= stake_bonus(prize_pool.claim_basis, prize_pool.bonus_multiplier_bps),
+            ErrorCode::BonusUnderfunded
+        );
+    }
+
+    let bonus_multiplier_bps = prize_pool.bonus_multiplier_bps;
+    let base_prize = match airdrop_threshold {
+        Some(_) => weighted_share(
+            prize_pool.claim_basis,
+            airdrop_weight,
+            prize_pool.airdrop_total_weight(),
+        ),
+        None => calculate_prize_with(rank, prize_pool.claim_basis, &prize_pool.rank_shares_bps),
+    };
+    let sponsor_bonus = stake_bonus(base_prize, bonus_multiplier_bps).min(prize_pool.bonus_reserve);
+    let amount = base_prize + sponsor_bonus;
+    prize_pool.bonus_reserve -= sponsor_bonus;
+    if airdrop_threshold.is_some() {
+        prize_pool.airdrop_claims += 1;
+        prize_pool.airdrop_weight_claimed += airdrop_weight;
+    }
+
+    // The winner's profile routes the prize the same way distribute_prizes does
+    let paid_to = player_profile
+        .as_ref()
+        .and_then(|profile| profile.payout_wallet)
+        .unwrap_or(wallet);
+    let split = player_profile.as_ref().and_then(|profile| {
+        profile
+            .split_recipient
+            .map(|recipient| (recipient, profile.split_bps))
+    });
+    let mut split_recipient = None;
+    let mut split_amount = 0;
+    if let Some((recipient, split_bps)) = split {
+        split_recipient = Some(recipient);
+        split_amount = bps_of(amount, split_bps);
+        if split_amount > 0 {
+            let account = split_token_account.ok_or(ErrorCode::SplitAccountRequired)?;
+            require_keys_eq!(account.owner, recipient, ErrorCode::WinnerAccountMismatch);
+            require_keys_eq!(account.mint, prize_pool.mint, ErrorCode::MintMismatch);
+            let to = account.to_account_info();
+            pay_from_vault(prize_pool, prize_pool_token_account, &to, token_program, split_amount)?;
+        }
+    }
+    if amount > 0 {
+        if amount > split_amount {
+            let to = winner_token_account.to_account_info();
+            let rest = amount - split_amount;
+            pay_from_vault(prize_pool, prize_pool_token_account, &to, token_program, rest)?;
+        }
+        prize_pool.total_distributed += amount;
+        week_stats.total_paid += amount;
+        mint_stats.total_distributed += amount;
+    }
+    week_stats.unique_claimers += 1;
+
+    // Streak bonuses come out of the treasury-funded streak vault, never the pool
+    let mut streak_bonus = 0;
+    // Qualifying for an airdrop isn't a placing
+    let profile = player_profile.filter(|_| airdrop_threshold.is_none());
+    if let Some(profile) = profile {
+        if profile.record_placing(&week_id) {
+            global_stats.unique_winners += 1;
+        }
+        if prize_pool.mint == config.streak_mint && profile.on_streak(config) {
+            let vault = streak_vault.ok_or(ErrorCode::StreakVaultRequired)?;
+            streak_bonus = bps_of(base_prize, config.streak_bonus_bps).min(vault.amount);
+            if streak_bonus > 0 {
+                let to = winner_token_account.to_account_info();
+                pay_from_config_vault(config, vault, &to, token_program, streak_bonus)?;
+            }
+        }
+    }
+
+    Ok(ClaimEvents {
+        fees,
+        claimed: PrizeClaimed {
+            winner: wallet,
+            rank,
+            score,
+            amount,
+            paid_to,
+            split_recipient,
+            split_amount,
+            bonus_multiplier_bps,
+            sponsor_bonus,
+            streak_bonus,
+            week_id,
+            category,
+        },
+    })
+}
+
+/**
+ * Takes the pool's cuts from what the vault holds beyond the sponsor's reserve
+ * and fixes the rest as claim_basis, the balance every claim's share comes from
+ * Called by the first claim, or by sweep_expired when nobody claimed.
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fix_claim_basis<'info>(
+    config: &mut Account<'info, Config>,
+    prize_pool: &mut Account<'info, PrizePool>,
+    prize_pool_token_account: &Account<'info, TokenAccount>,
+    creator_token_account: &Account<'info, TokenAccount>,
+    staker_rewards_vault: Option<&Account<'info, TokenAccount>>,
+    jackpot_vault: Option<&Account<'info, TokenAccount>>,
+    insurance_vault: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+) -> Result<FeeEvents> {
+    let available = prize_pool_token_account
+        .amount
+        .saturating_sub(prize_pool.bonus_reserve);
+    require!(available > 0, ErrorCode::InsufficientFunds);
+
+    let fees = take_pool_fees(
+        config,
+        prize_pool,
+        prize_pool_token_account,
+        creator_token_account,
+        staker_rewards_vault,
+        jackpot_vault,
+        insurance_vault,
+        token_program,
+        available,
+    )?;
+    let claim_basis = available - fees.total();
+    require!(claim_basis > 0, ErrorCode::InsufficientFunds);
+    prize_pool.claim_basis = claim_basis;
+
+    let week_id = &prize_pool.week_id;
+    let category = &prize_pool.category;
+    let mut events = FeeEvents::default();
+    if fees.royalty > 0 {
+        events.royalty = Some(CreatorRoyaltyPaid {
+            creator: config.creator,
+            amount: fees.royalty,
+            week_id: week_id.clone(),
+            category: category.clone(),
+        });
+    }
+    if fees.staker_share > 0 {
+        events.staker_rewards = Some(StakerRewardsAdded {
+            amount: fees.staker_share,
+            reward_per_share: config.reward_per_share,
+            total_staked: config.total_staked,
+            week_id: week_id.clone(),
+            category: category.clone(),
+        });
+    }
+    if fees.jackpot_share > 0 {
+        events.jackpot = Some(JackpotContributed {
+            amount: fees.jackpot_share,
+            week_id: week_id.clone(),
+            category: category.clone(),
+        });
+    }
+    if fees.insurance_share > 0 {
+        events.insurance = Some(InsuranceContributed {
+            amount: fees.insurance_share,
+            week_id: week_id.clone(),
+            category: category.clone(),
+        });
+    }
+    Ok(events)
+}
+
+/**
+ * Lamports of rent a claim cost its winner: the receipt, plus the pool's week
+ * stats and the winner's token account when the claim is what opened them
+ */
+pub(crate) fn claim_rent(opened_week_stats: bool, opened_token_account: bool) -> Result<u64> {
+    let rent = Rent::get()?;
+    let mut lamports = rent.minimum_balance(8 + ClaimRecord::INIT_SPACE);
+    if opened_week_stats {
+        lamports += rent.minimum_balance(8 + WeekStats::INIT_SPACE);
+    }
+    if opened_token_account {
+        lamports += rent.minimum_balance(TokenAccount::LEN);
+    }
+    Ok(lamports)
+}
+
+/**
+ * Opens `winner`'s associated token account for `mint` if `info` is that
+ * address and doesn't exist yet, then checks `info` is a token account of
+ * `paid_to` for `mint`, so a redirected payout wallet's account must already
+ * exist. Returns whether this instruction opened it, the only case its rent is
+ * rebated.
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn open_winner_token_account<'info>(
+    info: &AccountInfo<'info>,
+    winner: &Signer<'info>,
+    paid_to: &Pubkey,
+    mint: &Pubkey,
+    mint_account: Option<&Account<'info, Mint>>,
+    associated_token_program: Option<&Program<'info, AssociatedToken>>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+) -> Result<bool> {
+    let opened = info.data_is_empty();
+    if opened {
+        require_keys_eq!(
+            info.key(),
+            get_associated_token_address(&winner.key(), mint),
+            ErrorCode::WinnerAccountMismatch
+        );
+        let mint_account = mint_account.ok_or(ErrorCode::TokenAccountOpenerRequired)?;
+        let program = associated_token_program.ok_or(ErrorCode::TokenAccountOpenerRequired)?;
+        let cpi_accounts = Create {
+            payer: winner.to_account_info(),
+            associated_token: info.clone(),
+            authority: winner.to_account_info(),
+            mint: mint_account.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        };
+        associated_token::create(CpiContext::new(program.to_account_info(), cpi_accounts))?;
+    }
+    require_keys_eq!(*info.owner, token_program.key(), ErrorCode::WinnerAccountMismatch);
+    let token_account = TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require_keys_eq!(token_account.owner, *paid_to, ErrorCode::WinnerAccountMismatch);
+    require_keys_eq!(token_account.mint, *mint, ErrorCode::MintMismatch);
+    Ok(opened)
+}
+
+/// Pays `winner` back up to `rent` lamports out of the pool's rent rebate reserve, within its cap
+pub(crate) fn pay_rent_rebate<'info>(
+    prize_pool: &mut Account<'info, PrizePool>,
+    winner: &AccountInfo<'info>,
+    rent: u64,
+) -> Result<Option<RentRebatePaid>> {
+    let amount = rent
+        .min(prize_pool.rent_rebate_cap)
+        .min(prize_pool.rent_rebate_reserve);
+    if amount == 0 {
+        return Ok(None);
+    }
+    prize_pool.sub_lamports(amount)?;
+    winner.add_lamports(amount)?;
+    prize_pool.rent_rebate_reserve -= amount;
+
+    Ok(Some(RentRebatePaid {
+        winner: winner.key(),
+        amount,
+        rent_rebate_reserve: prize_pool.rent_rebate_reserve,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    }))
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::StakingRewardsClaimed;
+use crate::state::{Config, StakeAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        has_one = wallet @ ErrorCode::Unauthorized,
+        seeds = [b"stake", wallet.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"staker_rewards"],
+        bump
+    )]
+    pub staker_rewards_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = wallet_token_account.mint == staker_rewards_vault.mint @ ErrorCode::MintMismatch,
+        constraint = wallet_token_account.owner == wallet.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub wallet_token_account: Account<'info, TokenAccount>,
+    
+    pub wallet: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+    let reward_per_share = ctx.accounts.config.reward_per_share;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.settle(reward_per_share);
+    stake_account.reset_reward_debt(reward_per_share);
+    let amount = stake_account.pending_rewards;
+    require!(amount > 0, ErrorCode::NoStakingRewards);
+    stake_account.pending_rewards = 0;
+
+    let seeds = &[b"config".as_ref(), &[ctx.accounts.config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.staker_rewards_vault.to_account_info(),
+        to: ctx.accounts.wallet_token_account.to_account_info(),
+        authority: ctx.accounts.config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    emit_cpi!(StakingRewardsClaimed {
+        wallet: ctx.accounts.wallet.key(),
+        amount,
+    });
+
+    Ok(())
+}
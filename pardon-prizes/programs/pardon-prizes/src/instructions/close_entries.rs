@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::EntriesClosed;
+use crate::math::bps_of;
+use crate::state::{Config, PrizePool};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseEntries<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury @ ErrorCode::TreasuryAccountMismatch,
+        constraint = treasury_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CloseEntries>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(!prize_pool.entries_closed, ErrorCode::RegistrationClosed);
+
+    // Only entry fees are raked; sponsor deposits go to winners untouched
+    let entry_fees = prize_pool.entry_fees_collected;
+    let rake = bps_of(entry_fees, prize_pool.rake_bps);
+    if rake > 0 {
+        let to = ctx.accounts.treasury_token_account.to_account_info();
+        pay_from_vault(
+            &ctx.accounts.prize_pool,
+            &ctx.accounts.prize_pool_token_account,
+            &to,
+            &ctx.accounts.token_program,
+            rake,
+        )?;
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.total_fees += rake;
+    prize_pool.entries_closed = true;
+
+    emit_cpi!(EntriesClosed {
+        entry_fees,
+        rake,
+        treasury: ctx.accounts.config.treasury,
+        pool_size: ctx.accounts.prize_pool_token_account.amount - rake,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::{PoolRole, PrizePool, DELEGATE_CLOSE_PRIZE_POOL};
+
+#[derive(Accounts)]
+pub struct ClosePrizePool<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            prize_pool.seed_prefix(),
+            prize_pool.week_seed().as_slice(),
+            prize_pool.category.as_bytes()
+        ],
+        bump = prize_pool.bump,
+        has_one = authority @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        seeds = [b"vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = prize_pool.mint,
+        token::authority = prize_pool
+    )]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    /// Receives the rent, whoever closes the pool
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+    
+    /// The pool's closer or its delegate, see PoolRoles
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Closing,
+            DELEGATE_CLOSE_PRIZE_POOL,
+            &closer.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub closer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClosePrizePool>) -> Result<()> {
+    require!(
+        ctx.accounts.prize_pool.votes_escrowed == 0,
+        ErrorCode::VotesStillEscrowed
+    );
+    require!(ctx.accounts.prize_pool.bonds_held == 0, ErrorCode::BondsStillHeld);
+
+    // The authority can't take funds back on its own: leftovers go out through
+    // distribute_prizes or the guardian's timelocked emergency_withdraw
+    require!(
+        ctx.accounts.prize_pool_token_account.amount == 0,
+        ErrorCode::PoolNotEmpty
+    );
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::RegistrationsClosed;
+use crate::state::{PoolRole, PrizePool, Registration, DELEGATE_CLOSE_REGISTRATIONS};
+
+/**
+ * Remaining accounts are (registration, player) pairs: each registration is
+ * closed and its rent refunded to the player who paid for it. Only archived
+ * pools qualify, since they take no further registrations or claims.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseRegistrations<'info> {
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Closing,
+            DELEGATE_CLOSE_REGISTRATIONS,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// The pool's closer or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseRegistrations<'info>>,
+) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(prize_pool.archived, ErrorCode::PoolNotArchived);
+    require!(
+        ctx.remaining_accounts.chunks_exact(2).remainder().is_empty(),
+        ErrorCode::RegistrationPlayerMismatch
+    );
+
+    let mut registrations = 0;
+    let mut lamports_reclaimed = 0;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (info, player) = (&pair[0], &pair[1]);
+        let registration = Account::<Registration>::try_from(info)?;
+        require_keys_eq!(
+            registration.prize_pool,
+            prize_pool.key(),
+            ErrorCode::RegistrationMismatch
+        );
+        require_keys_eq!(
+            player.key(),
+            registration.player,
+            ErrorCode::RegistrationPlayerMismatch
+        );
+
+        lamports_reclaimed += info.lamports();
+        registration.close(player.clone())?;
+        registrations += 1;
+    }
+
+    emit_cpi!(RegistrationsClosed {
+        prize_pool: prize_pool.key(),
+        registrations,
+        lamports_reclaimed,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PoolRole, PrizePool, WinnerList, DELEGATE_DISTRIBUTE_PRIZES};
+
+#[derive(Accounts)]
+pub struct CloseWinnerList<'info> {
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Distribution,
+            DELEGATE_DISTRIBUTE_PRIZES,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"winner_list", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub winner_list: AccountLoader<'info, WinnerList>,
+    
+    /// The pool's distributor or its delegate, see PoolRoles; gets the rent back
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseWinnerList>) -> Result<()> {
+    Ok(())
+}
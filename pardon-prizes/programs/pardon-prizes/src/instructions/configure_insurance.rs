@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct ConfigureInsurance<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// Fed by a cut of every pool in its mint; its mint can't change once created
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"insurance_vault"],
+        bump,
+        token::mint = insurance_mint,
+        token::authority = config
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+    
+    pub insurance_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConfigureInsurance>, insurance_bps: u16) -> Result<()> {
+    require!(insurance_bps <= BPS_DENOMINATOR, ErrorCode::InvalidInsuranceShare);
+
+    let config = &mut ctx.accounts.config;
+    config.insurance_mint = ctx.accounts.insurance_mint.key();
+    config.insurance_bps = insurance_bps;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::math::{BPS_DENOMINATOR, MAX_SCORE};
+use crate::state::{Config, Jackpot};
+
+#[derive(Accounts)]
+pub struct ConfigureJackpot<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Jackpot::INIT_SPACE,
+        seeds = [b"jackpot"],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+    
+    /// Holds the jackpot between wins; its mint can't change once created
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"jackpot_vault"],
+        bump,
+        token::mint = jackpot_mint,
+        token::authority = jackpot
+    )]
+    pub jackpot_vault: Account<'info, TokenAccount>,
+    
+    pub jackpot_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConfigureJackpot>, jackpot_bps: u16, trigger_score: u8) -> Result<()> {
+    require!(jackpot_bps <= BPS_DENOMINATOR, ErrorCode::InvalidJackpotShare);
+    require!(
+        (1..=MAX_SCORE).contains(&trigger_score),
+        ErrorCode::InvalidTriggerScore
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.jackpot_mint = ctx.accounts.jackpot_mint.key();
+    config.jackpot_bps = jackpot_bps;
+
+    let jackpot = &mut ctx.accounts.jackpot;
+    jackpot.vault = ctx.accounts.jackpot_vault.key();
+    jackpot.trigger_score = trigger_score;
+    jackpot.bump = ctx.bumps.jackpot;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct ConfigureStreakBonus<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// Topped up by the treasury; its mint can't change once created
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"streak_vault"],
+        bump,
+        token::mint = streak_mint,
+        token::authority = config
+    )]
+    pub streak_vault: Account<'info, TokenAccount>,
+    
+    pub streak_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureStreakBonus>,
+    streak_bonus_bps: u16,
+    min_streak_weeks: u16,
+) -> Result<()> {
+    // A one-week "streak" would just be a flat bonus on every prize
+    require!(
+        streak_bonus_bps <= BPS_DENOMINATOR && min_streak_weeks >= 2,
+        ErrorCode::InvalidStreakBonus
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.streak_mint = ctx.accounts.streak_mint.key();
+    config.streak_bonus_bps = streak_bonus_bps;
+    config.min_streak_weeks = min_streak_weeks;
+    Ok(())
+}
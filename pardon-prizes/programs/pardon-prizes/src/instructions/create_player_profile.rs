@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PlayerProfile;
+
+#[derive(Accounts)]
+pub struct CreatePlayerProfile<'info> {
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreatePlayerProfile>) -> Result<()> {
+    let wallet = ctx.accounts.player.key();
+    ctx.accounts.player_profile.init(wallet, ctx.bumps.player_profile);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::{iso_week_id, week_index_at, week_start, WEEK_SECONDS};
+use crate::state::Config;
+
+/// What current_week returns
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WeekInfo {
+    pub week_index: u32,
+    /// ISO week the numbered week starts in, the week_id its pools carry
+    pub week_id: String,
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct CurrentWeek<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+}
+
+pub fn handler(ctx: Context<CurrentWeek>) -> Result<WeekInfo> {
+    let epoch_start = ctx.accounts.config.week_epoch_start;
+    require!(epoch_start > 0, ErrorCode::WeekScheduleUnset);
+    let week_index = week_index_at(epoch_start, Clock::get()?.unix_timestamp)
+        .ok_or(ErrorCode::WeekNotOpen)?;
+    let starts_at = week_start(epoch_start, week_index);
+
+    Ok(WeekInfo {
+        week_index,
+        week_id: iso_week_id(starts_at),
+        starts_at,
+        ends_at: starts_at + WEEK_SECONDS,
+    })
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::AuthorityDelegated;
+use crate::state::{AuthorityChange, AuthorityKind, Delegation, PrizePool, DELEGABLE_IXS};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DelegateAuthority<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<DelegateAuthority>,
+    key: Pubkey,
+    expires_at: i64,
+    allowed_ixs: u64,
+) -> Result<()> {
+    require!(
+        allowed_ixs & DELEGABLE_IXS == allowed_ixs,
+        ErrorCode::InvalidDelegation
+    );
+
+    // No instructions revokes the delegate, whatever its expiry
+    let now = Clock::get()?.unix_timestamp;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    let previous = prize_pool.delegation.map(|delegation| delegation.key).unwrap_or_default();
+    prize_pool.delegation = if allowed_ixs == 0 {
+        None
+    } else {
+        require!(
+            key != Pubkey::default() && expires_at > now,
+            ErrorCode::InvalidDelegation
+        );
+        Some(Delegation {
+            key,
+            expires_at,
+            allowed_ixs,
+        })
+    };
+
+    let delegate = prize_pool.delegation.map(|delegation| delegation.key).unwrap_or_default();
+    if delegate != previous {
+        AuthorityChange::record(
+            &mut prize_pool.authority_history,
+            AuthorityChange {
+                kind: AuthorityKind::Delegate,
+                previous,
+                key: delegate,
+                changed_at: now,
+            },
+        );
+    }
+
+    emit_cpi!(AuthorityDelegated {
+        key,
+        expires_at,
+        allowed_ixs,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
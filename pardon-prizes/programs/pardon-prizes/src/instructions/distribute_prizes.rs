@@ -0,0 +1,970 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::ed25519;
+use crate::errors::ErrorCode;
+use crate::events::{
+    CreatorRoyaltyPaid, InsuranceContributed, JackpotContributed, PrizeDistributed, PrizeUnpaid,
+    StakerRewardsAdded, TeamShareDistributed, UsdPrizesPriced,
+};
+use crate::math::{
+    bps_of, calculate_prize_with, highest_paid_rank, previous_week_id, price_within_confidence,
+    rank_bit, reward_per_share_increase, split_by_bps, stake_bonus, stake_multiplier_bps,
+    usd_to_tokens, BPS_DENOMINATOR, PODIUM_RANK,
+};
+use crate::pyth::{parse_price_update, PriceFeed, PYTH_RECEIVER_PROGRAM_ID};
+use crate::state::{
+    BalanceSnapshot, Config, DistributionRecord, GlobalStats, MintStats, PayoutStatus,
+    PlayerProfile, PoolRole, PrizePool, StakeAccount, Team, UnpaidPrize, UsdPrizes, WeekStats,
+    WeekWinner, WinnerEntry, WinnerList, DELEGATE_DISTRIBUTE_PRIZES, MAX_UNPAID_PRIZES,
+};
+
+/// Prefix of the message the judge enclave signs to authorize a distribution
+pub const ENCLAVE_DISTRIBUTION_PREFIX: &[u8] = b"pardon-prizes:enclave-distribution";
+
+/**
+ * Bytes the judge enclave signs: the prefix, the program id, then
+ * `prize_pool`, the pass's `nonce` (u64 LE) and the record's winners_hash
+ */
+pub fn enclave_distribution_message(
+    prize_pool: &Pubkey,
+    nonce: u64,
+    winners_hash: &[u8; 32],
+) -> Vec<u8> {
+    [
+        ENCLAVE_DISTRIBUTION_PREFIX,
+        crate::ID.as_ref(),
+        prize_pool.as_ref(),
+        &nonce.to_le_bytes(),
+        winners_hash,
+    ]
+    .concat()
+}
+
+/**
+ * Winner accounts are passed as remaining accounts, in winners order:
+ * - solo entry: the winner's PlayerProfile PDA (writable, may be
+ *   uninitialized unless the win cooldown applies to its rank), the token
+ *   account of the profile's payout wallet (the winner by default), then
+ *   the split recipient's token account if the profile has a split
+ *   configured
+ * - team entry: the Team account, then one token account per member
+ *
+ * A token account that was closed or frozen doesn't abort the pass: its leg
+ * is recorded as unpaid in the DistributionRecord and held in the vault for
+ * retry_unpaid.
+ *
+ * On exclusive pools each entry starts with the WeekWinner PDA of its
+ * wallet (the captain for teams), created here on the first win. On
+ * holder-gated pools the wallet's BalanceSnapshot PDA comes next. While the
+ * config has stake tiers, the wallet's StakeAccount PDA (may be
+ * uninitialized) follows.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(winners: Vec<WinnerEntry>, nonce: u64)]
+pub struct DistributePrizes<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        seeds = [
+            prize_pool.seed_prefix(),
+            prize_pool.week_seed().as_slice(),
+            prize_pool.category.as_bytes()
+        ],
+        bump = prize_pool.bump,
+        constraint = (prize_pool.authorizes(
+            PoolRole::Distribution,
+            DELEGATE_DISTRIBUTE_PRIZES,
+            &authority.key()
+        )? || instructions.is_some()) @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DistributionRecord::INIT_SPACE,
+        seeds = [b"distribution", prize_pool.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    pub distribution_record: Account<'info, DistributionRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = prize_pool.mint,
+        token::authority = prize_pool
+    )]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == config.creator @ ErrorCode::CreatorAccountMismatch,
+        constraint = creator_token_account.mint == prize_pool_token_account.mint @ ErrorCode::CreatorAccountMismatch
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    /// The pool's distributor or its delegate, see PoolRoles; anyone when the
+    /// judge enclave signed the winners
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Required once stakers are owed a share of pools in the stake mint
+    #[account(mut, seeds = [b"staker_rewards"], bump)]
+    pub staker_rewards_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the config feeds pools in this mint to the jackpot
+    #[account(mut, seeds = [b"jackpot_vault"], bump)]
+    pub jackpot_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the config pays streak bonuses in this mint
+    #[account(mut, seeds = [b"streak_vault"], bump)]
+    pub streak_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Pyth PriceUpdateV2, parsed by price_feed; required on the first pass of USD pools
+    #[account(owner = PYTH_RECEIVER_PROGRAM_ID @ ErrorCode::InvalidPriceUpdate)]
+    pub price_update: Option<UncheckedAccount<'info>>,
+    
+    /// Required while the config feeds pools in this mint to the insurance fund
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Winners staged with append_winners, paid instead of the `winners` argument
+    #[account(mut, seeds = [b"winner_list", prize_pool.key().as_ref()], bump)]
+    pub winner_list: Option<AccountLoader<'info, WinnerList>>,
+    
+    /// CHECK: the instructions sysvar, holding the judge enclave's ed25519 sig-verify;
+    /// only passed when the authority isn't the distributor
+    #[account(address = instructions_sysvar::ID @ ErrorCode::InvalidInstructionsSysvar)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + WeekStats::INIT_SPACE,
+        seeds = [b"week_stats", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub week_stats: Box<Account<'info, WeekStats>>,
+    
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Box<Account<'info, GlobalStats>>,
+    
+    #[account(mut, seeds = [b"mint_stats", prize_pool.mint.as_ref()], bump = mint_stats.bump)]
+    pub mint_stats: Box<Account<'info, MintStats>>,
+}
+
+impl<'info> DistributePrizes<'info> {
+    /// Transfer tokens from prize pool to a winner token account
+    fn pay(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        pay_from_vault(
+            &self.prize_pool,
+            &self.prize_pool_token_account,
+            to,
+            &self.token_program,
+            amount,
+        )
+    }
+
+    /**
+     * Pays `amount` to `to`, or records it in `unpaid` for `owner` if the
+     * account can't receive it; returns whether it was delivered
+     */
+    fn pay_or_defer(
+        &self,
+        to: Option<&AccountInfo<'info>>,
+        owner: Pubkey,
+        amount: u64,
+        winner: &WinnerEntry,
+        unpaid: &mut Vec<UnpaidPrize>,
+    ) -> Result<bool> {
+        if let Some(to) = to {
+            self.pay(to, amount)?;
+            return Ok(true);
+        }
+        require!(unpaid.len() < MAX_UNPAID_PRIZES, ErrorCode::TooManyUnpaidPrizes);
+        unpaid.push(UnpaidPrize {
+            winner: winner.wallet,
+            owner,
+            rank: winner.rank,
+            amount,
+            paid: false,
+        });
+        Ok(false)
+    }
+
+    /// Transfer a streak bonus from the streak vault to a winner token account
+    fn pay_streak_bonus(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let vault = self
+            .streak_vault
+            .as_ref()
+            .ok_or(ErrorCode::StreakVaultRequired)?;
+        pay_from_config_vault(&self.config, vault, to, &self.token_program, amount)
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
+    winners: Vec<WinnerEntry>,
+    nonce: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.prize_pool.claim_basis == 0,
+        ErrorCode::ClaimsStarted
+    );
+    require!(
+        ctx.accounts.prize_pool.airdrop_threshold.is_none(),
+        ErrorCode::AirdropPool
+    );
+    require!(
+        !ctx.accounts.config.require_scoring_transcripts
+            || ctx.accounts.prize_pool.scoring_transcript.is_some(),
+        ErrorCode::ScoringTranscriptRequired
+    );
+
+    // The rake comes out of entry fees first, so winners are paid from the locked pool
+    require!(
+        ctx.accounts.prize_pool.entry_fee == 0 || ctx.accounts.prize_pool.entries_closed,
+        ErrorCode::EntriesOpen
+    );
+
+    // A signed distribution is only valid for the pool state it was built against
+    require!(
+        nonce == ctx.accounts.prize_pool.distribution_nonce,
+        ErrorCode::StaleDistributionNonce
+    );
+    let prize_pool_key = ctx.accounts.prize_pool.key();
+    ctx.accounts
+        .week_stats
+        .open(prize_pool_key, &ctx.accounts.prize_pool, ctx.bumps.week_stats);
+
+    // The sponsor's bonus reserve and earlier passes' unpaid prizes sit in the
+    // vault but outside the prize math
+    let prize_pool_account = &ctx.accounts.prize_pool_token_account;
+    let mut total_available = prize_pool_account
+        .amount
+        .saturating_sub(ctx.accounts.prize_pool.bonus_reserve)
+        .saturating_sub(ctx.accounts.prize_pool.unpaid_reserve);
+    let mint = prize_pool_account.mint;
+    
+    require!(total_available > 0, ErrorCode::InsufficientFunds);
+
+    let week_id = ctx.accounts.prize_pool.week_id.clone();
+    let category = ctx.accounts.prize_pool.category.clone();
+    let rank_shares_bps = ctx.accounts.prize_pool.rank_shares_bps.clone();
+    let bonus_multiplier_bps = ctx.accounts.prize_pool.bonus_multiplier_bps;
+    let cooldown_rank = ctx.accounts.config.cooldown_rank;
+    let stake_tiers = ctx.accounts.config.stake_tiers.clone();
+    let previous_week = if cooldown_rank > 0 {
+        Some(previous_week_id(&week_id).ok_or(ErrorCode::InvalidWeekId)?)
+    } else {
+        None
+    };
+    
+    // A staged list stands in for the argument, which must then be left empty
+    let winners = match &ctx.accounts.winner_list {
+        Some(winner_list) => {
+            require!(winners.is_empty(), ErrorCode::WinnersListedTwice);
+            winner_list.load()?.winners()
+        }
+        None => winners,
+    };
+
+    // Validate all winners before distributing
+    let prize_pool = &ctx.accounts.prize_pool;
+    validate_winners(
+        &winners,
+        prize_pool.max_winners,
+        prize_pool.allow_rank_gaps,
+        prize_pool.paid_ranks,
+    )?;
+
+    let winners_hash = hash(&winners.try_to_vec()?).to_bytes();
+    let enclave_signer = if prize_pool.authorizes(
+        PoolRole::Distribution,
+        DELEGATE_DISTRIBUTE_PRIZES,
+        &ctx.accounts.authority.key(),
+    )? {
+        None
+    } else {
+        // Anyone may relay winners the judge enclave signed for this exact pass
+        let enclave_signer = ctx
+            .accounts
+            .config
+            .enclave_signer
+            .ok_or(ErrorCode::EnclaveSignerUnset)?;
+        let instructions = ctx
+            .accounts
+            .instructions
+            .as_ref()
+            .ok_or(ErrorCode::Unauthorized)?;
+        let message = enclave_distribution_message(&prize_pool.key(), nonce, &winners_hash);
+        ed25519::verify_previous_ix(
+            &instructions.to_account_info(),
+            &enclave_signer.key,
+            &message,
+        )?;
+        Some(enclave_signer.key)
+    };
+
+    // The pool's cuts come off the top once per week, on the first pass
+    if nonce == 0 {
+        let accounts = &mut *ctx.accounts;
+        let fees = take_pool_fees(
+            &mut accounts.config,
+            &mut accounts.prize_pool,
+            &accounts.prize_pool_token_account,
+            &accounts.creator_token_account,
+            accounts.staker_rewards_vault.as_ref(),
+            accounts.jackpot_vault.as_ref(),
+            accounts.insurance_vault.as_ref(),
+            &accounts.token_program,
+            total_available,
+        )?;
+        total_available -= fees.total();
+
+        if fees.royalty > 0 {
+            emit_cpi!(CreatorRoyaltyPaid {
+                creator: ctx.accounts.config.creator,
+                amount: fees.royalty,
+                week_id: week_id.clone(),
+                category: category.clone(),
+            });
+        }
+        if fees.staker_share > 0 {
+            let config = &ctx.accounts.config;
+            emit_cpi!(StakerRewardsAdded {
+                amount: fees.staker_share,
+                reward_per_share: config.reward_per_share,
+                total_staked: config.total_staked,
+                week_id: week_id.clone(),
+                category: category.clone(),
+            });
+        }
+        if fees.jackpot_share > 0 {
+            emit_cpi!(JackpotContributed {
+                amount: fees.jackpot_share,
+                week_id: week_id.clone(),
+                category: category.clone(),
+            });
+        }
+        if fees.insurance_share > 0 {
+            emit_cpi!(InsuranceContributed {
+                amount: fees.insurance_share,
+                week_id: week_id.clone(),
+                category: category.clone(),
+            });
+        }
+    }
+
+    // USD prizes are priced by the first pass, and the vault has to cover them in full
+    if let Some(usd_prizes) = ctx.accounts.prize_pool.usd_prizes.filter(|_| nonce == 0) {
+        let info = ctx
+            .accounts
+            .price_update
+            .as_ref()
+            .ok_or(ErrorCode::PriceUpdateRequired)?;
+        let feed = price_feed(&usd_prizes, info)?;
+        let amount = usd_to_tokens(
+            usd_prizes.usd_cents,
+            feed.price,
+            feed.exponent,
+            usd_prizes.mint_decimals,
+        )
+        .ok_or(ErrorCode::InvalidPriceUpdate)?;
+        require!(amount > 0 && amount <= total_available, ErrorCode::InsufficientFunds);
+        total_available = amount;
+
+        emit_cpi!(UsdPrizesPriced {
+            usd_cents: usd_prizes.usd_cents,
+            price: feed.price,
+            conf: feed.conf,
+            exponent: feed.exponent,
+            publish_time: feed.publish_time,
+            amount,
+            distribution_nonce: nonce,
+            week_id: week_id.clone(),
+            category: category.clone(),
+        });
+    }
+
+    if nonce == 0 {
+        // A sponsored multiplier must be paid in full to every rank the pool pays
+        let bonus_needed = stake_bonus(total_available, bonus_multiplier_bps);
+        require!(
+            ctx.accounts.prize_pool.bonus_reserve >= bonus_needed,
+            ErrorCode::BonusUnderfunded
+        );
+        // Later passes split the same amount, so a rank's share doesn't
+        // depend on what earlier passes already took out of the vault
+        ctx.accounts.prize_pool.distribution_basis = total_available;
+    }
+    let basis = ctx.accounts.prize_pool.distribution_basis;
+
+    // Stake boosts only come out of what the listed ranks leave in the vault,
+    // so every winner's base prize stays covered
+    let base_total = winners.iter().fold(0u64, |sum, winner| {
+        sum.saturating_add(calculate_prize_with(winner.rank, basis, &rank_shares_bps))
+    });
+    require!(base_total <= total_available, ErrorCode::InsufficientFunds);
+    let mut bonus_headroom = total_available - base_total;
+
+    // Streak bonuses come out of the treasury-funded streak vault, never the pool
+    let config = &ctx.accounts.config;
+    let pays_streaks = config.streak_bonus_bps > 0 && mint == config.streak_mint;
+    let mut streak_budget = match &ctx.accounts.streak_vault {
+        Some(vault) if pays_streaks => vault.amount,
+        None if pays_streaks => return err!(ErrorCode::StreakVaultRequired),
+        _ => 0,
+    };
+
+    // Calculate and transfer prizes
+    let mut winner_accounts = ctx.remaining_accounts.iter();
+    let mut total_paid: u64 = 0;
+    let mut unpaid = Vec::new();
+    let mut statuses = Vec::with_capacity(winners.len());
+    for winner in &winners {
+        let unpaid_before = unpaid.len();
+        let mut delivered = false;
+        let base_prize = calculate_prize_with(winner.rank, basis, &rank_shares_bps);
+
+        if ctx.accounts.prize_pool.exclusive_winners {
+            let marker = winner_accounts
+                .next()
+                .ok_or(ErrorCode::MissingWinnerAccount)?;
+            record_week_win(ctx.accounts, marker, &week_id, &category, &winner.wallet)?;
+        }
+
+        let min_holding = ctx.accounts.prize_pool.min_holding;
+        if min_holding > 0 {
+            let info = winner_accounts
+                .next()
+                .ok_or(ErrorCode::MissingWinnerAccount)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"snapshot", ctx.accounts.prize_pool.key().as_ref(), winner.wallet.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(info.key(), expected, ErrorCode::BalanceSnapshotMismatch);
+            require!(!info.data_is_empty(), ErrorCode::InsufficientHolding);
+            let snapshot = Account::<BalanceSnapshot>::try_from(info)?;
+            require!(snapshot.amount >= min_holding, ErrorCode::InsufficientHolding);
+        }
+
+        let multiplier_bps = if stake_tiers.is_empty() {
+            BPS_DENOMINATOR
+        } else {
+            let staked = next_staked_amount(&mut winner_accounts, &winner.wallet)?;
+            stake_multiplier_bps(staked, &stake_tiers)
+        };
+        let bonus = stake_bonus(base_prize, multiplier_bps).min(bonus_headroom);
+        bonus_headroom -= bonus;
+        let sponsor_bonus = stake_bonus(base_prize, bonus_multiplier_bps)
+            .min(ctx.accounts.prize_pool.bonus_reserve);
+        ctx.accounts.prize_pool.bonus_reserve -= sponsor_bonus;
+        let prize_amount = base_prize + bonus + sponsor_bonus;
+        
+        let mut paid_to = winner.wallet;
+        let mut split_recipient = None;
+        let mut split_amount = 0;
+        let mut streak_bonus = 0;
+        match winner.team {
+            None => {
+                let mut profile = next_player_profile(&mut winner_accounts, &winner.wallet)?;
+                if let Some(previous_week) = previous_week
+                    .as_ref()
+                    .filter(|_| winner.rank <= cooldown_rank)
+                {
+                    let history = profile.as_ref().ok_or(ErrorCode::PlayerProfileRequired)?;
+                    require!(
+                        history.last_podium_week != *previous_week,
+                        ErrorCode::WinCooldownActive
+                    );
+                }
+
+                paid_to = profile
+                    .as_ref()
+                    .and_then(|p| p.payout_wallet)
+                    .unwrap_or(winner.wallet);
+                let to = next_destination(&mut winner_accounts, &paid_to, &mint)?;
+                if let Some((recipient, split_bps)) = profile
+                    .as_ref()
+                    .and_then(|p| p.split_recipient.map(|recipient| (recipient, p.split_bps)))
+                {
+                    let secondary = next_destination(&mut winner_accounts, &recipient, &mint)?;
+                    split_recipient = Some(recipient);
+                    split_amount = bps_of(prize_amount, split_bps);
+                    if split_amount > 0 {
+                        delivered |= ctx.accounts.pay_or_defer(
+                            secondary,
+                            recipient,
+                            split_amount,
+                            winner,
+                            &mut unpaid,
+                        )?;
+                    }
+                }
+                if prize_amount > split_amount {
+                    delivered |= ctx.accounts.pay_or_defer(
+                        to,
+                        paid_to,
+                        prize_amount - split_amount,
+                        winner,
+                        &mut unpaid,
+                    )?;
+                }
+
+                if let Some(profile) = profile.as_mut() {
+                    if profile.record_placing(&week_id) {
+                        ctx.accounts.global_stats.unique_winners += 1;
+                    }
+                    // The streak vault isn't the pool's, so a bonus that can't land is just skipped
+                    if let Some(to) = to.filter(|_| pays_streaks) {
+                        if profile.on_streak(&ctx.accounts.config) {
+                            streak_bonus = bps_of(base_prize, ctx.accounts.config.streak_bonus_bps)
+                                .min(streak_budget);
+                            streak_budget -= streak_bonus;
+                            if streak_bonus > 0 {
+                                ctx.accounts.pay_streak_bonus(to, streak_bonus)?;
+                            }
+                        }
+                    }
+                    if winner.rank <= PODIUM_RANK {
+                        profile.last_podium_week = week_id.clone();
+                    }
+                    profile.exit(&crate::ID)?;
+                }
+            }
+            Some(team_key) => {
+                let team_info = winner_accounts
+                    .next()
+                    .ok_or(ErrorCode::MissingWinnerAccount)?;
+                require_keys_eq!(team_info.key(), team_key, ErrorCode::TeamMismatch);
+                let team = Account::<Team>::try_from(team_info)?;
+                require_keys_eq!(team.captain, winner.wallet, ErrorCode::TeamMismatch);
+
+                let share_bps: Vec<u16> = team.members.iter().map(|m| m.share_bps).collect();
+                let shares = split_by_bps(prize_amount, &share_bps);
+                for (member, share) in team.members.iter().zip(shares) {
+                    let to = next_destination(&mut winner_accounts, &member.wallet, &mint)?;
+                    if share > 0
+                        && ctx.accounts.pay_or_defer(to, member.wallet, share, winner, &mut unpaid)?
+                    {
+                        delivered = true;
+                        emit_cpi!(TeamShareDistributed {
+                            team: team_key,
+                            member: member.wallet,
+                            amount: share,
+                            week_id: week_id.clone(),
+                            category: category.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        
+        if prize_amount > 0 {
+            ctx.accounts.prize_pool.total_distributed += prize_amount;
+            ctx.accounts.week_stats.total_paid += prize_amount;
+            ctx.accounts.mint_stats.total_distributed += prize_amount;
+            total_paid += prize_amount;
+            
+            emit_cpi!(PrizeDistributed {
+                winner: winner.wallet,
+                rank: winner.rank,
+                score: winner.score,
+                amount: prize_amount,
+                week_id: week_id.clone(),
+                category: category.clone(),
+                distribution_nonce: nonce,
+                paid_to,
+                split_recipient,
+                split_amount,
+                stake_multiplier_bps: multiplier_bps,
+                stake_bonus: bonus,
+                bonus_multiplier_bps,
+                sponsor_bonus,
+                streak_bonus,
+            });
+        }
+
+        statuses.push(if unpaid.len() == unpaid_before {
+            PayoutStatus::Paid
+        } else if delivered {
+            PayoutStatus::Partial
+        } else {
+            PayoutStatus::Failed
+        });
+    }
+
+    // Unpaid legs never left the vault, so they move from the totals to the reserve
+    for (index, prize) in unpaid.iter().enumerate() {
+        total_paid -= prize.amount;
+        ctx.accounts.prize_pool.total_distributed -= prize.amount;
+        ctx.accounts.week_stats.total_paid -= prize.amount;
+        ctx.accounts.mint_stats.total_distributed -= prize.amount;
+        ctx.accounts.prize_pool.unpaid_reserve += prize.amount;
+
+        emit_cpi!(PrizeUnpaid {
+            winner: prize.winner,
+            owner: prize.owner,
+            rank: prize.rank,
+            amount: prize.amount,
+            distribution_nonce: nonce,
+            index: index as u8,
+            week_id: week_id.clone(),
+            category: category.clone(),
+        });
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.distribution_nonce += 1;
+    prize_pool.winners_hash = hashv(&[&prize_pool.winners_hash, &winners_hash]).to_bytes();
+    prize_pool.paid_ranks |= winners.iter().fold(0, |bits, winner| bits | rank_bit(winner.rank));
+    let paid_ranks = prize_pool.paid_ranks;
+
+    let record = &mut ctx.accounts.distribution_record;
+    record.prize_pool = ctx.accounts.prize_pool.key();
+    record.nonce = nonce;
+    record.winners_hash = winners_hash;
+    record.winner_count = winners.len() as u16;
+    record.total_paid = total_paid;
+    record.executor = ctx.accounts.authority.key();
+    record.enclave_signer = enclave_signer;
+    record.slot = Clock::get()?.slot;
+    record.winners = winners.iter().map(|winner| winner.wallet).collect();
+    record.statuses = statuses;
+    record.unpaid = unpaid;
+    record.paid_ranks = paid_ranks;
+    record.bump = ctx.bumps.distribution_record;
+
+    // The staged list is spent; the next pass appends its own
+    if let Some(winner_list) = &ctx.accounts.winner_list {
+        winner_list.load_mut()?.count = 0;
+    }
+
+    Ok(())
+}
+
+/**
+ * Checks the whole winners list before any tokens move: ranks ascending and,
+ * unless gaps are allowed, consecutive from the last rank earlier passes paid,
+ * within max_winners and not paid yet, each wallet once
+ * Kept out of the handler so its locals don't sit in the handler's stack frame.
+ */
+fn validate_winners(
+    winners: &[WinnerEntry],
+    max_winners: u8,
+    allow_rank_gaps: bool,
+    paid_ranks: u16,
+) -> Result<()> {
+    require!(!winners.is_empty(), ErrorCode::EmptyWinners);
+    require!(winners.len() <= max_winners as usize, ErrorCode::TooManyWinners);
+    for (i, winner) in winners.iter().enumerate() {
+        require!(
+            winner.rank > 0 && winner.rank <= max_winners,
+            ErrorCode::InvalidRank
+        );
+        require!(winner.score >= 80, ErrorCode::ScoreTooLow);
+        require!(paid_ranks & rank_bit(winner.rank) == 0, ErrorCode::RankAlreadyPaid);
+        if let Some(previous) = i.checked_sub(1).map(|j| &winners[j]) {
+            require!(winner.rank > previous.rank, ErrorCode::WinnersNotSorted);
+            require!(
+                allow_rank_gaps || winner.rank == previous.rank + 1,
+                ErrorCode::RankGap
+            );
+        } else if paid_ranks != 0 {
+            require!(
+                allow_rank_gaps || winner.rank == highest_paid_rank(paid_ranks) + 1,
+                ErrorCode::RankGap
+            );
+        }
+        require!(
+            winners[..i].iter().all(|other| other.wallet != winner.wallet),
+            ErrorCode::DuplicateWinner
+        );
+    }
+    Ok(())
+}
+
+/// The pool's Pyth price, if it is fresh and tight enough to pay prizes at
+fn price_feed(usd_prizes: &UsdPrizes, info: &AccountInfo) -> Result<PriceFeed> {
+    let feed = parse_price_update(&info.try_borrow_data()?).ok_or(ErrorCode::InvalidPriceUpdate)?;
+    require!(feed.feed_id == usd_prizes.feed_id, ErrorCode::PriceFeedMismatch);
+    let age = Clock::get()?.unix_timestamp.saturating_sub(feed.publish_time);
+    require!(
+        age <= usd_prizes.max_price_age_secs as i64,
+        ErrorCode::StalePrice
+    );
+    require!(
+        price_within_confidence(feed.price, feed.conf, usd_prizes.max_conf_bps),
+        ErrorCode::PriceTooUncertain
+    );
+    Ok(feed)
+}
+
+/// Signs a transfer out of the pool vault as the prize pool PDA
+pub(crate) fn pay_from_vault<'info>(
+    prize_pool: &Account<'info, PrizePool>,
+    vault: &Account<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let week_seed = prize_pool.week_seed();
+    let seeds = &[
+        prize_pool.seed_prefix(),
+        week_seed.as_slice(),
+        prize_pool.category.as_bytes(),
+        &[prize_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: vault.to_account_info(),
+        to: to.clone(),
+        authority: prize_pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Transfer out of a vault the config owns, e.g. the streak vault
+pub(crate) fn pay_from_config_vault<'info>(
+    config: &Account<'info, Config>,
+    vault: &Account<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[b"config".as_ref(), &[config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: vault.to_account_info(),
+        to: to.clone(),
+        authority: config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Cuts taken off the top of a pool before any winner math
+pub(crate) struct PoolFees {
+    pub royalty: u64,
+    pub staker_share: u64,
+    pub jackpot_share: u64,
+    pub insurance_share: u64,
+}
+
+impl PoolFees {
+    pub fn total(&self) -> u64 {
+        self.royalty + self.staker_share + self.jackpot_share + self.insurance_share
+    }
+}
+
+/**
+ * The creator royalty out of `available`, then the stakers' share of what's
+ * left, then the jackpot's cut and the insurance cut of the rest, for a pool
+ * in `mint`
+ */
+pub(crate) fn pool_fees(config: &Config, mint: &Pubkey, available: u64) -> PoolFees {
+    let royalty = bps_of(available, config.creator_share_bps);
+    let remaining = available - royalty;
+    let staker_share = if config.total_staked > 0 && *mint == config.stake_mint {
+        bps_of(remaining, config.staker_share_bps)
+    } else {
+        0
+    };
+    let remaining = remaining - staker_share;
+    let jackpot_share = if *mint == config.jackpot_mint {
+        bps_of(remaining, config.jackpot_bps)
+    } else {
+        0
+    };
+    let remaining = remaining - jackpot_share;
+    let insurance_share = if *mint == config.insurance_mint {
+        bps_of(remaining, config.insurance_bps)
+    } else {
+        0
+    };
+    PoolFees { royalty, staker_share, jackpot_share, insurance_share }
+}
+
+/**
+ * Pays the pool_fees of `available`: the stakers' share pro-rata to their
+ * stake, the jackpot's cut into the jackpot vault, the insurance cut into the
+ * insurance vault
+ * Callers take this once per pool, on its first payout.
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn take_pool_fees<'info>(
+    config: &mut Account<'info, Config>,
+    prize_pool: &mut Account<'info, PrizePool>,
+    vault: &Account<'info, TokenAccount>,
+    creator_token_account: &Account<'info, TokenAccount>,
+    staker_rewards_vault: Option<&Account<'info, TokenAccount>>,
+    jackpot_vault: Option<&Account<'info, TokenAccount>>,
+    insurance_vault: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    available: u64,
+) -> Result<PoolFees> {
+    let fees = pool_fees(config, &vault.mint, available);
+    if fees.royalty > 0 {
+        let to = creator_token_account.to_account_info();
+        pay_from_vault(prize_pool, vault, &to, token_program, fees.royalty)?;
+        prize_pool.total_fees += fees.royalty;
+    }
+
+    if fees.staker_share > 0 {
+        let to = staker_rewards_vault
+            .ok_or(ErrorCode::StakerRewardsVaultRequired)?
+            .to_account_info();
+        pay_from_vault(prize_pool, vault, &to, token_program, fees.staker_share)?;
+        prize_pool.total_fees += fees.staker_share;
+        config.reward_per_share +=
+            reward_per_share_increase(fees.staker_share, config.total_staked);
+    }
+
+    if fees.jackpot_share > 0 {
+        let to = jackpot_vault
+            .ok_or(ErrorCode::JackpotVaultRequired)?
+            .to_account_info();
+        pay_from_vault(prize_pool, vault, &to, token_program, fees.jackpot_share)?;
+        prize_pool.total_fees += fees.jackpot_share;
+    }
+
+    if fees.insurance_share > 0 {
+        let to = insurance_vault
+            .ok_or(ErrorCode::InsuranceVaultRequired)?
+            .to_account_info();
+        pay_from_vault(prize_pool, vault, &to, token_program, fees.insurance_share)?;
+        prize_pool.total_fees += fees.insurance_share;
+    }
+
+    Ok(fees)
+}
+
+/**
+ * Creates the wallet's WeekWinner marker for this category, or checks that
+ * an existing one was left by this same category (an earlier pass)
+ */
+fn record_week_win<'info>(
+    accounts: &DistributePrizes<'info>,
+    marker: &'info AccountInfo<'info>,
+    week_id: &str,
+    category: &str,
+    wallet: &Pubkey,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"week_winner", week_id.as_bytes(), wallet.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(marker.key(), expected, ErrorCode::WeekWinnerMismatch);
+
+    if !marker.data_is_empty() {
+        let existing = Account::<WeekWinner>::try_from(marker)?;
+        require!(existing.category == category, ErrorCode::AlreadyWonCategory);
+        return Ok(());
+    }
+
+    let space = 8 + WeekWinner::INIT_SPACE;
+    let seeds = &[b"week_winner", week_id.as_bytes(), wallet.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.system_program.to_account_info(),
+        CreateAccount {
+            from: accounts.authority.to_account_info(),
+            to: marker.clone(),
+        },
+        signer,
+    );
+    system_program::create_account(
+        cpi_ctx,
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let record = WeekWinner {
+        week_id: week_id.to_string(),
+        wallet: *wallet,
+        category: category.to_string(),
+        bump,
+    };
+    record.try_serialize(&mut &mut marker.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Pops the winner's PlayerProfile PDA, returning None if it was never created
+fn next_player_profile<'info>(
+    winner_accounts: &mut std::slice::Iter<'info, AccountInfo<'info>>,
+    wallet: &Pubkey,
+) -> Result<Option<Account<'info, PlayerProfile>>> {
+    let info = winner_accounts
+        .next()
+        .ok_or(ErrorCode::MissingWinnerAccount)?;
+    let (expected, _) = Pubkey::find_program_address(&[b"player", wallet.as_ref()], &crate::ID);
+    require_keys_eq!(info.key(), expected, ErrorCode::PlayerProfileMismatch);
+    if info.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Account::<PlayerProfile>::try_from(info)?))
+}
+
+/// Pops the wallet's StakeAccount PDA, returning 0 if it was never created
+fn next_staked_amount<'info>(
+    winner_accounts: &mut std::slice::Iter<'info, AccountInfo<'info>>,
+    wallet: &Pubkey,
+) -> Result<u64> {
+    let info = winner_accounts
+        .next()
+        .ok_or(ErrorCode::MissingWinnerAccount)?;
+    let (expected, _) = Pubkey::find_program_address(&[b"stake", wallet.as_ref()], &crate::ID);
+    require_keys_eq!(info.key(), expected, ErrorCode::StakeAccountMismatch);
+    if info.data_is_empty() {
+        return Ok(0);
+    }
+    Ok(Account::<StakeAccount>::try_from(info)?.amount)
+}
+
+/**
+ * Pops the next remaining account and checks it is a token account of `owner`
+ * for `mint`, returning None if it was closed or is frozen and can't be paid.
+ * A missing account only counts as closed at the owner's associated token
+ * address, so a caller can't pass some empty account to skip a payment.
+ */
+fn next_destination<'info>(
+    winner_accounts: &mut std::slice::Iter<'info, AccountInfo<'info>>,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Option<&'info AccountInfo<'info>>> {
+    let info = winner_accounts
+        .next()
+        .ok_or(ErrorCode::MissingWinnerAccount)?;
+    if info.data_is_empty() && info.owner == &System::id() {
+        let expected = get_associated_token_address(owner, mint);
+        require_keys_eq!(info.key(), expected, ErrorCode::WinnerAccountMismatch);
+        return Ok(None);
+    }
+    let token_account = Account::<TokenAccount>::try_from(info)?;
+    require_keys_eq!(token_account.owner, *owner, ErrorCode::WinnerAccountMismatch);
+    require_keys_eq!(token_account.mint, *mint, ErrorCode::WinnerAccountMismatch);
+    if token_account.is_frozen() {
+        return Ok(None);
+    }
+    Ok(Some(info))
+}
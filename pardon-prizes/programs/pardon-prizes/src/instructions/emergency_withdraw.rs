@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::EmergencyWithdrawn;
+use crate::state::{Config, PrizePool};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub guardian: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+    let announced = ctx
+        .accounts
+        .prize_pool
+        .emergency_destination
+        .ok_or(ErrorCode::EmergencyNotAnnounced)?;
+    require_keys_eq!(
+        ctx.accounts.destination.key(),
+        announced,
+        ErrorCode::EmergencyDestinationMismatch
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.prize_pool.emergency_unlock_at,
+        ErrorCode::EmergencyTimelockActive
+    );
+
+    // Unpaid prizes stay for retry_unpaid and the sponsor's reserve for refund_bonus
+    let amount = ctx
+        .accounts
+        .prize_pool
+        .emergency_withdrawable(ctx.accounts.prize_pool_token_account.amount);
+    if amount > 0 {
+        let week_seed = ctx.accounts.prize_pool.week_seed();
+        let seeds = &[
+            ctx.accounts.prize_pool.seed_prefix(),
+            week_seed.as_slice(),
+            ctx.accounts.prize_pool.category.as_bytes(),
+            &[ctx.accounts.prize_pool.bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.prize_pool_token_account.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: ctx.accounts.prize_pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.emergency_withdrawn = prize_pool
+        .emergency_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    prize_pool.emergency_destination = None;
+    prize_pool.emergency_unlock_at = 0;
+
+    emit_cpi!(EmergencyWithdrawn {
+        guardian: ctx.accounts.guardian.key(),
+        destination: announced,
+        amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
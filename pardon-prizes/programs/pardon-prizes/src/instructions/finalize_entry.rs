@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::EntryFinalized;
+use crate::math::hybrid_score;
+use crate::state::{
+    Config, Entry, Jackpot, PoolRole, PrizePool, WeekStats, DELEGATE_FINALIZE_ENTRY,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeEntry<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Posting,
+            DELEGATE_FINALIZE_ENTRY,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"entry", prize_pool.key().as_ref(), entry.player.as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, Entry>,
+    
+    /// The pool's poster or its delegate, see PoolRoles
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Required once the config has a jackpot
+    #[account(seeds = [b"jackpot"], bump = jackpot.bump)]
+    pub jackpot: Option<Account<'info, Jackpot>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + WeekStats::INIT_SPACE,
+        seeds = [b"week_stats", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub week_stats: Account<'info, WeekStats>,
+}
+
+pub fn handler(ctx: Context<FinalizeEntry>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let entry = &mut ctx.accounts.entry;
+    require!(!entry.finalized, ErrorCode::EntryFinalized);
+    // award_jackpot counts on every qualifier being marked before the draw
+    require!(!ctx.accounts.prize_pool.results_final(), ErrorCode::ResultsFinal);
+
+    entry.final_score = hybrid_score(
+        entry.score,
+        entry.votes,
+        config.judge_weight,
+        config.vote_weight,
+    );
+    entry.finalized = true;
+
+    if config.jackpot_mint != Pubkey::default() {
+        let jackpot = ctx.accounts.jackpot.as_ref().ok_or(ErrorCode::JackpotRequired)?;
+        if entry.score >= jackpot.trigger_score {
+            entry.jackpot_qualified = true;
+            ctx.accounts.prize_pool.jackpot_qualifiers += 1;
+        }
+    }
+
+    let week_stats = &mut ctx.accounts.week_stats;
+    week_stats.open(
+        ctx.accounts.prize_pool.key(),
+        &ctx.accounts.prize_pool,
+        ctx.bumps.week_stats,
+    );
+    week_stats.record_entry(entry.score);
+
+    emit_cpi!(EntryFinalized {
+        player: entry.player,
+        score: entry.score,
+        votes: entry.votes,
+        final_score: entry.final_score,
+        week_id: ctx.accounts.prize_pool.week_id.clone(),
+        category: ctx.accounts.prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::LeaderboardFinalized;
+use crate::state::{PoolRole, PrizePool, CLAIM_WINDOW_SECONDS, DELEGATE_FINALIZE_LEADERBOARD};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeLeaderboard<'info> {
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Posting,
+            DELEGATE_FINALIZE_LEADERBOARD,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// The pool's poster or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<FinalizeLeaderboard>, root: [u8; 32], entry_count: u32) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    require!(prize_pool.leaderboard_root.is_none(), ErrorCode::LeaderboardFinalized);
+    require!(entry_count > 0, ErrorCode::EmptyLeaderboard);
+
+    prize_pool.leaderboard_root = Some(root);
+    prize_pool.leaderboard_entries = entry_count;
+    prize_pool.claim_deadline = Clock::get()?.unix_timestamp + CLAIM_WINDOW_SECONDS;
+
+    emit_cpi!(LeaderboardFinalized {
+        root,
+        entry_count,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::BonusFunded;
+use crate::state::PrizePool;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundBonus<'info> {
+    #[account(mut, has_one = bonus_sponsor @ ErrorCode::BonusSponsorMismatch)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = sponsor_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+    
+    pub bonus_sponsor: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FundBonus>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        ctx.accounts.prize_pool.payouts_pending(),
+        ErrorCode::BonusLocked
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.sponsor_token_account.to_account_info(),
+        to: ctx.accounts.prize_pool_token_account.to_account_info(),
+        authority: ctx.accounts.bonus_sponsor.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    // Held apart from total_funded so fees and rank shares never touch it
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.bonus_reserve += amount;
+
+    emit_cpi!(BonusFunded {
+        sponsor: ctx.accounts.bonus_sponsor.key(),
+        amount,
+        bonus_reserve: prize_pool.bonus_reserve,
+        bonus_multiplier_bps: prize_pool.bonus_multiplier_bps,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
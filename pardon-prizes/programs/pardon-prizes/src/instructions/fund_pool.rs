@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::caller::require_allowed_caller;
+use crate::errors::ErrorCode;
+use crate::events::PoolFunded;
+use crate::state::{Config, FunderRecord, PrizePool, WeekStats};
+
+/// Longest memo accepted by fund_pool, in bytes
+pub const MAX_MEMO_LEN: usize = 64;
+
+/**
+ * `vault` is the pool's vault for deposits in the pool mint, or the
+ * ["mint_vault", prize_pool, mint] sub-vault for another allowlisted mint
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundPool<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + FunderRecord::INIT_SPACE,
+        seeds = [b"funder", prize_pool.key().as_ref(), funder.key().as_ref()],
+        bump
+    )]
+    pub funder_record: Account<'info, FunderRecord>,
+    
+    #[account(
+        mut,
+        constraint = funder_token_account.mint == vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    
+    /// CHECK: the instructions sysvar, naming the program that CPI'd into us, if any
+    #[account(address = instructions_sysvar::ID @ ErrorCode::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + WeekStats::INIT_SPACE,
+        seeds = [b"week_stats", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub week_stats: Account<'info, WeekStats>,
+}
+
+pub fn handler(ctx: Context<FundPool>, amount: u64, memo: String) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(memo.len() <= MAX_MEMO_LEN, ErrorCode::MemoTooLong);
+
+    let prize_pool = &ctx.accounts.prize_pool;
+    let config = &ctx.accounts.config;
+    require_allowed_caller(config, &ctx.accounts.instructions)?;
+    let mint = ctx.accounts.vault.mint;
+    let expected_vault = if mint == prize_pool.mint {
+        require!(config.accepts_mint(&mint), ErrorCode::MintNotAllowed);
+        prize_pool.vault
+    } else {
+        require!(config.funding_mints.contains(&mint), ErrorCode::MintNotAllowed);
+        Pubkey::find_program_address(
+            &[b"mint_vault", prize_pool.key().as_ref(), mint.as_ref()],
+            &crate::ID,
+        )
+        .0
+    };
+    require_keys_eq!(ctx.accounts.vault.key(), expected_vault, ErrorCode::VaultMismatch);
+
+    ctx.accounts
+        .week_stats
+        .open(prize_pool.key(), prize_pool, ctx.bumps.week_stats);
+
+    let record = &mut ctx.accounts.funder_record;
+    if record.funder == Pubkey::default() {
+        record.prize_pool = prize_pool.key();
+        record.funder = ctx.accounts.funder.key();
+        record.bump = ctx.bumps.funder_record;
+    }
+    require!(
+        !prize_pool.whitelist_only || record.whitelisted,
+        ErrorCode::FunderNotWhitelisted
+    );
+    // Allowlisted mints are variants of the same asset, so the cap counts them 1:1
+    record.deposited += amount;
+    require!(
+        prize_pool.funder_cap == 0 || record.deposited <= prize_pool.funder_cap,
+        ErrorCode::FunderCapExceeded
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    // total_funded tracks the vault distribute_prizes pays from
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    if mint == prize_pool.mint {
+        prize_pool.total_funded += amount;
+        ctx.accounts.week_stats.total_funded += amount;
+    }
+
+    emit_cpi!(PoolFunded {
+        funder: ctx.accounts.funder.key(),
+        mint,
+        amount,
+        memo,
+        total_funded: prize_pool.total_funded,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::RentRebatesFunded;
+use crate::state::PrizePool;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundRentRebates<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundRentRebates>, cap: u64, amount: u64) -> Result<()> {
+    // Archived pools take no more claims, so there is no rent left to rebate
+    require!(!ctx.accounts.prize_pool.archived, ErrorCode::PoolArchived);
+
+    if amount > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.prize_pool.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, amount)?;
+    }
+
+    // Whatever isn't rebated goes back to the authority with the pool's rent when it closes
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.rent_rebate_cap = cap;
+    prize_pool.rent_rebate_reserve += amount;
+
+    emit_cpi!(RentRebatesFunded {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        rent_rebate_cap: cap,
+        rent_rebate_reserve: prize_pool.rent_rebate_reserve,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
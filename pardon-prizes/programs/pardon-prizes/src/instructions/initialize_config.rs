@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::program::PardonPrizes;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::InvalidProgramData
+    )]
+    pub program: Program<'info, PardonPrizes>,
+    
+    /// Only the upgrade authority may claim the admin role
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeConfig>,
+    creator: Pubkey,
+    creator_share_bps: u16,
+) -> Result<()> {
+    require!(
+        creator_share_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidCreatorShare
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.creator = creator;
+    config.creator_share_bps = creator_share_bps;
+    config.cooldown_rank = 0;
+    config.judge_weight = 1;
+    config.vote_weight = 0;
+    config.quadratic_votes = false;
+    config.stake_mint = Pubkey::default();
+    config.stake_tiers = Vec::new();
+    config.total_staked = 0;
+    config.staker_share_bps = 0;
+    config.reward_per_share = 0;
+    config.guardian = Pubkey::default();
+    config.funding_mints = Vec::new();
+    config.treasury = Pubkey::default();
+    config.jackpot_mint = Pubkey::default();
+    config.jackpot_bps = 0;
+    config.streak_mint = Pubkey::default();
+    config.streak_bonus_bps = 0;
+    config.min_streak_weeks = 0;
+    config.insurance_mint = Pubkey::default();
+    config.insurance_bps = 0;
+    config.insurance_paid = 0;
+    config.auto_close_after_secs = 0;
+    config.week_epoch_start = 0;
+    config.pool_defaults = None;
+    config.require_scoring_transcripts = false;
+    config.enclave_signer = None;
+    config.cpi_callers = Vec::new();
+    config.authority_history = Vec::new();
+    config.bump = ctx.bumps.config;
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::math::{MAX_RANK, MAX_SCORE};
+use crate::state::{
+    Config, GlobalStats, MintStats, PoolRoles, PrizePool, ScoreCurve, MAX_CATEGORY_LEN,
+};
+
+#[derive(Accounts)]
+#[instruction(week_id: String, category: String)]
+pub struct InitializePrizePool<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PrizePool::INIT_SPACE,
+        seeds = [b"prize_pool", week_id.as_bytes(), category.as_bytes()],
+        bump
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(constraint = config.accepts_mint(&mint.key()) @ ErrorCode::MintNotAllowed)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = prize_pool
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintStats::INIT_SPACE,
+        seeds = [b"mint_stats", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+}
+
+pub fn handler(
+    ctx: Context<InitializePrizePool>,
+    week_id: String,
+    category: String,
+    max_winners: u8,
+    rank_shares_bps: Vec<u16>,
+) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    setup_pool(
+        &mut accounts.prize_pool,
+        accounts.authority.key(),
+        &accounts.mint,
+        &accounts.vault,
+        week_id,
+        None,
+        category,
+        max_winners,
+        rank_shares_bps,
+        ctx.bumps.prize_pool,
+    )?;
+    accounts.global_stats.record_pool(&accounts.prize_pool.week_id);
+    accounts.mint_stats.open(accounts.mint.key(), ctx.bumps.mint_stats);
+    Ok(())
+}
+
+/**
+ * Validates a new pool's category and split and writes every field,
+ * shared by initialize_prize_pool and initialize_weekly_pool
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn setup_pool(
+    prize_pool: &mut PrizePool,
+    authority: Pubkey,
+    mint: &Account<Mint>,
+    vault: &Account<TokenAccount>,
+    week_id: String,
+    week_index: Option<u32>,
+    category: String,
+    max_winners: u8,
+    rank_shares_bps: Vec<u16>,
+    bump: u8,
+) -> Result<()> {
+    require!(
+        max_winners > 0 && max_winners <= MAX_RANK,
+        ErrorCode::InvalidMaxWinners
+    );
+    require!(
+        !category.is_empty() && category.len() <= MAX_CATEGORY_LEN,
+        ErrorCode::InvalidCategory
+    );
+    PrizePool::validate_rank_shares(&rank_shares_bps, max_winners)?;
+
+    prize_pool.authority = authority;
+    prize_pool.roles = PoolRoles::held_by(prize_pool.authority);
+    prize_pool.delegation = None;
+    prize_pool.authority_history = Vec::new();
+    prize_pool.week_id = week_id;
+    prize_pool.week_index = week_index;
+    prize_pool.category = category;
+    prize_pool.rank_shares_bps = rank_shares_bps;
+    prize_pool.name = String::new();
+    prize_pool.metadata_uri = String::new();
+    prize_pool.mint = mint.key();
+    prize_pool.vault = vault.key();
+    prize_pool.total_funded = 0;
+    prize_pool.funder_cap = 0;
+    prize_pool.whitelist_only = false;
+    prize_pool.exclusive_winners = false;
+    prize_pool.allow_rank_gaps = false;
+    prize_pool.airdrop_threshold = None;
+    prize_pool.qualification_tiers = Vec::new();
+    prize_pool.raw_score_max = MAX_SCORE as u16;
+    prize_pool.score_curve = ScoreCurve::Linear;
+    prize_pool.judges = Vec::new();
+    prize_pool.judge_quorum = 0;
+    prize_pool.judging_ends_at = 0;
+    prize_pool.votes_escrowed = 0;
+    prize_pool.min_holding = 0;
+    prize_pool.snapshot_start_slot = 0;
+    prize_pool.snapshot_end_slot = 0;
+    prize_pool.entry_collection = None;
+    prize_pool.burn_entry_ticket = false;
+    prize_pool.gatekeeper_network = None;
+    prize_pool.wallet_age = None;
+    prize_pool.entry_fee = 0;
+    prize_pool.rake_bps = 0;
+    prize_pool.entry_fees_collected = 0;
+    prize_pool.entry_bond = 0;
+    prize_pool.bonds_held = 0;
+    prize_pool.bonds_slashed = 0;
+    prize_pool.report_bond = 0;
+    prize_pool.report_bounty_bps = 0;
+    prize_pool.entries_closed = false;
+    prize_pool.jackpot_drawn = false;
+    prize_pool.jackpot_qualifiers = 0;
+    prize_pool.bonus_multiplier_bps = 0;
+    prize_pool.bonus_sponsor = Pubkey::default();
+    prize_pool.bonus_reserve = 0;
+    prize_pool.unpaid_reserve = 0;
+    prize_pool.usd_prizes = None;
+    prize_pool.happy_hours = Vec::new();
+    prize_pool.emergency_destination = None;
+    prize_pool.emergency_unlock_at = 0;
+    prize_pool.scoring_transcript = None;
+    prize_pool.scoring_proof_verified = false;
+    prize_pool.leaderboard_root = None;
+    prize_pool.leaderboard_entries = 0;
+    prize_pool.claim_deadline = 0;
+    prize_pool.claim_basis = 0;
+    prize_pool.airdrop_claims = 0;
+    prize_pool.airdrop_weight = 0;
+    prize_pool.airdrop_weight_claimed = 0;
+    prize_pool.archived = false;
+    prize_pool.archived_at = 0;
+    prize_pool.expired_swept = false;
+    prize_pool.swept = 0;
+    prize_pool.expired_next_rank = 0;
+    prize_pool.rent_rebate_cap = 0;
+    prize_pool.rent_rebate_reserve = 0;
+    prize_pool.total_distributed = 0;
+    prize_pool.total_fees = 0;
+    prize_pool.emergency_withdrawn = 0;
+    prize_pool.rolled_over = 0;
+    prize_pool.distribution_nonce = 0;
+    prize_pool.winners_hash = [0; 32];
+    prize_pool.distribution_basis = 0;
+    prize_pool.paid_ranks = 0;
+    prize_pool.max_winners = max_winners;
+    prize_pool.bump = bump;
+    Ok(())
+}
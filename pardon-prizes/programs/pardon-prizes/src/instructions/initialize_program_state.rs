@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, ProgramState};
+
+#[derive(Accounts)]
+pub struct InitializeProgramState<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProgramState::INIT_SPACE,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeProgramState>) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.version = ProgramState::CURRENT_VERSION.to_string();
+    program_state.features = 0;
+    program_state.bump = ctx.bumps.program_state;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::initialize_prize_pool::setup_pool;
+use crate::errors::ErrorCode;
+use crate::math::{iso_week_id, week_index_at, week_start};
+use crate::state::{Config, GlobalStats, MintStats, PrizePool, WEEKLY_POOL_SEED};
+
+#[derive(Accounts)]
+#[instruction(week_index: u32, category: String)]
+pub struct InitializeWeeklyPool<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PrizePool::INIT_SPACE,
+        seeds = [WEEKLY_POOL_SEED, week_index.to_le_bytes().as_ref(), category.as_bytes()],
+        bump
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(constraint = config.accepts_mint(&mint.key()) @ ErrorCode::MintNotAllowed)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = prize_pool
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintStats::INIT_SPACE,
+        seeds = [b"mint_stats", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeWeeklyPool>,
+    week_index: u32,
+    category: String,
+    max_winners: u8,
+    rank_shares_bps: Vec<u16>,
+) -> Result<()> {
+    let epoch_start = ctx.accounts.config.week_epoch_start;
+    require!(epoch_start > 0, ErrorCode::WeekScheduleUnset);
+    // The current week, or the next one so it can be set up ahead of time
+    let current = week_index_at(epoch_start, Clock::get()?.unix_timestamp)
+        .ok_or(ErrorCode::WeekNotOpen)?;
+    require!(
+        week_index == current || Some(week_index) == current.checked_add(1),
+        ErrorCode::WeekNotOpen
+    );
+
+    let accounts = &mut *ctx.accounts;
+    setup_pool(
+        &mut accounts.prize_pool,
+        accounts.authority.key(),
+        &accounts.mint,
+        &accounts.vault,
+        iso_week_id(week_start(epoch_start, week_index)),
+        Some(week_index),
+        category,
+        max_winners,
+        rank_shares_bps,
+        ctx.bumps.prize_pool,
+    )?;
+    accounts.global_stats.record_pool(&accounts.prize_pool.week_id);
+    accounts.mint_stats.open(accounts.mint.key(), ctx.bumps.mint_stats);
+    Ok(())
+}
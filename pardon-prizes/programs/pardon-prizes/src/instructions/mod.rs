@@ -0,0 +1,218 @@
+// Each module exposes its own `handler`; lib.rs always calls it by module path
+#![allow(ambiguous_glob_reexports)]
+
+pub mod aggregate_scores;
+pub mod announce_emergency_withdraw;
+pub mod append_winners;
+pub mod archive_week;
+pub mod attest_results;
+pub mod attest_wallet_age;
+pub mod authorize_payout_wallet;
+pub mod auto_close_prize_pool;
+pub mod award_jackpot;
+pub mod claim_bet_winnings;
+pub mod claim_many;
+pub mod claim_prize;
+pub mod claim_staking_rewards;
+pub mod close_entries;
+pub mod close_prize_pool;
+pub mod close_registrations;
+pub mod close_winner_list;
+pub mod configure_insurance;
+pub mod configure_jackpot;
+pub mod configure_streak_bonus;
+pub mod create_player_profile;
+pub mod current_week;
+pub mod delegate_authority;
+pub mod distribute_prizes;
+pub mod emergency_withdraw;
+pub mod finalize_entry;
+pub mod finalize_leaderboard;
+pub mod fund_bonus;
+pub mod fund_pool;
+pub mod fund_rent_rebates;
+pub mod initialize_config;
+pub mod initialize_global_stats;
+pub mod initialize_prize_pool;
+pub mod initialize_program_state;
+pub mod initialize_weekly_pool;
+pub mod open_bet_market;
+pub mod open_mint_vault;
+pub mod open_winner_list;
+pub mod pay_insurance_claim;
+pub mod place_bet;
+pub mod pool_status;
+pub mod post_scoring_transcript;
+pub mod preview_prize;
+pub mod record_balance_snapshot;
+pub mod refund_bet;
+pub mod refund_bonus;
+pub mod refund_entry_bond;
+pub mod register_player;
+pub mod register_team;
+pub mod report_cheater;
+pub mod resolve_report;
+pub mod retry_unpaid;
+pub mod rollover_week;
+pub mod set_airdrop_mode;
+pub mod set_airdrop_weight;
+pub mod set_auto_close;
+pub mod set_bonus_multiplier;
+pub mod set_cpi_callers;
+pub mod set_enclave_signer;
+pub mod set_entry_bond;
+pub mod set_entry_collection;
+pub mod set_entry_fee;
+pub mod set_exclusive_winners;
+pub mod set_feature_flag;
+pub mod set_funder_allowed;
+pub mod set_funding_mints;
+pub mod set_funding_rules;
+pub mod set_gatekeeper_network;
+pub mod set_guardian;
+pub mod set_happy_hours;
+pub mod set_holder_requirement;
+pub mod set_judges;
+pub mod set_payout_wallet;
+pub mod set_pool_defaults;
+pub mod set_pool_metadata;
+pub mod set_pool_role;
+pub mod set_prize_split;
+pub mod set_quadratic_votes;
+pub mod set_qualification_tiers;
+pub mod set_rank_gaps_allowed;
+pub mod set_report_bounty;
+pub mod set_score_normalization;
+pub mod set_score_weights;
+pub mod set_scoring_transcripts_required;
+pub mod set_scoring_verifier;
+pub mod set_stake_tiers;
+pub mod set_staker_share;
+pub mod set_treasury;
+pub mod set_usd_prizes;
+pub mod set_wallet_age_requirement;
+pub mod set_week_schedule;
+pub mod set_win_cooldown;
+pub mod settle_bets;
+pub mod slash_entry_bond;
+pub mod stake;
+pub mod submit_judge_score;
+pub mod submit_score;
+pub mod sweep_expired;
+pub mod sweep_mint_vault;
+pub mod sync_program_version;
+pub mod unlock_votes;
+pub mod unstake;
+pub mod update_config;
+pub mod update_team;
+pub mod verify_accounting;
+pub mod verify_scoring_proof;
+pub mod vote_for_entry;
+
+pub use aggregate_scores::*;
+pub use announce_emergency_withdraw::*;
+pub use append_winners::*;
+pub use archive_week::*;
+pub use attest_results::*;
+pub use attest_wallet_age::*;
+pub use authorize_payout_wallet::*;
+pub use auto_close_prize_pool::*;
+pub use award_jackpot::*;
+pub use claim_bet_winnings::*;
+pub use claim_many::*;
+pub use claim_prize::*;
+pub use claim_staking_rewards::*;
+pub use close_entries::*;
+pub use close_prize_pool::*;
+pub use close_registrations::*;
+pub use close_winner_list::*;
+pub use configure_insurance::*;
+pub use configure_jackpot::*;
+pub use configure_streak_bonus::*;
+pub use create_player_profile::*;
+pub use current_week::*;
+pub use delegate_authority::*;
+pub use distribute_prizes::*;
+pub use emergency_withdraw::*;
+pub use finalize_entry::*;
+pub use finalize_leaderboard::*;
+pub use fund_bonus::*;
+pub use fund_pool::*;
+pub use fund_rent_rebates::*;
+pub use initialize_config::*;
+pub use initialize_global_stats::*;
+pub use initialize_prize_pool::*;
+pub use initialize_program_state::*;
+pub use initialize_weekly_pool::*;
+pub use open_bet_market::*;
+pub use open_mint_vault::*;
+pub use open_winner_list::*;
+pub use pay_insurance_claim::*;
+pub use place_bet::*;
+pub use pool_status::*;
+pub use post_scoring_transcript::*;
+pub use preview_prize::*;
+pub use record_balance_snapshot::*;
+pub use refund_bet::*;
+pub use refund_bonus::*;
+pub use refund_entry_bond::*;
+pub use register_player::*;
+pub use register_team::*;
+pub use report_cheater::*;
+pub use resolve_report::*;
+pub use retry_unpaid::*;
+pub use rollover_week::*;
+pub use set_airdrop_mode::*;
+pub use set_airdrop_weight::*;
+pub use set_auto_close::*;
+pub use set_bonus_multiplier::*;
+pub use set_cpi_callers::*;
+pub use set_enclave_signer::*;
+pub use set_entry_bond::*;
+pub use set_entry_collection::*;
+pub use set_entry_fee::*;
+pub use set_exclusive_winners::*;
+pub use set_feature_flag::*;
+pub use set_funder_allowed::*;
+pub use set_funding_mints::*;
+pub use set_funding_rules::*;
+pub use set_gatekeeper_network::*;
+pub use set_guardian::*;
+pub use set_happy_hours::*;
+pub use set_holder_requirement::*;
+pub use set_judges::*;
+pub use set_payout_wallet::*;
+pub use set_pool_defaults::*;
+pub use set_pool_metadata::*;
+pub use set_pool_role::*;
+pub use set_prize_split::*;
+pub use set_quadratic_votes::*;
+pub use set_qualification_tiers::*;
+pub use set_rank_gaps_allowed::*;
+pub use set_report_bounty::*;
+pub use set_score_normalization::*;
+pub use set_score_weights::*;
+pub use set_scoring_transcripts_required::*;
+pub use set_scoring_verifier::*;
+pub use set_stake_tiers::*;
+pub use set_staker_share::*;
+pub use set_treasury::*;
+pub use set_usd_prizes::*;
+pub use set_wallet_age_requirement::*;
+pub use set_week_schedule::*;
+pub use set_win_cooldown::*;
+pub use settle_bets::*;
+pub use slash_entry_bond::*;
+pub use stake::*;
+pub use submit_judge_score::*;
+pub use submit_score::*;
+pub use sweep_expired::*;
+pub use sweep_mint_vault::*;
+pub use sync_program_version::*;
+pub use unlock_votes::*;
+pub use unstake::*;
+pub use update_config::*;
+pub use update_team::*;
+pub use verify_accounting::*;
+pub use verify_scoring_proof::*;
+pub use vote_for_entry::*;
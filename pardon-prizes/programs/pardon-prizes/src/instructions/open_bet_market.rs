@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::{BetMarket, PrizePool, BET_SETTLE_WINDOW_SECONDS};
+
+#[derive(Accounts)]
+pub struct OpenBetMarket<'info> {
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BetMarket::INIT_SPACE,
+        seeds = [b"bet_market", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub bet_market: Account<'info, BetMarket>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"bet_vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = bet_market
+    )]
+    pub bet_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = prize_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenBetMarket>, closes_at: i64, rake_bps: u16) -> Result<()> {
+    require!(rake_bps <= BPS_DENOMINATOR, ErrorCode::InvalidRake);
+    // Once the leaderboard is published the outcome is known
+    require!(
+        closes_at > Clock::get()?.unix_timestamp
+            && ctx.accounts.prize_pool.leaderboard_root.is_none(),
+        ErrorCode::InvalidBetClose
+    );
+
+    let market = &mut ctx.accounts.bet_market;
+    market.prize_pool = ctx.accounts.prize_pool.key();
+    market.vault = ctx.accounts.bet_vault.key();
+    market.closes_at = closes_at;
+    market.settle_deadline = closes_at
+        .checked_add(BET_SETTLE_WINDOW_SECONDS)
+        .ok_or(ErrorCode::InvalidBetClose)?;
+    market.rake_bps = rake_bps;
+    market.total_staked = 0;
+    market.winner = None;
+    market.winning_stake = 0;
+    market.payout_pool = 0;
+    market.bump = ctx.bumps.bet_market;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, PrizePool};
+
+/// Sub-vault holding a pool's deposits in an allowlisted mint other than its own
+#[derive(Accounts)]
+pub struct OpenMintVault<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        constraint = config.funding_mints.contains(&mint.key()) @ ErrorCode::MintNotAllowed,
+        constraint = mint.key() != prize_pool.mint @ ErrorCode::MintMismatch
+    )]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"mint_vault", prize_pool.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = prize_pool
+    )]
+    pub mint_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(_ctx: Context<OpenMintVault>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PoolRole, PrizePool, WinnerList, DELEGATE_DISTRIBUTE_PRIZES};
+
+#[derive(Accounts)]
+pub struct OpenWinnerList<'info> {
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Distribution,
+            DELEGATE_DISTRIBUTE_PRIZES,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + WinnerList::INIT_SPACE,
+        seeds = [b"winner_list", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub winner_list: AccountLoader<'info, WinnerList>,
+    
+    /// The pool's distributor or its delegate, see PoolRoles
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenWinnerList>) -> Result<()> {
+    let mut winner_list = ctx.accounts.winner_list.load_init()?;
+    winner_list.prize_pool = ctx.accounts.prize_pool.key();
+    winner_list.count = 0;
+    winner_list.bump = ctx.bumps.winner_list;
+    Ok(())
+}
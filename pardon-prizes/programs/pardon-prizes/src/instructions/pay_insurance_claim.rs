@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_config_vault;
+use crate::errors::ErrorCode;
+use crate::events::InsuranceClaimPaid;
+use crate::state::{Config, PrizePool};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PayInsuranceClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// The pool whose payout went wrong, recorded in the claim's event
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = claimant_token_account.mint == insurance_vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+    
+    pub guardian: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<PayInsuranceClaim>,
+    amount: u64,
+    incident_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        amount > 0 && amount <= ctx.accounts.insurance_vault.amount,
+        ErrorCode::InsufficientFunds
+    );
+
+    let to = ctx.accounts.claimant_token_account.to_account_info();
+    pay_from_config_vault(
+        &ctx.accounts.config,
+        &ctx.accounts.insurance_vault,
+        &to,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+    ctx.accounts.config.insurance_paid += amount;
+
+    let prize_pool = &ctx.accounts.prize_pool;
+    emit_cpi!(InsuranceClaimPaid {
+        prize_pool: prize_pool.key(),
+        claimant: ctx.accounts.claimant_token_account.owner,
+        amount,
+        incident_hash,
+        guardian: ctx.accounts.guardian.key(),
+        remaining: ctx.accounts.insurance_vault.amount - amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::BetPlaced;
+use crate::state::{Bet, BetMarket, BetOutcome, PrizePool, Registration};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct PlaceBet<'info> {
+    #[account(
+        mut,
+        seeds = [b"bet_market", bet_market.prize_pool.as_ref()],
+        bump = bet_market.bump
+    )]
+    pub bet_market: Account<'info, BetMarket>,
+    
+    #[account(address = bet_market.prize_pool)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = bet_market.vault @ ErrorCode::VaultMismatch)]
+    pub bet_vault: Account<'info, TokenAccount>,
+    
+    /// Only registered players can be backed
+    #[account(
+        seeds = [b"registration", prize_pool.key().as_ref(), player.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        init_if_needed,
+        payer = bettor,
+        space = 8 + BetOutcome::INIT_SPACE,
+        seeds = [b"bet_outcome", bet_market.key().as_ref(), player.as_ref()],
+        bump
+    )]
+    pub bet_outcome: Account<'info, BetOutcome>,
+    
+    #[account(
+        init_if_needed,
+        payer = bettor,
+        space = 8 + Bet::INIT_SPACE,
+        seeds = [b"bet", bet_market.key().as_ref(), bettor.key().as_ref()],
+        bump
+    )]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        mut,
+        constraint = bettor_token_account.mint == bet_vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub bettor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PlaceBet>, player: Pubkey, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    // A published leaderboard gives the winner away, and a cancelled week has none,
+    // whatever closes_at says
+    require!(
+        Clock::get()?.unix_timestamp < ctx.accounts.bet_market.closes_at
+            && ctx.accounts.prize_pool.leaderboard_root.is_none()
+            && !ctx.accounts.prize_pool.is_cancelled(),
+        ErrorCode::BettingClosed
+    );
+
+    let market_key = ctx.accounts.bet_market.key();
+    let bettor = ctx.accounts.bettor.key();
+    let bet = &mut ctx.accounts.bet;
+    if bet.bettor == Pubkey::default() {
+        bet.bet_market = market_key;
+        bet.bettor = bettor;
+        bet.player = player;
+        bet.bump = ctx.bumps.bet;
+    }
+    require_keys_eq!(bet.player, player, ErrorCode::BetPlayerMismatch);
+    bet.amount += amount;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.bettor_token_account.to_account_info(),
+        to: ctx.accounts.bet_vault.to_account_info(),
+        authority: ctx.accounts.bettor.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    let outcome = &mut ctx.accounts.bet_outcome;
+    outcome.bet_market = market_key;
+    outcome.player = player;
+    outcome.total_staked += amount;
+    outcome.bump = ctx.bumps.bet_outcome;
+    let player_total = outcome.total_staked;
+
+    let market = &mut ctx.accounts.bet_market;
+    market.total_staked += amount;
+
+    emit_cpi!(BetPlaced {
+        prize_pool: market.prize_pool,
+        bettor,
+        player,
+        amount,
+        player_total,
+        total_staked: market.total_staked,
+    });
+
+    Ok(())
+}
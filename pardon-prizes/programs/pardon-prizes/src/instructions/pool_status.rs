@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use super::preview_prize::prize_basis;
+use crate::errors::ErrorCode;
+use crate::state::{Config, PrizePool};
+
+/// What pool_status returns
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PoolStatus {
+    /// Vault balance, the sponsor's bonus reserve included
+    pub balance: u64,
+    /// What the rank shares would be taken of if the pool paid out now, in tokens
+    pub prize_basis: u64,
+    pub bonus_reserve: u64,
+    /// What the vault should hold per the pool's counters, see verify_accounting
+    pub expected_balance: Option<u64>,
+    pub entries_closed: bool,
+    pub leaderboard_finalized: bool,
+    pub payouts_pending: bool,
+    pub archived: bool,
+    /// An emergency withdrawal was announced
+    pub cancelled: bool,
+}
+
+#[derive(Accounts)]
+pub struct GetPoolStatus<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<GetPoolStatus>) -> Result<PoolStatus> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let balance = ctx.accounts.prize_pool_token_account.amount;
+
+    Ok(PoolStatus {
+        balance,
+        prize_basis: prize_basis(&ctx.accounts.config, prize_pool, balance),
+        bonus_reserve: prize_pool.bonus_reserve,
+        expected_balance: prize_pool.expected_vault_balance(),
+        entries_closed: prize_pool.entries_closed,
+        leaderboard_finalized: prize_pool.leaderboard_root.is_some(),
+        payouts_pending: prize_pool.payouts_pending(),
+        archived: prize_pool.archived,
+        cancelled: prize_pool.is_cancelled(),
+    })
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ScoringTranscriptPosted;
+use crate::state::{
+    PoolRole, PrizePool, ScoringTranscript, DELEGATE_POST_SCORING_TRANSCRIPT, MAX_METADATA_URI_LEN,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PostScoringTranscript<'info> {
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Posting,
+            DELEGATE_POST_SCORING_TRANSCRIPT,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// The pool's poster or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<PostScoringTranscript>,
+    transcript_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Players dispute against this exact artifact, so it's published once and before payouts
+    require!(
+        prize_pool.scoring_transcript.is_none(),
+        ErrorCode::ScoringTranscriptPosted
+    );
+    require!(prize_pool.payouts_pending(), ErrorCode::ScoringTranscriptTooLate);
+    require!(
+        transcript_hash != [0; 32] && !uri.is_empty() && uri.len() <= MAX_METADATA_URI_LEN,
+        ErrorCode::InvalidScoringTranscript
+    );
+
+    prize_pool.scoring_transcript = Some(ScoringTranscript {
+        hash: transcript_hash,
+        uri: uri.clone(),
+        posted_at: Clock::get()?.unix_timestamp,
+    });
+
+    emit_cpi!(ScoringTranscriptPosted {
+        prize_pool: prize_pool.key(),
+        transcript_hash,
+        uri,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
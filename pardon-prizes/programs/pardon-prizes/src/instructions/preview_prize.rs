@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use super::distribute_prizes::pool_fees;
+use crate::errors::ErrorCode;
+use crate::math::{calculate_prize_with, stake_bonus, weighted_share, BPS_DENOMINATOR};
+use crate::state::{Config, PrizePool};
+
+/// What preview_prize returns
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrizePreview {
+    /// Amount the pool's rank shares are taken of, see prize_basis
+    pub basis: u64,
+    pub base_prize: u64,
+    /// Paid out of the sponsor's bonus reserve on top of the base prize
+    pub sponsor_bonus: u64,
+}
+
+#[derive(Accounts)]
+pub struct PreviewPrize<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+}
+
+/**
+ * What the pool's rank shares would be taken of if it paid out now: the
+ * basis fixed by the first claim, or else the vault less the sponsor's
+ * reserve, less the pool's cuts unless a distribution pass already took them
+ */
+pub(crate) fn prize_basis(config: &Config, prize_pool: &PrizePool, balance: u64) -> u64 {
+    if prize_pool.claim_basis > 0 {
+        return prize_pool.claim_basis;
+    }
+    let available = balance.saturating_sub(prize_pool.bonus_reserve);
+    if prize_pool.distribution_nonce > 0 {
+        return available;
+    }
+    available - pool_fees(config, &prize_pool.mint, available).total()
+}
+
+pub fn handler(ctx: Context<PreviewPrize>, rank: u8) -> Result<PrizePreview> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    // Airdrop shares ignore the rank but depend on how many qualified, and in which tier
+    match prize_pool.airdrop_threshold {
+        Some(_) => require!(
+            prize_pool.airdrop_total_weight() > 0,
+            ErrorCode::AirdropWeightUnset
+        ),
+        None => require!(
+            rank > 0 && rank <= prize_pool.max_winners,
+            ErrorCode::InvalidRank
+        ),
+    }
+    // USD prizes only have a token amount once distribute_prizes prices them
+    require!(prize_pool.usd_prizes.is_none(), ErrorCode::PreviewUnsupported);
+
+    let basis = prize_basis(
+        &ctx.accounts.config,
+        prize_pool,
+        ctx.accounts.prize_pool_token_account.amount,
+    );
+    let base_prize = match prize_pool.airdrop_threshold {
+        Some(_) => weighted_share(
+            basis,
+            BPS_DENOMINATOR as u64,
+            prize_pool.airdrop_total_weight(),
+        ),
+        None => calculate_prize_with(rank, basis, &prize_pool.rank_shares_bps),
+    };
+    let sponsor_bonus =
+        stake_bonus(base_prize, prize_pool.bonus_multiplier_bps).min(prize_pool.bonus_reserve);
+
+    Ok(PrizePreview {
+        basis,
+        base_prize,
+        sponsor_bonus,
+    })
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::{BalanceSnapshot, PrizePool};
+
+#[derive(Accounts)]
+pub struct RecordBalanceSnapshot<'info> {
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + BalanceSnapshot::INIT_SPACE,
+        seeds = [b"snapshot", prize_pool.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, BalanceSnapshot>,
+    
+    #[account(
+        constraint = wallet_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch,
+        constraint = wallet_token_account.owner == wallet.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub wallet_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordBalanceSnapshot>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    require!(
+        ctx.accounts.prize_pool.snapshots_open(slot),
+        ErrorCode::SnapshotsClosed
+    );
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.prize_pool = ctx.accounts.prize_pool.key();
+    snapshot.wallet = ctx.accounts.wallet.key();
+    snapshot.amount = ctx.accounts.wallet_token_account.amount;
+    snapshot.slot = slot;
+    snapshot.bump = ctx.bumps.snapshot;
+    Ok(())
+}
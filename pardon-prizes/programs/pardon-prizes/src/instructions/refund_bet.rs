@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::settle_bets::pay_from_bet_vault;
+use crate::errors::ErrorCode;
+use crate::events::BetRefunded;
+use crate::state::{Bet, BetMarket, BetOutcome, PrizePool, Registration};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundBet<'info> {
+    #[account(
+        mut,
+        seeds = [b"bet_market", bet_market.prize_pool.as_ref()],
+        bump = bet_market.bump
+    )]
+    pub bet_market: Account<'info, BetMarket>,
+    
+    #[account(address = bet_market.prize_pool)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = bet_market.vault @ ErrorCode::VaultMismatch)]
+    pub bet_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        close = bettor,
+        seeds = [b"bet", bet_market.key().as_ref(), bettor.key().as_ref()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        mut,
+        constraint = bettor_token_account.mint == bet_vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub bettor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    #[account(
+        mut,
+        seeds = [b"bet_outcome", bet_market.key().as_ref(), bet.player.as_ref()],
+        bump = bet_outcome.bump
+    )]
+    pub bet_outcome: Account<'info, BetOutcome>,
+    
+    /// The backed player's registration; a slashed bond disqualifies them
+    #[account(
+        seeds = [b"registration", prize_pool.key().as_ref(), bet.player.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+}
+
+pub fn handler(ctx: Context<RefundBet>) -> Result<()> {
+    require!(
+        ctx.accounts.bet_market.winner.is_none(),
+        ErrorCode::BetsAlreadySettled
+    );
+    // Nothing fair to settle the bet on: the week is cancelled, the backed
+    // player was caught cheating, or settle_bets never ran in time
+    require!(
+        ctx.accounts.prize_pool.is_cancelled()
+            || ctx.accounts.registration.bond_slashed
+            || Clock::get()?.unix_timestamp >= ctx.accounts.bet_market.settle_deadline,
+        ErrorCode::BetNotRefundable
+    );
+
+    let amount = ctx.accounts.bet.amount;
+    let to = ctx.accounts.bettor_token_account.to_account_info();
+    pay_from_bet_vault(
+        &ctx.accounts.bet_market,
+        &ctx.accounts.bet_vault,
+        &to,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    // The stake leaves the market, so a later settlement doesn't count it
+    let outcome = &mut ctx.accounts.bet_outcome;
+    outcome.total_staked = outcome.total_staked.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+    let market = &mut ctx.accounts.bet_market;
+    market.total_staked = market.total_staked.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(BetRefunded {
+        prize_pool: ctx.accounts.prize_pool.key(),
+        bettor: ctx.accounts.bettor.key(),
+        player: ctx.accounts.bet.player,
+        amount,
+    });
+
+    Ok(())
+}
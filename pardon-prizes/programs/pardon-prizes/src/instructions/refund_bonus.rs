@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::BonusRefunded;
+use crate::state::PrizePool;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundBonus<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = sponsor_token_account.owner == prize_pool.bonus_sponsor @ ErrorCode::TokenOwnerMismatch,
+        constraint = sponsor_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RefundBonus>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    // Later distribution passes still draw on the reserve, so only the
+    // authority decides when the winners are all paid; a cancelled week pays none
+    require!(
+        !prize_pool.payouts_pending() || prize_pool.is_cancelled(),
+        ErrorCode::PayoutsNotStarted
+    );
+    let amount = prize_pool.bonus_reserve;
+    require!(amount > 0, ErrorCode::InsufficientFunds);
+
+    let to = ctx.accounts.sponsor_token_account.to_account_info();
+    pay_from_vault(
+        &ctx.accounts.prize_pool,
+        &ctx.accounts.prize_pool_token_account,
+        &to,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.bonus_reserve = 0;
+
+    emit_cpi!(BonusRefunded {
+        sponsor: prize_pool.bonus_sponsor,
+        amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
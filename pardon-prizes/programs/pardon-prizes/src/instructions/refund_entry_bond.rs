@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::EntryBondRefunded;
+use crate::state::{PrizePool, Registration};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundEntryBond<'info> {
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        has_one = player @ ErrorCode::Unauthorized,
+        seeds = [b"registration", prize_pool.key().as_ref(), player.key().as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(mut, seeds = [b"bond_vault", prize_pool.key().as_ref()], bump)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = player_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch,
+        constraint = player_token_account.owner == player.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+    
+    pub player: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RefundEntryBond>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    // Cheating found before the week pays out can still be slashed
+    require!(
+        !prize_pool.payouts_pending() || prize_pool.is_cancelled(),
+        ErrorCode::PayoutsNotStarted
+    );
+    let amount = ctx.accounts.registration.bond;
+    require!(amount > 0, ErrorCode::NoBondHeld);
+    require!(ctx.accounts.registration.pending_reports == 0, ErrorCode::ReportPending);
+
+    let to = ctx.accounts.player_token_account.to_account_info();
+    pay_from_vault(
+        &ctx.accounts.prize_pool,
+        &ctx.accounts.bond_vault,
+        &to,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    ctx.accounts.registration.bond = 0;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.bonds_held = prize_pool.bonds_held.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(EntryBondRefunded {
+        prize_pool: prize_pool.key(),
+        player: ctx.accounts.player.key(),
+        amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::civic::check_gateway_token;
+use crate::errors::ErrorCode;
+use crate::events::PlayerRegistered;
+use crate::metaplex::{metadata_address, parse_metadata, METADATA_PROGRAM_ID};
+use crate::state::{PlayerProfile, PrizePool, Registration};
+
+/**
+ * On collection-gated pools the player also passes a token account holding
+ * the NFT and that NFT's Metaplex metadata account; ticket pools add the
+ * NFT's mint and the token program to burn it. Pools with an entry fee take
+ * it from the player's token account into the vault, and pools with an entry
+ * bond hold it in the bond vault until the week ends. Pools gated by a
+ * gatekeeper network take the player's Civic gateway token, and pools with a
+ * wallet age requirement the player's profile attested by its registrar.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterPlayer<'info> {
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Registration::INIT_SPACE,
+        seeds = [b"registration", prize_pool.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(mut)]
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Owner and address checked against the metadata program in the handler
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub nft_mint: Option<Account<'info, Mint>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    #[account(mut)]
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut)]
+    pub prize_pool_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Owner and contents checked by check_gateway_token
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    
+    #[account(seeds = [b"player", player.key().as_ref()], bump = player_profile.bump)]
+    pub player_profile: Option<Account<'info, PlayerProfile>>,
+    
+    #[account(mut, seeds = [b"bond_vault", prize_pool.key().as_ref()], bump)]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<RegisterPlayer>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let player = ctx.accounts.player.key();
+    require!(
+        !prize_pool.entries_closed && !prize_pool.archived,
+        ErrorCode::RegistrationClosed
+    );
+
+    if let Some(network) = prize_pool.gatekeeper_network {
+        check_gateway_token(ctx.accounts.gateway_token.as_deref(), &player, &network)?;
+    }
+    if let Some(requirement) = &prize_pool.wallet_age {
+        let profile = ctx
+            .accounts
+            .player_profile
+            .as_ref()
+            .ok_or(ErrorCode::WalletAgeAttestationRequired)?;
+        require_keys_eq!(
+            profile.age_registrar,
+            requirement.registrar,
+            ErrorCode::WalletAgeAttestationRequired
+        );
+        require!(
+            requirement.is_met_by(profile, Clock::get()?.unix_timestamp),
+            ErrorCode::WalletTooNew
+        );
+    }
+
+    let nft_mint = match prize_pool.entry_collection {
+        Some(collection) => {
+            let (Some(token_account), Some(metadata)) =
+                (&ctx.accounts.nft_token_account, &ctx.accounts.nft_metadata)
+            else {
+                return err!(ErrorCode::CollectionNftRequired);
+            };
+            require_keys_eq!(token_account.owner, player, ErrorCode::InvalidCollectionNft);
+            require!(token_account.amount >= 1, ErrorCode::InvalidCollectionNft);
+            require_keys_eq!(
+                metadata.key(),
+                metadata_address(&token_account.mint),
+                ErrorCode::InvalidCollectionNft
+            );
+            require_keys_eq!(*metadata.owner, METADATA_PROGRAM_ID, ErrorCode::InvalidCollectionNft);
+
+            let info = parse_metadata(&metadata.try_borrow_data()?)
+                .ok_or(ErrorCode::InvalidCollectionNft)?;
+            require!(
+                info.verified_collection == Some(collection),
+                ErrorCode::InvalidCollectionNft
+            );
+            Some(token_account.mint)
+        }
+        None => None,
+    };
+
+    // Tickets are single-use: burn it and hand the emptied account's rent back
+    let ticket_burned = prize_pool.burn_entry_ticket;
+    if ticket_burned {
+        let accounts = &ctx.accounts;
+        let (Some(token_account), Some(mint), Some(token_program)) =
+            (&accounts.nft_token_account, &accounts.nft_mint, &accounts.token_program)
+        else {
+            return err!(ErrorCode::TicketBurnAccountsRequired);
+        };
+        require_keys_eq!(mint.key(), token_account.mint, ErrorCode::MintMismatch);
+
+        let cpi_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: token_account.to_account_info(),
+                authority: accounts.player.to_account_info(),
+            },
+        );
+        token::burn(cpi_ctx, token_account.amount)?;
+
+        let cpi_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: token_account.to_account_info(),
+                destination: accounts.player.to_account_info(),
+                authority: accounts.player.to_account_info(),
+            },
+        );
+        token::close_account(cpi_ctx)?;
+    }
+
+    let entry_fee = prize_pool.entry_fee;
+    if entry_fee > 0 {
+        let accounts = &ctx.accounts;
+        let (Some(from), Some(vault), Some(token_program)) = (
+            &accounts.player_token_account,
+            &accounts.prize_pool_token_account,
+            &accounts.token_program,
+        ) else {
+            return err!(ErrorCode::EntryFeeAccountsRequired);
+        };
+        require_keys_eq!(vault.key(), prize_pool.vault, ErrorCode::VaultMismatch);
+
+        let cpi_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: vault.to_account_info(),
+                authority: accounts.player.to_account_info(),
+            },
+        );
+        token::transfer(cpi_ctx, entry_fee)?;
+    }
+
+    let bond = prize_pool.entry_bond;
+    if bond > 0 {
+        let accounts = &ctx.accounts;
+        let (Some(from), Some(bond_vault), Some(token_program)) =
+            (&accounts.player_token_account, &accounts.bond_vault, &accounts.token_program)
+        else {
+            return err!(ErrorCode::EntryBondAccountsRequired);
+        };
+
+        let cpi_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: bond_vault.to_account_info(),
+                authority: accounts.player.to_account_info(),
+            },
+        );
+        token::transfer(cpi_ctx, bond)?;
+    }
+
+    let registration = &mut ctx.accounts.registration;
+    registration.prize_pool = prize_pool.key();
+    registration.player = player;
+    registration.nft_mint = nft_mint;
+    registration.ticket_burned = ticket_burned;
+    registration.registered_at = Clock::get()?.unix_timestamp;
+    registration.bond = bond;
+    registration.bond_slashed = false;
+    registration.pending_reports = 0;
+    registration.bump = ctx.bumps.registration;
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.entry_fees_collected = prize_pool
+        .entry_fees_collected
+        .checked_add(entry_fee)
+        .ok_or(ErrorCode::Overflow)?;
+    prize_pool.bonds_held = prize_pool.bonds_held.checked_add(bond).ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(PlayerRegistered {
+        player,
+        nft_mint,
+        ticket_burned,
+        entry_fee,
+        bond,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Team, TeamMember};
+
+#[derive(Accounts)]
+pub struct RegisterTeam<'info> {
+    #[account(
+        init,
+        payer = captain,
+        space = 8 + Team::INIT_SPACE,
+        seeds = [b"team", captain.key().as_ref()],
+        bump
+    )]
+    pub team: Account<'info, Team>,
+    
+    #[account(mut)]
+    pub captain: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterTeam>, members: Vec<TeamMember>) -> Result<()> {
+    Team::validate_members(&members)?;
+
+    let team = &mut ctx.accounts.team;
+    team.captain = ctx.accounts.captain.key();
+    team.members = members;
+    team.bump = ctx.bumps.team;
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::CheaterReported;
+use crate::state::{CheatReport, PrizePool, Registration, ReportStatus};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct ReportCheater<'info> {
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// The reported player's registration, whose bond is at stake
+    #[account(
+        mut,
+        seeds = [b"registration", prize_pool.key().as_ref(), wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + CheatReport::INIT_SPACE,
+        seeds = [
+            b"cheat_report",
+            prize_pool.key().as_ref(),
+            wallet.as_ref(),
+            reporter.key().as_ref()
+        ],
+        bump
+    )]
+    pub cheat_report: Account<'info, CheatReport>,
+    
+    #[account(mut, seeds = [b"bond_vault", prize_pool.key().as_ref()], bump)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = reporter_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch,
+        constraint = reporter_token_account.owner == reporter.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub reporter_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReportCheater>, wallet: Pubkey, evidence_hash: [u8; 32]) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let bond = prize_pool.report_bond;
+    require!(bond > 0, ErrorCode::ReportsDisabled);
+    require_keys_neq!(wallet, ctx.accounts.reporter.key(), ErrorCode::InvalidReport);
+    // Nothing left to slash once the bond is refunded or already slashed
+    require!(ctx.accounts.registration.bond > 0, ErrorCode::NoBondHeld);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.reporter_token_account.to_account_info(),
+        to: ctx.accounts.bond_vault.to_account_info(),
+        authority: ctx.accounts.reporter.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), bond)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let report = &mut ctx.accounts.cheat_report;
+    report.prize_pool = prize_pool.key();
+    report.wallet = wallet;
+    report.reporter = ctx.accounts.reporter.key();
+    report.evidence_hash = evidence_hash;
+    report.bond = bond;
+    report.reported_at = now;
+    report.status = ReportStatus::Pending;
+    report.bounty = 0;
+    report.resolved_at = 0;
+    report.bump = ctx.bumps.cheat_report;
+
+    ctx.accounts.registration.pending_reports += 1;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.bonds_held = prize_pool.bonds_held.checked_add(bond).ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(CheaterReported {
+        prize_pool: prize_pool.key(),
+        wallet,
+        reporter: ctx.accounts.reporter.key(),
+        evidence_hash,
+        bond,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::CheatReportResolved;
+use crate::math::bps_of;
+use crate::state::{CheatReport, Config, PrizePool, Registration, ReportStatus};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveReport<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"cheat_report",
+            prize_pool.key().as_ref(),
+            cheat_report.wallet.as_ref(),
+            cheat_report.reporter.as_ref()
+        ],
+        bump = cheat_report.bump
+    )]
+    pub cheat_report: Account<'info, CheatReport>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", prize_pool.key().as_ref(), cheat_report.wallet.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(mut, seeds = [b"bond_vault", prize_pool.key().as_ref()], bump)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = reporter_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch,
+        constraint = reporter_token_account.owner == cheat_report.reporter @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub reporter_token_account: Account<'info, TokenAccount>,
+    
+    pub guardian: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/**
+ * Upholding slashes the reported player's entry bond: the reporter gets
+ * their report bond back plus report_bounty_bps of it, the rest joins the
+ * prizes. Only the first upheld report finds a bond left to slash.
+ * Rejecting forfeits the report bond to the prizes instead.
+ */
+pub fn handler(ctx: Context<ResolveReport>, upheld: bool) -> Result<()> {
+    require!(
+        ctx.accounts.cheat_report.status == ReportStatus::Pending,
+        ErrorCode::ReportAlreadyResolved
+    );
+    let report_bond = ctx.accounts.cheat_report.bond;
+    let (slashed, bounty) = if upheld {
+        let slashed = ctx.accounts.registration.bond;
+        (slashed, bps_of(slashed, ctx.accounts.prize_pool.report_bounty_bps))
+    } else {
+        (0, 0)
+    };
+    let (to_reporter, to_prizes) = if upheld {
+        (report_bond + bounty, slashed - bounty)
+    } else {
+        (0, report_bond)
+    };
+
+    let accounts = &ctx.accounts;
+    for (to, amount) in [
+        (&accounts.reporter_token_account, to_reporter),
+        (&accounts.prize_pool_token_account, to_prizes),
+    ] {
+        if amount > 0 {
+            pay_from_vault(
+                &accounts.prize_pool,
+                &accounts.bond_vault,
+                &to.to_account_info(),
+                &accounts.token_program,
+                amount,
+            )?;
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let report = &mut ctx.accounts.cheat_report;
+    report.status = if upheld { ReportStatus::Upheld } else { ReportStatus::Rejected };
+    report.bounty = bounty;
+    report.resolved_at = now;
+
+    let registration = &mut ctx.accounts.registration;
+    registration.pending_reports -= 1;
+    if slashed > 0 {
+        registration.bond = 0;
+        registration.bond_slashed = true;
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.bonds_held = prize_pool
+        .bonds_held
+        .checked_sub(report_bond + slashed)
+        .ok_or(ErrorCode::Overflow)?;
+    prize_pool.bonds_slashed = prize_pool
+        .bonds_slashed
+        .checked_add(to_prizes)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(CheatReportResolved {
+        prize_pool: prize_pool.key(),
+        wallet: report.wallet,
+        reporter: report.reporter,
+        upheld,
+        slashed,
+        bounty,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
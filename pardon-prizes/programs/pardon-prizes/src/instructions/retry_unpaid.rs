@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::UnpaidPrizeRetried;
+use crate::state::{DistributionRecord, MintStats, PayoutStatus, PrizePool, WeekStats};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RetryUnpaid<'info> {
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"distribution", prize_pool.key().as_ref(), &nonce.to_le_bytes()],
+        bump = distribution_record.bump
+    )]
+    pub distribution_record: Account<'info, DistributionRecord>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    /// Must belong to the unpaid leg's owner
+    #[account(
+        mut,
+        constraint = destination.mint == prize_pool.mint @ ErrorCode::MintMismatch
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    /// Opened by the distribute_prizes pass that left the prize unpaid
+    #[account(mut, seeds = [b"week_stats", prize_pool.key().as_ref()], bump = week_stats.bump)]
+    pub week_stats: Account<'info, WeekStats>,
+    
+    #[account(mut, seeds = [b"mint_stats", prize_pool.mint.as_ref()], bump = mint_stats.bump)]
+    pub mint_stats: Account<'info, MintStats>,
+}
+
+pub fn handler(ctx: Context<RetryUnpaid>, nonce: u64, index: u8) -> Result<()> {
+    let prize = ctx
+        .accounts
+        .distribution_record
+        .unpaid
+        .get(index as usize)
+        .cloned()
+        .ok_or(ErrorCode::UnpaidPrizeNotFound)?;
+    require!(!prize.paid, ErrorCode::UnpaidPrizeAlreadyPaid);
+    require_keys_eq!(
+        ctx.accounts.destination.owner,
+        prize.owner,
+        ErrorCode::WinnerAccountMismatch
+    );
+
+    let to = ctx.accounts.destination.to_account_info();
+    pay_from_vault(
+        &ctx.accounts.prize_pool,
+        &ctx.accounts.prize_pool_token_account,
+        &to,
+        &ctx.accounts.token_program,
+        prize.amount,
+    )?;
+
+    let record = &mut ctx.accounts.distribution_record;
+    record.unpaid[index as usize].paid = true;
+    record.total_paid += prize.amount;
+
+    // The entry only counts as paid once none of its legs are still owed
+    let settled = record.unpaid.iter().all(|other| other.winner != prize.winner || other.paid);
+    let entry = record.winners.iter().position(|winner| *winner == prize.winner);
+    if let Some(i) = entry {
+        record.statuses[i] = if settled {
+            PayoutStatus::Paid
+        } else {
+            PayoutStatus::Partial
+        };
+    }
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.unpaid_reserve -= prize.amount;
+    prize_pool.total_distributed += prize.amount;
+    ctx.accounts.week_stats.total_paid += prize.amount;
+    ctx.accounts.mint_stats.total_distributed += prize.amount;
+
+    emit_cpi!(UnpaidPrizeRetried {
+        winner: prize.winner,
+        owner: prize.owner,
+        amount: prize.amount,
+        distribution_nonce: nonce,
+        index,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
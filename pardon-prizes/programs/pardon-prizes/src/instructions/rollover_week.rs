@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use super::initialize_prize_pool::setup_pool;
+use crate::errors::ErrorCode;
+use crate::events::WeekStarted;
+use crate::math::{iso_week_id, week_index_at, week_start, WEEK_SECONDS};
+use crate::state::{Config, GlobalStats, MintStats, PrizePool, WEEKLY_POOL_SEED};
+
+/**
+ * Opens the pool of the week after `previous_pool`'s once that week has
+ * ended, with the previous pool's category, mint, authority and roles and the
+ * config's PoolDefaults split. An archived earlier pool of the same category
+ * and mint can be passed as rollover_source to carry its surplus over.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RolloverWeek<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(constraint = previous_pool.week_index.is_some() @ ErrorCode::NotNumberedPool)]
+    pub previous_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PrizePool::INIT_SPACE,
+        seeds = [
+            WEEKLY_POOL_SEED,
+            previous_pool.week_index.unwrap_or_default().wrapping_add(1).to_le_bytes().as_ref(),
+            previous_pool.category.as_bytes()
+        ],
+        bump
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = previous_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = prize_pool
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    /// Whoever cranks the rollover, paying the new pool's and vault's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Archived pool whose surplus moves into the new one
+    #[account(mut)]
+    pub rollover_source: Option<Box<Account<'info, PrizePool>>>,
+    
+    /// Required with rollover_source
+    #[account(mut)]
+    pub rollover_source_vault: Option<Account<'info, TokenAccount>>,
+    
+    #[account(mut, seeds = [b"global_stats"], bump = global_stats.bump)]
+    pub global_stats: Account<'info, GlobalStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintStats::INIT_SPACE,
+        seeds = [b"mint_stats", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_stats: Account<'info, MintStats>,
+}
+
+pub fn handler(ctx: Context<RolloverWeek>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let defaults = config.pool_defaults.clone().ok_or(ErrorCode::RolloverDisabled)?;
+    let epoch_start = config.week_epoch_start;
+    let week_index = ctx
+        .accounts
+        .previous_pool
+        .week_index
+        .and_then(|previous| previous.checked_add(1))
+        .ok_or(ErrorCode::NotNumberedPool)?;
+    let starts_at = week_start(epoch_start, week_index);
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= starts_at, ErrorCode::WeekNotOver);
+    // After a missed week the chain restarts with initialize_weekly_pool, not a stale pool
+    require!(
+        week_index_at(epoch_start, now) == Some(week_index),
+        ErrorCode::WeekNotOpen
+    );
+
+    let accounts = &mut *ctx.accounts;
+    let previous = &accounts.previous_pool;
+    setup_pool(
+        &mut accounts.prize_pool,
+        previous.authority,
+        &accounts.mint,
+        &accounts.vault,
+        iso_week_id(starts_at),
+        Some(week_index),
+        previous.category.clone(),
+        defaults.max_winners,
+        defaults.rank_shares_bps,
+        ctx.bumps.prize_pool,
+    )?;
+    accounts.global_stats.record_pool(&accounts.prize_pool.week_id);
+    accounts.mint_stats.open(accounts.mint.key(), ctx.bumps.mint_stats);
+    // Whoever ran last week, e.g. the game program as poster, runs this one too
+    accounts.prize_pool.roles = previous.roles;
+
+    let mut rolled_over = 0;
+    let rollover_source = accounts.rollover_source.as_ref().map(|source| source.key());
+    if let Some(source) = accounts.rollover_source.as_mut() {
+        let source_vault = accounts
+            .rollover_source_vault
+            .as_ref()
+            .ok_or(ErrorCode::VaultMismatch)?;
+        require_keys_eq!(source_vault.key(), source.vault, ErrorCode::VaultMismatch);
+        require!(
+            source.week_index.is_some_and(|index| index < week_index)
+                && source.category == accounts.prize_pool.category
+                && source.mint == accounts.prize_pool.mint,
+            ErrorCode::RolloverSourceMismatch
+        );
+        rolled_over = source
+            .rollover_surplus(source_vault.amount)
+            .ok_or(ErrorCode::RolloverSourceUnsettled)?;
+        if rolled_over > 0 {
+            let to = accounts.vault.to_account_info();
+            pay_from_vault(source, source_vault, &to, &accounts.token_program, rolled_over)?;
+            source.rolled_over += rolled_over;
+            accounts.prize_pool.total_funded += rolled_over;
+        }
+    }
+
+    let prize_pool = &ctx.accounts.prize_pool;
+    emit_cpi!(WeekStarted {
+        prize_pool: prize_pool.key(),
+        week_index,
+        starts_at,
+        ends_at: starts_at + WEEK_SECONDS,
+        rollover_source,
+        rolled_over,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::MAX_SCORE;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetAirdropMode<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAirdropMode>, threshold: Option<u8>) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // The leaderboard decides who qualifies and how many shares there are
+    require!(
+        prize_pool.leaderboard_root.is_none() && prize_pool.payouts_pending(),
+        ErrorCode::AirdropModeLocked
+    );
+    if let Some(threshold) = threshold {
+        require!(threshold <= MAX_SCORE, ErrorCode::InvalidAirdropThreshold);
+    }
+
+    prize_pool.airdrop_threshold = threshold;
+    Ok(())
+}
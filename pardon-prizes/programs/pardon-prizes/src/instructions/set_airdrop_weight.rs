@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::{PoolRole, PrizePool, DELEGATE_FINALIZE_LEADERBOARD};
+
+#[derive(Accounts)]
+pub struct SetAirdropWeight<'info> {
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Posting,
+            DELEGATE_FINALIZE_LEADERBOARD,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// The pool's poster or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAirdropWeight>, total_weight: u64) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Every share is taken of the total, so it can't move once one is paid
+    require!(
+        prize_pool.airdrop_threshold.is_some()
+            && !prize_pool.qualification_tiers.is_empty()
+            && prize_pool.leaderboard_root.is_some()
+            && prize_pool.payouts_pending(),
+        ErrorCode::AirdropWeightLocked
+    );
+
+    let entries = prize_pool.leaderboard_entries as u64;
+    let top_multiplier = prize_pool
+        .qualification_tiers
+        .iter()
+        .map(|tier| tier.multiplier_bps)
+        .max()
+        .unwrap_or_default();
+    require!(
+        (entries * BPS_DENOMINATOR as u64..=entries * top_multiplier as u64)
+            .contains(&total_weight),
+        ErrorCode::InvalidAirdropWeight
+    );
+
+    prize_pool.airdrop_weight = total_weight;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetAutoClose<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAutoClose>, auto_close_after_secs: i64) -> Result<()> {
+    require!(auto_close_after_secs >= 0, ErrorCode::InvalidAutoClose);
+    ctx.accounts.config.auto_close_after_secs = auto_close_after_secs;
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::{BPS_DENOMINATOR, MAX_BONUS_MULTIPLIER_BPS};
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetBonusMultiplier<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetBonusMultiplier>,
+    bonus_multiplier_bps: u16,
+    sponsor: Pubkey,
+) -> Result<()> {
+    require!(
+        bonus_multiplier_bps == 0
+            || (BPS_DENOMINATOR..=MAX_BONUS_MULTIPLIER_BPS).contains(&bonus_multiplier_bps),
+        ErrorCode::InvalidBonusMultiplier
+    );
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // The sponsor funded a specific deal; it can't be swapped out from under them
+    require!(
+        prize_pool.bonus_reserve == 0 && prize_pool.payouts_pending(),
+        ErrorCode::BonusLocked
+    );
+
+    prize_pool.bonus_multiplier_bps = bonus_multiplier_bps;
+    prize_pool.bonus_sponsor = sponsor;
+    Ok(())
+}
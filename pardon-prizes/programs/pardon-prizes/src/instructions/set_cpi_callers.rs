@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, MAX_CPI_CALLERS};
+
+#[derive(Accounts)]
+pub struct SetCpiCallers<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetCpiCallers>, cpi_callers: Vec<Pubkey>) -> Result<()> {
+    require!(
+        cpi_callers.len() <= MAX_CPI_CALLERS,
+        ErrorCode::InvalidCpiCallers
+    );
+    for (i, program) in cpi_callers.iter().enumerate() {
+        require!(
+            *program != crate::ID && !cpi_callers[..i].contains(program),
+            ErrorCode::InvalidCpiCallers
+        );
+    }
+
+    ctx.accounts.config.cpi_callers = cpi_callers;
+    Ok(())
+}
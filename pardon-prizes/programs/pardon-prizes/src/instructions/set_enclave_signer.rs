@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, EnclaveSigner};
+
+#[derive(Accounts)]
+pub struct SetEnclaveSigner<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetEnclaveSigner>,
+    enclave_signer: Option<EnclaveSigner>,
+) -> Result<()> {
+    if let Some(enclave_signer) = &enclave_signer {
+        require!(
+            enclave_signer.key != Pubkey::default()
+                && enclave_signer.measurement != [0; 48]
+                && enclave_signer.report_hash != [0; 32],
+            ErrorCode::InvalidEnclaveSigner
+        );
+    }
+    ctx.accounts.config.enclave_signer = enclave_signer;
+    Ok(())
+}
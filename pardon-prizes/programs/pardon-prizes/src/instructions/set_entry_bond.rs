@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetEntryBond<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// Holds posted bonds apart from the prize vault, so they never count as prizes
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"bond_vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = prize_pool
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = prize_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetEntryBond>, entry_bond: u64) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Players already posted under the current terms
+    require!(
+        prize_pool.bonds_held == 0 && prize_pool.bonds_slashed == 0 && !prize_pool.entries_closed,
+        ErrorCode::EntryBondLocked
+    );
+
+    prize_pool.entry_bond = entry_bond;
+    Ok(())
+}
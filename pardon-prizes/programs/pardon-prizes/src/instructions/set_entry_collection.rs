@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetEntryCollection<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetEntryCollection>,
+    collection: Option<Pubkey>,
+    burn_ticket: bool,
+) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.entry_collection = collection;
+    prize_pool.burn_entry_ticket = collection.is_some() && burn_ticket;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetEntryFee<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetEntryFee>, entry_fee: u64, rake_bps: u16) -> Result<()> {
+    require!(rake_bps <= BPS_DENOMINATOR, ErrorCode::InvalidRake);
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Players already paid under the current terms
+    require!(
+        prize_pool.entry_fees_collected == 0 && !prize_pool.entries_closed,
+        ErrorCode::EntryFeeLocked
+    );
+
+    prize_pool.entry_fee = entry_fee;
+    prize_pool.rake_bps = rake_bps;
+    Ok(())
+}
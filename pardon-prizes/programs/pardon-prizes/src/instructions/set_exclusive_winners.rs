@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetExclusiveWinners<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetExclusiveWinners>, exclusive: bool) -> Result<()> {
+    ctx.accounts.prize_pool.exclusive_winners = exclusive;
+    Ok(())
+}
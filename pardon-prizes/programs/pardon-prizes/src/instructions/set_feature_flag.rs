@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::FeatureFlagSet;
+use crate::state::{Config, ProgramState, KNOWN_FEATURES};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeatureFlag<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut, seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetFeatureFlag>, feature: u64, enabled: bool) -> Result<()> {
+    require!(
+        feature.count_ones() == 1 && feature & KNOWN_FEATURES == feature,
+        ErrorCode::UnknownFeature
+    );
+
+    let program_state = &mut ctx.accounts.program_state;
+    if enabled {
+        program_state.features |= feature;
+    } else {
+        program_state.features &= !feature;
+    }
+    program_state.version = ProgramState::CURRENT_VERSION.to_string();
+
+    emit_cpi!(FeatureFlagSet {
+        feature,
+        enabled,
+        features: program_state.features,
+        version: program_state.version.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{FunderRecord, PoolRole, PrizePool, DELEGATE_SET_FUNDER_ALLOWED};
+
+#[derive(Accounts)]
+#[instruction(funder: Pubkey)]
+pub struct SetFunderAllowed<'info> {
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Funding,
+            DELEGATE_SET_FUNDER_ALLOWED,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FunderRecord::INIT_SPACE,
+        seeds = [b"funder", prize_pool.key().as_ref(), funder.as_ref()],
+        bump
+    )]
+    pub funder_record: Account<'info, FunderRecord>,
+    
+    /// The pool's funder or its delegate, see PoolRoles
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetFunderAllowed>, funder: Pubkey, allowed: bool) -> Result<()> {
+    let record = &mut ctx.accounts.funder_record;
+    record.prize_pool = ctx.accounts.prize_pool.key();
+    record.funder = funder;
+    record.whitelisted = allowed;
+    record.bump = ctx.bumps.funder_record;
+    Ok(())
+}
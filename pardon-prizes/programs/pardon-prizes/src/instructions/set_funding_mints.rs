@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, MAX_FUNDING_MINTS};
+
+#[derive(Accounts)]
+pub struct SetFundingMints<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetFundingMints>, funding_mints: Vec<Pubkey>) -> Result<()> {
+    require!(
+        funding_mints.len() <= MAX_FUNDING_MINTS,
+        ErrorCode::InvalidFundingMints
+    );
+    for (i, mint) in funding_mints.iter().enumerate() {
+        require!(
+            !funding_mints[..i].contains(mint),
+            ErrorCode::InvalidFundingMints
+        );
+    }
+
+    ctx.accounts.config.funding_mints = funding_mints;
+    Ok(())
+}
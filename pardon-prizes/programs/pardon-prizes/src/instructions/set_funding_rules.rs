@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PoolRole, PrizePool, DELEGATE_SET_FUNDING_RULES};
+
+#[derive(Accounts)]
+pub struct SetFundingRules<'info> {
+    #[account(
+        mut,
+        constraint = prize_pool.authorizes(
+            PoolRole::Funding,
+            DELEGATE_SET_FUNDING_RULES,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    /// The pool's funder or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetFundingRules>, funder_cap: u64, whitelist_only: bool) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.funder_cap = funder_cap;
+    prize_pool.whitelist_only = whitelist_only;
+    Ok(())
+}
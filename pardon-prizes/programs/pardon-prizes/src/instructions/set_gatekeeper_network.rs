@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetGatekeeperNetwork<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGatekeeperNetwork>, network: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.prize_pool.gatekeeper_network = network;
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{HappyHour, PrizePool};
+
+#[derive(Accounts)]
+pub struct SetHappyHours<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetHappyHours>, happy_hours: Vec<HappyHour>) -> Result<()> {
+    PrizePool::validate_happy_hours(&happy_hours)?;
+    ctx.accounts.prize_pool.happy_hours = happy_hours;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetHolderRequirement<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetHolderRequirement>,
+    min_holding: u64,
+    snapshot_start_slot: u64,
+    snapshot_end_slot: u64,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Moving the window or the bar after snapshots opened would favour some holders
+    require!(
+        prize_pool.min_holding == 0 || slot < prize_pool.snapshot_start_slot,
+        ErrorCode::SnapshotWindowOpen
+    );
+    if min_holding > 0 {
+        require!(
+            slot <= snapshot_start_slot && snapshot_start_slot < snapshot_end_slot,
+            ErrorCode::InvalidSnapshotWindow
+        );
+    }
+
+    prize_pool.min_holding = min_holding;
+    prize_pool.snapshot_start_slot = snapshot_start_slot;
+    prize_pool.snapshot_end_slot = snapshot_end_slot;
+    Ok(())
+}
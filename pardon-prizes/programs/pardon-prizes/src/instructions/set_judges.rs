@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PrizePool, MAX_JUDGES, MIN_JUDGE_QUORUM};
+
+#[derive(Accounts)]
+pub struct SetJudges<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetJudges>,
+    judges: Vec<Pubkey>,
+    quorum: u8,
+    judging_ends_at: i64,
+) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    require!(
+        prize_pool.leaderboard_root.is_none() && prize_pool.payouts_pending(),
+        ErrorCode::JudgesLocked
+    );
+    require!(judges.len() <= MAX_JUDGES, ErrorCode::InvalidJudges);
+    require!(
+        judges
+            .iter()
+            .enumerate()
+            .all(|(i, judge)| !judges[..i].contains(judge)),
+        ErrorCode::InvalidJudges
+    );
+    let now = Clock::get()?.unix_timestamp;
+    let panel_valid = if judges.is_empty() {
+        quorum == 0 && judging_ends_at == 0
+    } else {
+        (MIN_JUDGE_QUORUM as usize..=judges.len()).contains(&(quorum as usize))
+            && judging_ends_at > now
+    };
+    require!(panel_valid, ErrorCode::InvalidJudges);
+
+    prize_pool.judges = judges;
+    prize_pool.judge_quorum = quorum;
+    prize_pool.judging_ends_at = judging_ends_at;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PlayerProfile;
+
+#[derive(Accounts)]
+pub struct SetPayoutWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPayoutWallet>, payout_wallet: Option<Pubkey>) -> Result<()> {
+    let profile = &mut ctx.accounts.player_profile;
+    profile.payout_wallet = payout_wallet;
+    // A direct change also voids any signed authorization still in flight
+    profile.payout_nonce += 1;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::MAX_RANK;
+use crate::state::{Config, PoolDefaults, PrizePool};
+
+#[derive(Accounts)]
+pub struct SetPoolDefaults<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetPoolDefaults>,
+    max_winners: u8,
+    rank_shares_bps: Vec<u16>,
+) -> Result<()> {
+    // Zero winners turns rollover off
+    if max_winners == 0 {
+        ctx.accounts.config.pool_defaults = None;
+        return Ok(());
+    }
+    require!(max_winners <= MAX_RANK, ErrorCode::InvalidMaxWinners);
+    PrizePool::validate_rank_shares(&rank_shares_bps, max_winners)?;
+
+    ctx.accounts.config.pool_defaults = Some(PoolDefaults { max_winners, rank_shares_bps });
+    Ok(())
+}
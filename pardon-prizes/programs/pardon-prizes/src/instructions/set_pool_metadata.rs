@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::PoolMetadataUpdated;
+use crate::state::{PrizePool, MAX_METADATA_URI_LEN, MAX_POOL_NAME_LEN};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPoolMetadata<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPoolMetadata>, name: String, metadata_uri: String) -> Result<()> {
+    require!(name.len() <= MAX_POOL_NAME_LEN, ErrorCode::PoolNameTooLong);
+    require!(
+        metadata_uri.len() <= MAX_METADATA_URI_LEN,
+        ErrorCode::MetadataUriTooLong
+    );
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.name = name;
+    prize_pool.metadata_uri = metadata_uri;
+
+    emit_cpi!(PoolMetadataUpdated {
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+        name: prize_pool.name.clone(),
+        metadata_uri: prize_pool.metadata_uri.clone(),
+    });
+
+    Ok(())
+}
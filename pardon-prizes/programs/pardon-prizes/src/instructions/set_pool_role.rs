@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::PoolRoleChanged;
+use crate::state::{AuthorityChange, PoolRole, PrizePool};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPoolRole<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPoolRole>, role: PoolRole, key: Pubkey) -> Result<()> {
+    let changed_at = Clock::get()?.unix_timestamp;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    let previous = prize_pool.roles.key(role);
+    prize_pool.roles.set(role, key);
+    if previous != key {
+        AuthorityChange::record(
+            &mut prize_pool.authority_history,
+            AuthorityChange {
+                kind: role.into(),
+                previous,
+                key,
+                changed_at,
+            },
+        );
+    }
+
+    emit_cpi!(PoolRoleChanged {
+        role,
+        previous,
+        key,
+        changed_at,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
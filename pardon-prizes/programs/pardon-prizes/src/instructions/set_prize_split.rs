@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::PlayerProfile;
+
+#[derive(Accounts)]
+pub struct SetPrizeSplit<'info> {
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    pub player: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetPrizeSplit>,
+    recipient: Option<Pubkey>,
+    split_bps: u16,
+) -> Result<()> {
+    require!(split_bps <= BPS_DENOMINATOR, ErrorCode::InvalidSplitBps);
+
+    let profile = &mut ctx.accounts.player_profile;
+    profile.split_recipient = recipient;
+    profile.split_bps = if recipient.is_some() { split_bps } else { 0 };
+    Ok(())
+}
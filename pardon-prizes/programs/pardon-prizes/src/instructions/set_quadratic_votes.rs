@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetQuadraticVotes<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetQuadraticVotes>, enabled: bool) -> Result<()> {
+    ctx.accounts.config.quadratic_votes = enabled;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PrizePool, QualificationTier};
+
+#[derive(Accounts)]
+pub struct SetQualificationTiers<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetQualificationTiers>, tiers: Vec<QualificationTier>) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // The poster weighs the leaderboard under these tiers
+    require!(
+        prize_pool.leaderboard_root.is_none() && prize_pool.payouts_pending(),
+        ErrorCode::AirdropModeLocked
+    );
+    PrizePool::validate_qualification_tiers(&tiers)?;
+
+    prize_pool.qualification_tiers = tiers;
+    Ok(())
+}
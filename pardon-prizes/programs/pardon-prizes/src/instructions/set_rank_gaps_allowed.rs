@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetRankGapsAllowed<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetRankGapsAllowed>, allowed: bool) -> Result<()> {
+    ctx.accounts.prize_pool.allow_rank_gaps = allowed;
+    Ok(())
+}
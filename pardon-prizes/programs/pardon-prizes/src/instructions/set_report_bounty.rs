@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::PrizePool;
+
+#[derive(Accounts)]
+pub struct SetReportBounty<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetReportBounty>, report_bond: u64, bounty_bps: u16) -> Result<()> {
+    require!(bounty_bps <= BPS_DENOMINATOR, ErrorCode::InvalidReportBounty);
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Reports are held in the bond vault and paid from the entry bonds
+    require!(report_bond == 0 || prize_pool.entry_bond > 0, ErrorCode::EntryBondRequired);
+
+    prize_pool.report_bond = report_bond;
+    prize_pool.report_bounty_bps = bounty_bps;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PrizePool, ScoreCurve};
+
+#[derive(Accounts)]
+pub struct SetScoreNormalization<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetScoreNormalization>,
+    raw_score_max: u16,
+    score_curve: ScoreCurve,
+) -> Result<()> {
+    require!(raw_score_max > 0, ErrorCode::InvalidScoreScale);
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.raw_score_max = raw_score_max;
+    prize_pool.score_curve = score_curve;
+    Ok(())
+}
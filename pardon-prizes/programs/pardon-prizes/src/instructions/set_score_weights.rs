@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetScoreWeights<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetScoreWeights>, judge_weight: u16, vote_weight: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.judge_weight = judge_weight;
+    config.vote_weight = vote_weight;
+    Ok(())
+}
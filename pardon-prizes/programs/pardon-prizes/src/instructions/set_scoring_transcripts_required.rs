@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetScoringTranscriptsRequired<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetScoringTranscriptsRequired>, required: bool) -> Result<()> {
+    ctx.accounts.config.require_scoring_transcripts = required;
+    Ok(())
+}
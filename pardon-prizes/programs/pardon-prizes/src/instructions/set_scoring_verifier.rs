@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, ScoringVerifier, ScoringVerifyingKey};
+
+#[derive(Accounts)]
+pub struct SetScoringVerifier<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ScoringVerifier::INIT_SPACE,
+        seeds = [b"scoring_verifier"],
+        bump
+    )]
+    pub scoring_verifier: Box<Account<'info, ScoringVerifier>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetScoringVerifier>, key: ScoringVerifyingKey) -> Result<()> {
+    let scoring_verifier = &mut ctx.accounts.scoring_verifier;
+    scoring_verifier.key = key;
+    scoring_verifier.bump = ctx.bumps.scoring_verifier;
+    Ok(())
+}
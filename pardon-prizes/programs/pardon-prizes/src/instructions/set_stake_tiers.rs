@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::state::{Config, StakeTier};
+
+#[derive(Accounts)]
+pub struct SetStakeTiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub stake_mint: Account<'info, Mint>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetStakeTiers>, stake_tiers: Vec<StakeTier>) -> Result<()> {
+    Config::validate_stake_tiers(&stake_tiers)?;
+
+    let config = &mut ctx.accounts.config;
+    let stake_mint = ctx.accounts.stake_mint.key();
+    // Existing stakes must be withdrawable from the vault they went into
+    require!(
+        config.total_staked == 0 || config.stake_mint == stake_mint,
+        ErrorCode::StakeMintLocked
+    );
+    config.stake_mint = stake_mint;
+    config.stake_tiers = stake_tiers;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetStakerShare<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    /// Holds rewards until stakers claim them
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"staker_rewards"],
+        bump,
+        token::mint = stake_mint,
+        token::authority = config
+    )]
+    pub staker_rewards_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = config.stake_mint @ ErrorCode::StakingDisabled)]
+    pub stake_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetStakerShare>, staker_share_bps: u16) -> Result<()> {
+    require!(
+        staker_share_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidStakerShare
+    );
+    ctx.accounts.config.staker_share_bps = staker_share_bps;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigKeyChanged;
+use crate::state::{AuthorityChange, AuthorityKind, Config};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous = config.treasury;
+    if previous == treasury {
+        return Ok(());
+    }
+    config.treasury = treasury;
+
+    let change = AuthorityChange {
+        kind: AuthorityKind::Treasury,
+        previous,
+        key: treasury,
+        changed_at: Clock::get()?.unix_timestamp,
+    };
+    AuthorityChange::record(&mut config.authority_history, change);
+
+    emit_cpi!(ConfigKeyChanged {
+        kind: change.kind,
+        previous,
+        key: treasury,
+        changed_at: change.changed_at,
+    });
+
+    Ok(())
+}
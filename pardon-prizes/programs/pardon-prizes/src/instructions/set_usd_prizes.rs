@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::ErrorCode;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::{PrizePool, UsdPrizes};
+
+#[derive(Accounts)]
+pub struct SetUsdPrizes<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = prize_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetUsdPrizes>,
+    usd_cents: u64,
+    feed_id: [u8; 32],
+    max_price_age_secs: u32,
+    max_conf_bps: u16,
+) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    // Winners already paid were priced under the current terms
+    require!(prize_pool.payouts_pending(), ErrorCode::UsdPrizesLocked);
+
+    // Zero USD goes back to paying out whatever the vault holds
+    if usd_cents == 0 {
+        prize_pool.usd_prizes = None;
+        return Ok(());
+    }
+    require!(
+        max_price_age_secs > 0 && (1..=BPS_DENOMINATOR).contains(&max_conf_bps),
+        ErrorCode::InvalidUsdPrizes
+    );
+
+    prize_pool.usd_prizes = Some(UsdPrizes {
+        feed_id,
+        usd_cents,
+        mint_decimals: ctx.accounts.mint.decimals,
+        max_price_age_secs,
+        max_conf_bps,
+    });
+    Ok(())
+}
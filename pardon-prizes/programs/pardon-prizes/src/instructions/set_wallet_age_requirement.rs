@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{PrizePool, WalletAgeRequirement};
+
+#[derive(Accounts)]
+pub struct SetWalletAgeRequirement<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetWalletAgeRequirement>,
+    requirement: Option<WalletAgeRequirement>,
+) -> Result<()> {
+    if let Some(requirement) = &requirement {
+        require!(
+            requirement.registrar != Pubkey::default() && requirement.min_age_days > 0,
+            ErrorCode::InvalidWalletAge
+        );
+    }
+    ctx.accounts.prize_pool.wallet_age = requirement;
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetWeekSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetWeekSchedule>, week_epoch_start: i64) -> Result<()> {
+    require!(week_epoch_start > 0, ErrorCode::InvalidWeekSchedule);
+    // Moving the epoch would renumber every week, and with it the address of every numbered pool
+    let config = &mut ctx.accounts.config;
+    require!(config.week_epoch_start == 0, ErrorCode::WeekScheduleLocked);
+    config.week_epoch_start = week_epoch_start;
+    Ok(())
+}
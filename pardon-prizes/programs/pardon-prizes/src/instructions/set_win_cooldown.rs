@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::math::MAX_RANK;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetWinCooldown<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetWinCooldown>, cooldown_rank: u8) -> Result<()> {
+    require!(cooldown_rank <= MAX_RANK, ErrorCode::InvalidCooldownRank);
+    ctx.accounts.config.cooldown_rank = cooldown_rank;
+    Ok(())
+}
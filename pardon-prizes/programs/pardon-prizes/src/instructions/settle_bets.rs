@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::BetsSettled;
+use crate::math::bps_of;
+use crate::merkle::{leaderboard_leaf, verify_proof};
+use crate::state::{BetMarket, BetOutcome, Config, PrizePool};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(winner: Pubkey)]
+pub struct SettleBets<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"bet_market", prize_pool.key().as_ref()],
+        bump = bet_market.bump
+    )]
+    pub bet_market: Account<'info, BetMarket>,
+    
+    #[account(mut, address = bet_market.vault @ ErrorCode::VaultMismatch)]
+    pub bet_vault: Account<'info, TokenAccount>,
+    
+    /// CHECK: the winner's BetOutcome PDA, uninitialized when nobody backed them
+    #[account(seeds = [b"bet_outcome", bet_market.key().as_ref(), winner.as_ref()], bump)]
+    pub winner_outcome: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    /// Required when there is a rake to pay
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury @ ErrorCode::TreasuryAccountMismatch,
+        constraint = treasury_token_account.mint == bet_vault.mint @ ErrorCode::MintMismatch
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+impl<'info> SettleBets<'info> {
+    /// Transfer out of the bet vault, signed by the market PDA
+    pub(crate) fn pay(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        pay_from_bet_vault(
+            &self.bet_market,
+            &self.bet_vault,
+            to,
+            &self.token_program,
+            amount,
+        )
+    }
+}
+
+/// Transfer out of a market's bet vault, signed by the market PDA
+pub(crate) fn pay_from_bet_vault<'info>(
+    bet_market: &Account<'info, BetMarket>,
+    bet_vault: &Account<'info, TokenAccount>,
+    to: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"bet_market".as_ref(),
+        bet_market.prize_pool.as_ref(),
+        &[bet_market.bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: bet_vault.to_account_info(),
+        to: to.clone(),
+        authority: bet_market.to_account_info(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)
+}
+
+pub fn handler(
+    ctx: Context<SettleBets>,
+    winner: Pubkey,
+    score: u8,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let market = &ctx.accounts.bet_market;
+    require!(market.winner.is_none(), ErrorCode::BetsAlreadySettled);
+    require!(
+        !ctx.accounts.prize_pool.is_cancelled(),
+        ErrorCode::WeekCancelled
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= market.closes_at, ErrorCode::BettingStillOpen);
+    // Bettors may already be taking their stakes back
+    require!(now < market.settle_deadline, ErrorCode::SettleDeadlinePassed);
+
+    // The winner is whoever the published leaderboard puts at rank 1
+    let root = ctx
+        .accounts
+        .prize_pool
+        .leaderboard_root
+        .ok_or(ErrorCode::LeaderboardNotFinalized)?;
+    require!(
+        verify_proof(&proof, &root, leaderboard_leaf(&winner, 1, score)),
+        ErrorCode::InvalidMerkleProof
+    );
+
+    let outcome = &ctx.accounts.winner_outcome;
+    let winning_stake = if outcome.data_is_empty() {
+        0
+    } else {
+        BetOutcome::try_deserialize(&mut &outcome.try_borrow_data()?[..])?.total_staked
+    };
+
+    // Nobody backed the winner: no rake, every bet is refunded in full
+    let total_staked = market.total_staked;
+    let rake = if winning_stake == 0 {
+        0
+    } else {
+        bps_of(total_staked - winning_stake, market.rake_bps)
+    };
+    if rake > 0 {
+        let treasury = ctx
+            .accounts
+            .treasury_token_account
+            .as_ref()
+            .ok_or(ErrorCode::TreasuryAccountRequired)?;
+        let to = treasury.to_account_info();
+        ctx.accounts.pay(&to, rake)?;
+    }
+
+    let market = &mut ctx.accounts.bet_market;
+    market.winner = Some(winner);
+    market.winning_stake = winning_stake;
+    market.payout_pool = total_staked - rake;
+
+    emit_cpi!(BetsSettled {
+        prize_pool: market.prize_pool,
+        winner,
+        total_staked,
+        winning_stake,
+        rake,
+        payout_pool: market.payout_pool,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::EntryBondSlashed;
+use crate::state::{Config, PrizePool, Registration};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SlashEntryBond<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"registration", prize_pool.key().as_ref(), registration.player.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Account<'info, Registration>,
+    
+    #[account(mut, seeds = [b"bond_vault", prize_pool.key().as_ref()], bump)]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    pub guardian: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SlashEntryBond>) -> Result<()> {
+    let amount = ctx.accounts.registration.bond;
+    require!(amount > 0, ErrorCode::NoBondHeld);
+
+    // The bond joins the prizes the cheater was competing for
+    let to = ctx.accounts.prize_pool_token_account.to_account_info();
+    pay_from_vault(
+        &ctx.accounts.prize_pool,
+        &ctx.accounts.bond_vault,
+        &to,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let registration = &mut ctx.accounts.registration;
+    registration.bond = 0;
+    registration.bond_slashed = true;
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.bonds_held = prize_pool.bonds_held.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+    prize_pool.bonds_slashed = prize_pool
+        .bonds_slashed
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(EntryBondSlashed {
+        prize_pool: prize_pool.key(),
+        player: registration.player,
+        guardian: ctx.accounts.guardian.key(),
+        amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
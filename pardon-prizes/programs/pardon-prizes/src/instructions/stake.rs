@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::Staked;
+use crate::state::{Config, StakeAccount, STAKE_LOCK_SECONDS};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = 8 + StakeAccount::INIT_SPACE,
+        seeds = [b"stake", wallet.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        seeds = [b"stake_vault"],
+        bump,
+        token::mint = stake_mint,
+        token::authority = config
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = config.stake_mint @ ErrorCode::StakingDisabled)]
+    pub stake_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = wallet_token_account.mint == stake_mint.key() @ ErrorCode::MintMismatch,
+        constraint = wallet_token_account.owner == wallet.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub wallet_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.wallet_token_account.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.wallet.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    let reward_per_share = ctx.accounts.config.reward_per_share;
+    let stake_account = &mut ctx.accounts.stake_account;
+    if stake_account.wallet == Pubkey::default() {
+        stake_account.wallet = ctx.accounts.wallet.key();
+        stake_account.bump = ctx.bumps.stake_account;
+    }
+    stake_account.settle(reward_per_share);
+    // Every deposit restarts the lock, so a stake can't be topped up for one payout
+    stake_account.amount += amount;
+    stake_account.reset_reward_debt(reward_per_share);
+    stake_account.locked_until = Clock::get()?.unix_timestamp + STAKE_LOCK_SECONDS;
+    ctx.accounts.config.total_staked += amount;
+
+    emit_cpi!(Staked {
+        wallet: stake_account.wallet,
+        amount,
+        staked: stake_account.amount,
+        locked_until: stake_account.locked_until,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::JudgeScoreSubmitted;
+use crate::math::happy_hour_multiplier_bps;
+use crate::state::{JudgeScore, JudgeScores, PrizePool};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct SubmitJudgeScore<'info> {
+    #[account(constraint = prize_pool.judges.contains(&judge.key()) @ ErrorCode::Unauthorized)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init_if_needed,
+        payer = judge,
+        space = 8 + JudgeScores::INIT_SPACE,
+        seeds = [b"judge_scores", prize_pool.key().as_ref(), player.as_ref()],
+        bump
+    )]
+    pub judge_scores: Account<'info, JudgeScores>,
+    
+    /// One of the pool's judges
+    #[account(mut)]
+    pub judge: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitJudgeScore>, player: Pubkey, raw_score: u16) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < prize_pool.judging_ends_at, ErrorCode::JudgingClosed);
+    require!(
+        raw_score <= prize_pool.raw_score_max,
+        ErrorCode::ScoreOutOfRange
+    );
+
+    let judge = ctx.accounts.judge.key();
+    let judge_scores = &mut ctx.accounts.judge_scores;
+    if judge_scores.prize_pool == Pubkey::default() {
+        // The boost follows the first judge's clock, like submit_score's
+        judge_scores.prize_pool = prize_pool.key();
+        judge_scores.player = player;
+        judge_scores.multiplier_bps = happy_hour_multiplier_bps(now, &prize_pool.happy_hours);
+        judge_scores.bump = ctx.bumps.judge_scores;
+    }
+    require!(!judge_scores.aggregated, ErrorCode::ScoresAggregated);
+    judge_scores
+        .scores
+        .retain(|score| prize_pool.judges.contains(&score.judge));
+    require!(
+        judge_scores.scores.iter().all(|score| score.judge != judge),
+        ErrorCode::JudgeAlreadyScored
+    );
+    judge_scores.scores.push(JudgeScore { judge, raw_score });
+
+    emit_cpi!(JudgeScoreSubmitted {
+        judge,
+        player,
+        raw_score,
+        submissions: judge_scores.scores.len() as u8,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
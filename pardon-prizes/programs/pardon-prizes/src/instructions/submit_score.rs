@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::caller::require_allowed_caller;
+use crate::errors::ErrorCode;
+use crate::events::ScoreSubmitted;
+use crate::math::{boosted_score, happy_hour_multiplier_bps, normalize_score};
+use crate::state::{Config, Entry, PoolRole, PrizePool, Registration, DELEGATE_SUBMIT_SCORE};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct SubmitScore<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Posting,
+            DELEGATE_SUBMIT_SCORE,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Entry::INIT_SPACE,
+        seeds = [b"entry", prize_pool.key().as_ref(), player.as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, Entry>,
+    
+    /// The pool's poster or its delegate, see PoolRoles
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: the instructions sysvar, naming the program that CPI'd into us, if any
+    #[account(address = instructions_sysvar::ID @ ErrorCode::InvalidInstructionsSysvar)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Required on collection- and gatekeeper-gated pools
+    #[account(
+        seeds = [b"registration", prize_pool.key().as_ref(), player.as_ref()],
+        bump = registration.bump
+    )]
+    pub registration: Option<Account<'info, Registration>>,
+}
+
+pub fn handler(ctx: Context<SubmitScore>, player: Pubkey, raw_score: u16) -> Result<()> {
+    require_allowed_caller(&ctx.accounts.config, &ctx.accounts.instructions)?;
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(prize_pool.judges.is_empty(), ErrorCode::JudgeQuorumPool);
+    // Boosts follow the clock at submission, so a score can't be backdated into a window
+    let now = Clock::get()?.unix_timestamp;
+    let multiplier_bps = happy_hour_multiplier_bps(now, &prize_pool.happy_hours);
+
+    let event = record_score(
+        prize_pool,
+        &mut ctx.accounts.entry,
+        player,
+        raw_score,
+        multiplier_bps,
+        ctx.accounts.registration.is_some(),
+    )?;
+    ctx.accounts.entry.bump = ctx.bumps.entry;
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/**
+ * Write `raw_score` to `player`'s entry, normalized with the pool's curve and
+ * boosted by `multiplier_bps`; `registered` is whether the player's
+ * Registration was passed
+ */
+pub(crate) fn record_score(
+    prize_pool: &Account<PrizePool>,
+    entry: &mut Entry,
+    player: Pubkey,
+    raw_score: u16,
+    multiplier_bps: u16,
+    registered: bool,
+) -> Result<ScoreSubmitted> {
+    require!(
+        raw_score <= prize_pool.raw_score_max,
+        ErrorCode::ScoreOutOfRange
+    );
+    require!(
+        (prize_pool.entry_collection.is_none()
+            && prize_pool.gatekeeper_network.is_none()
+            && prize_pool.wallet_age.is_none())
+            || registered,
+        ErrorCode::RegistrationRequired
+    );
+    let score = normalize_score(raw_score, prize_pool.raw_score_max, prize_pool.score_curve);
+    let score = boosted_score(score, multiplier_bps);
+
+    require!(!entry.finalized, ErrorCode::EntryFinalized);
+    entry.prize_pool = prize_pool.key();
+    entry.player = player;
+    entry.raw_score = raw_score;
+    entry.score = score;
+    entry.multiplier_bps = multiplier_bps;
+
+    Ok(ScoreSubmitted {
+        player,
+        raw_score,
+        score,
+        multiplier_bps,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    })
+}
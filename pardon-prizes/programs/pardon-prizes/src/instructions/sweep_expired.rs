@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::archive_week::close_claim_record;
+use super::claim_prize::fix_claim_basis;
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::{ExpiredPrizesSwept, PrizeExpired, StaleReceiptsClosed};
+use crate::math::{calculate_prize_with, tier_multiplier_bps, weighted_share};
+use crate::merkle::{leaderboard_leaf, verify_proof};
+use crate::state::{ClaimRecord, Config, PrizePool, SweptLeaf, WeekArchive};
+
+/**
+ * Remaining accounts are a (["claim", prize_pool, wallet] receipt, wallet)
+ * pair per leaf, in order. A claimed leaf's receipt is folded into the
+ * WeekArchive and closed; an empty one marks the leaf's prize as expired. The
+ * first call sweeps the vault; later calls only go through further leaves.
+ */
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepExpired<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(mut, address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury @ ErrorCode::TreasuryAccountMismatch,
+        constraint = treasury_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Takes the creator royalty when nobody claimed
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == config.creator @ ErrorCode::CreatorAccountMismatch,
+        constraint = creator_token_account.mint == prize_pool.mint @ ErrorCode::CreatorAccountMismatch
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + WeekArchive::INIT_SPACE,
+        seeds = [b"week_archive", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub week_archive: Box<Account<'info, WeekArchive>>,
+    
+    /// Pays for the WeekArchive if archive_week hasn't opened it yet
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Required once stakers are owed a share of pools in the stake mint
+    #[account(mut, seeds = [b"staker_rewards"], bump)]
+    pub staker_rewards_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the config feeds pools in this mint to the jackpot
+    #[account(mut, seeds = [b"jackpot_vault"], bump)]
+    pub jackpot_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Required while the config feeds pools in this mint to the insurance fund
+    #[account(mut, seeds = [b"insurance_vault"], bump)]
+    pub insurance_vault: Option<Account<'info, TokenAccount>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepExpired<'info>>,
+    leaves: Vec<SweptLeaf>,
+) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let root = prize_pool
+        .leaderboard_root
+        .ok_or(ErrorCode::LeaderboardNotFinalized)?;
+    require!(
+        prize_pool.distribution_nonce == 0,
+        ErrorCode::PoolAlreadyDistributed
+    );
+    // Archiving closes the receipts that tell claimed prizes from expired ones
+    require!(!prize_pool.archived, ErrorCode::PoolArchived);
+    require!(!prize_pool.is_cancelled(), ErrorCode::PoolCancelled);
+    require!(
+        Clock::get()?.unix_timestamp >= prize_pool.claim_deadline,
+        ErrorCode::ClaimWindowOpen
+    );
+    require!(
+        ctx.remaining_accounts.len() == leaves.len() * 2,
+        ErrorCode::ExpiredReceiptMismatch
+    );
+
+    let prize_pool_key = prize_pool.key();
+    ctx.accounts
+        .week_archive
+        .open(prize_pool_key, &ctx.accounts.prize_pool, ctx.bumps.week_archive);
+
+    if !ctx.accounts.prize_pool.expired_swept {
+        // The sponsor's reserve goes back through refund_bonus, not to the treasury
+        let mut amount = ctx.accounts
+            .prize_pool_token_account
+            .amount
+            .saturating_sub(ctx.accounts.prize_pool.bonus_reserve);
+        // Nobody claimed: the pool's cuts are taken as the first claim would have,
+        // so the expired shares come from the same basis claims use
+        if ctx.accounts.prize_pool.claim_basis == 0 && amount > 0 {
+            let fees = fix_claim_basis(
+                &mut ctx.accounts.config,
+                &mut ctx.accounts.prize_pool,
+                &ctx.accounts.prize_pool_token_account,
+                &ctx.accounts.creator_token_account,
+                ctx.accounts.staker_rewards_vault.as_ref(),
+                ctx.accounts.jackpot_vault.as_ref(),
+                ctx.accounts.insurance_vault.as_ref(),
+                &ctx.accounts.token_program,
+            )?;
+            amount = ctx.accounts.prize_pool.claim_basis;
+            if let Some(event) = fees.royalty {
+                emit_cpi!(event);
+            }
+            if let Some(event) = fees.staker_rewards {
+                emit_cpi!(event);
+            }
+            if let Some(event) = fees.jackpot {
+                emit_cpi!(event);
+            }
+            if let Some(event) = fees.insurance {
+                emit_cpi!(event);
+            }
+        }
+        if amount > 0 {
+            let to = ctx.accounts.treasury_token_account.to_account_info();
+            pay_from_vault(
+                &ctx.accounts.prize_pool,
+                &ctx.accounts.prize_pool_token_account,
+                &to,
+                &ctx.accounts.token_program,
+                amount,
+            )?;
+        }
+
+        let prize_pool = &mut ctx.accounts.prize_pool;
+        prize_pool.expired_swept = true;
+        prize_pool.swept = amount;
+        emit_cpi!(ExpiredPrizesSwept {
+            treasury: ctx.accounts.config.treasury,
+            amount,
+            week_id: prize_pool.week_id.clone(),
+            category: prize_pool.category.clone(),
+        });
+    }
+
+    let mut next_rank = ctx.accounts.prize_pool.expired_next_rank;
+    let mut receipts = 0;
+    let mut lamports_reclaimed = 0;
+    for (leaf, pair) in leaves.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (receipt, wallet) = (&pair[0], &pair[1]);
+        require!(leaf.rank >= next_rank, ErrorCode::ExpiredPrizeOrder);
+        next_rank = leaf.rank.saturating_add(1);
+        let prize_pool = &ctx.accounts.prize_pool;
+        require!(
+            verify_proof(
+                &leaf.proof,
+                &root,
+                leaderboard_leaf(&leaf.wallet, leaf.rank, leaf.score)
+            ),
+            ErrorCode::InvalidMerkleProof
+        );
+        let (address, _) = Pubkey::find_program_address(
+            &[b"claim", prize_pool_key.as_ref(), leaf.wallet.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(receipt.key(), address, ErrorCode::ExpiredReceiptMismatch);
+        require_keys_eq!(wallet.key(), leaf.wallet, ErrorCode::ArchiveWinnerMismatch);
+
+        // A claimed prize's receipt has served its purpose once claims are closed
+        if !receipt.data_is_empty() {
+            let record = Account::<ClaimRecord>::try_from(receipt)?;
+            let archive = &mut ctx.accounts.week_archive;
+            lamports_reclaimed +=
+                close_claim_record(record, wallet, &mut ctx.accounts.prize_pool, archive)?;
+            receipts += 1;
+            continue;
+        }
+
+        // The same checks claim_prize makes, so only real prizes are reported
+        let (rank, amount) = match prize_pool.airdrop_threshold {
+            Some(threshold) => {
+                require!(leaf.score >= threshold, ErrorCode::BelowAirdropThreshold);
+                let weight = tier_multiplier_bps(leaf.score, &prize_pool.qualification_tiers);
+                let amount = weighted_share(
+                    prize_pool.claim_basis,
+                    weight as u64,
+                    prize_pool.airdrop_total_weight(),
+                );
+                (0, amount)
+            }
+            None => {
+                require!(
+                    leaf.rank > 0 && leaf.rank <= prize_pool.max_winners as u32,
+                    ErrorCode::InvalidRank
+                );
+                require!(leaf.score >= 80, ErrorCode::ScoreTooLow);
+                let rank = leaf.rank as u8;
+                let amount =
+                    calculate_prize_with(rank, prize_pool.claim_basis, &prize_pool.rank_shares_bps);
+                (rank, amount)
+            }
+        };
+
+        emit_cpi!(PrizeExpired {
+            winner: leaf.wallet,
+            rank,
+            score: leaf.score,
+            amount,
+            week_id: prize_pool.week_id.clone(),
+            category: prize_pool.category.clone(),
+        });
+    }
+    ctx.accounts.prize_pool.expired_next_rank = next_rank;
+
+    if receipts > 0 {
+        let prize_pool = &ctx.accounts.prize_pool;
+        let archive = &ctx.accounts.week_archive;
+        emit_cpi!(StaleReceiptsClosed {
+            prize_pool: prize_pool_key,
+            receipts,
+            lamports_reclaimed,
+            receipts_archived: archive.receipts_archived,
+            receipts_hash: archive.receipts_hash,
+            week_id: prize_pool.week_id.clone(),
+            category: prize_pool.category.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::distribute_prizes::pay_from_vault;
+use crate::errors::ErrorCode;
+use crate::events::MintVaultSwept;
+use crate::state::{PoolRole, PrizePool, DELEGATE_SWEEP_MINT_VAULT};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepMintVault<'info> {
+    #[account(
+        constraint = prize_pool.authorizes(
+            PoolRole::Funding,
+            DELEGATE_SWEEP_MINT_VAULT,
+            &authority.key()
+        )? @ ErrorCode::Unauthorized
+    )]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"mint_vault", prize_pool.key().as_ref(), mint_vault.mint.as_ref()],
+        bump
+    )]
+    pub mint_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut, constraint = destination.mint == mint_vault.mint @ ErrorCode::MintMismatch)]
+    pub destination: Account<'info, TokenAccount>,
+    
+    /// The pool's funder or its delegate, see PoolRoles
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SweepMintVault>) -> Result<()> {
+    let amount = ctx.accounts.mint_vault.amount;
+    require!(amount > 0, ErrorCode::InsufficientFunds);
+
+    let destination = ctx.accounts.destination.to_account_info();
+    pay_from_vault(
+        &ctx.accounts.prize_pool,
+        &ctx.accounts.mint_vault,
+        &destination,
+        &ctx.accounts.token_program,
+        amount,
+    )?;
+
+    let prize_pool = &ctx.accounts.prize_pool;
+    emit_cpi!(MintVaultSwept {
+        mint: ctx.accounts.mint_vault.mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProgramState;
+
+#[derive(Accounts)]
+pub struct SyncProgramVersion<'info> {
+    #[account(mut, seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+}
+
+pub fn handler(ctx: Context<SyncProgramVersion>) -> Result<()> {
+    ctx.accounts.program_state.version = ProgramState::CURRENT_VERSION.to_string();
+    Ok(())
+}
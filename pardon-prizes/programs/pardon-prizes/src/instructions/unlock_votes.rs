@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::VotesUnlocked;
+use crate::state::{PrizePool, VoteEscrow};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockVotes<'info> {
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        close = voter,
+        has_one = voter @ ErrorCode::Unauthorized,
+        seeds = [b"vote_escrow", prize_pool.key().as_ref(), voter.key().as_ref()],
+        bump = vote_escrow.bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_vault", prize_pool.key().as_ref()],
+        bump
+    )]
+    pub vote_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = voter_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch,
+        constraint = voter_token_account.owner == voter.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<UnlockVotes>) -> Result<()> {
+    // Claimed weeks never move the nonce and cancelled ones never pay out
+    let prize_pool = &ctx.accounts.prize_pool;
+    require!(
+        !prize_pool.payouts_pending() || prize_pool.is_cancelled(),
+        ErrorCode::VotesLocked
+    );
+
+    let amount = ctx.accounts.vote_escrow.locked;
+    let week_seed = ctx.accounts.prize_pool.week_seed();
+    let seeds = &[
+        ctx.accounts.prize_pool.seed_prefix(),
+        week_seed.as_slice(),
+        ctx.accounts.prize_pool.category.as_bytes(),
+        &[ctx.accounts.prize_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vote_vault.to_account_info(),
+        to: ctx.accounts.voter_token_account.to_account_info(),
+        authority: ctx.accounts.prize_pool.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.votes_escrowed = prize_pool
+        .votes_escrowed
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit_cpi!(VotesUnlocked {
+        voter: ctx.accounts.voter.key(),
+        amount,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
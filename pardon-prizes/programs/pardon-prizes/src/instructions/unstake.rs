@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::Unstaked;
+use crate::state::{Config, StakeAccount};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(
+        mut,
+        has_one = wallet @ ErrorCode::Unauthorized,
+        seeds = [b"stake", wallet.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = wallet_token_account.mint == stake_vault.mint @ ErrorCode::MintMismatch,
+        constraint = wallet_token_account.owner == wallet.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub wallet_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        amount <= ctx.accounts.stake_account.amount,
+        ErrorCode::InsufficientStake
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.stake_account.locked_until,
+        ErrorCode::StakeLocked
+    );
+
+    let seeds = &[b"config".as_ref(), &[ctx.accounts.config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.wallet_token_account.to_account_info(),
+        authority: ctx.accounts.config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)?;
+
+    let reward_per_share = ctx.accounts.config.reward_per_share;
+    let stake_account = &mut ctx.accounts.stake_account;
+    stake_account.settle(reward_per_share);
+    stake_account.amount -= amount;
+    stake_account.reset_reward_debt(reward_per_share);
+    ctx.accounts.config.total_staked -= amount;
+
+    emit_cpi!(Unstaked {
+        wallet: stake_account.wallet,
+        amount,
+        staked: stake_account.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigKeyChanged;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::{AuthorityChange, AuthorityKind, Config};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    creator: Pubkey,
+    creator_share_bps: u16,
+) -> Result<()> {
+    require!(
+        creator_share_bps <= BPS_DENOMINATOR,
+        ErrorCode::InvalidCreatorShare
+    );
+
+    let config = &mut ctx.accounts.config;
+    let previous = config.creator;
+    config.creator = creator;
+    config.creator_share_bps = creator_share_bps;
+
+    if previous != creator {
+        let change = AuthorityChange {
+            kind: AuthorityKind::Creator,
+            previous,
+            key: creator,
+            changed_at: Clock::get()?.unix_timestamp,
+        };
+        AuthorityChange::record(&mut config.authority_history, change);
+
+        emit_cpi!(ConfigKeyChanged {
+            kind: change.kind,
+            previous,
+            key: creator,
+            changed_at: change.changed_at,
+        });
+    }
+
+    Ok(())
+}
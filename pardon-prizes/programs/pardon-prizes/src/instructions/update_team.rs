@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{Team, TeamMember};
+
+#[derive(Accounts)]
+pub struct UpdateTeam<'info> {
+    #[account(
+        mut,
+        seeds = [b"team", captain.key().as_ref()],
+        bump = team.bump,
+        has_one = captain @ ErrorCode::Unauthorized
+    )]
+    pub team: Account<'info, Team>,
+    
+    pub captain: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateTeam>, members: Vec<TeamMember>) -> Result<()> {
+    Team::validate_members(&members)?;
+    ctx.accounts.team.members = members;
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::events::{AccountingMismatch, AccountingOk};
+use crate::state::PrizePool;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VerifyAccounting<'info> {
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(address = prize_pool.vault @ ErrorCode::VaultMismatch)]
+    pub prize_pool_token_account: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<VerifyAccounting>) -> Result<()> {
+    let prize_pool = &ctx.accounts.prize_pool;
+    let expected = prize_pool.expected_vault_balance();
+    let balance = ctx.accounts.prize_pool_token_account.amount;
+
+    // Reported rather than failed, so watchdogs read the result from the event
+    if expected == Some(balance) {
+        emit_cpi!(AccountingOk {
+            prize_pool: prize_pool.key(),
+            balance,
+            week_id: prize_pool.week_id.clone(),
+            category: prize_pool.category.clone(),
+        });
+    } else {
+        emit_cpi!(AccountingMismatch {
+            prize_pool: prize_pool.key(),
+            expected,
+            balance,
+            week_id: prize_pool.week_id.clone(),
+            category: prize_pool.category.clone(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::ScoringProofVerified;
+use crate::groth16::{scoring_inputs, verify, ScoringProof};
+use crate::state::{PrizePool, ScoringVerifier};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VerifyScoringProof<'info> {
+    #[account(seeds = [b"scoring_verifier"], bump = scoring_verifier.bump)]
+    pub scoring_verifier: Box<Account<'info, ScoringVerifier>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+}
+
+pub fn handler(ctx: Context<VerifyScoringProof>, proof: ScoringProof) -> Result<()> {
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    let (leaderboard_root, transcript_hash) =
+        match (prize_pool.leaderboard_root, &prize_pool.scoring_transcript) {
+            (Some(root), Some(transcript)) => (root, transcript.hash),
+            _ => return err!(ErrorCode::ScoringInputsMissing),
+        };
+
+    let inputs = scoring_inputs(&leaderboard_root, &transcript_hash);
+    require!(
+        verify(&ctx.accounts.scoring_verifier.key, &proof, &inputs)?,
+        ErrorCode::InvalidScoringProof
+    );
+    prize_pool.scoring_proof_verified = true;
+
+    emit_cpi!(ScoringProofVerified {
+        prize_pool: prize_pool.key(),
+        leaderboard_root,
+        transcript_hash,
+        week_id: prize_pool.week_id.clone(),
+        category: prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::ErrorCode;
+use crate::events::VoteCast;
+use crate::math::counted_votes;
+use crate::state::{Config, Entry, PrizePool, VoteEscrow, VoteRecord};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteForEntry<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    #[account(mut)]
+    pub prize_pool: Box<Account<'info, PrizePool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"entry", prize_pool.key().as_ref(), entry.player.as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, Entry>,
+    
+    /// One per (entry, voter); a second vote fails to re-init it
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote", entry.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoteEscrow::INIT_SPACE,
+        seeds = [b"vote_escrow", prize_pool.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_escrow: Account<'info, VoteEscrow>,
+    
+    #[account(
+        init_if_needed,
+        payer = voter,
+        seeds = [b"vote_vault", prize_pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = prize_pool
+    )]
+    pub vote_vault: Account<'info, TokenAccount>,
+    
+    #[account(address = prize_pool.mint @ ErrorCode::MintMismatch)]
+    pub mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = voter_token_account.mint == prize_pool.mint @ ErrorCode::MintMismatch,
+        constraint = voter_token_account.owner == voter.key() @ ErrorCode::TokenOwnerMismatch
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<VoteForEntry>, weight: u64) -> Result<()> {
+    require!(weight > 0, ErrorCode::InvalidAmount);
+    require!(
+        weight <= ctx.accounts.voter_token_account.amount,
+        ErrorCode::VoteWeightExceedsBalance
+    );
+    require!(!ctx.accounts.entry.finalized, ErrorCode::EntryFinalized);
+
+    // Votes are backed by tokens locked until distribution, so the same
+    // tokens can't vote again from another wallet
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.voter_token_account.to_account_info(),
+        to: ctx.accounts.vote_vault.to_account_info(),
+        authority: ctx.accounts.voter.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), weight)?;
+
+    let escrow = &mut ctx.accounts.vote_escrow;
+    if escrow.voter == Pubkey::default() {
+        escrow.prize_pool = ctx.accounts.prize_pool.key();
+        escrow.voter = ctx.accounts.voter.key();
+        escrow.bump = ctx.bumps.vote_escrow;
+    }
+    escrow.locked = escrow.locked.checked_add(weight).ok_or(ErrorCode::Overflow)?;
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.votes_escrowed = prize_pool
+        .votes_escrowed
+        .checked_add(weight)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let counted = counted_votes(weight, ctx.accounts.config.quadratic_votes);
+    let entry = &mut ctx.accounts.entry;
+    entry.votes += counted;
+
+    let record = &mut ctx.accounts.vote_record;
+    record.entry = entry.key();
+    record.voter = ctx.accounts.voter.key();
+    record.weight = weight;
+    record.counted = counted;
+    record.bump = ctx.bumps.vote_record;
+
+    emit_cpi!(VoteCast {
+        voter: record.voter,
+        player: entry.player,
+        weight,
+        counted,
+        votes: entry.votes,
+        week_id: ctx.accounts.prize_pool.week_id.clone(),
+        category: ctx.accounts.prize_pool.category.clone(),
+    });
+
+    Ok(())
+}
@@ -1,5 +1,24 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub mod caller;
+pub mod civic;
+pub mod ed25519;
+pub mod errors;
+pub mod events;
+pub mod groth16;
+pub mod instructions;
+pub mod math;
+pub mod merkle;
+pub mod metaplex;
+pub mod pyth;
+pub mod state;
+pub mod vrf;
+pub mod wormhole;
+
+pub use errors::ErrorCode;
+pub use events::*;
+pub use instructions::*;
+pub use state::*;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
@@ -8,228 +27,1150 @@ pub mod pardon_prizes {
     use super::*;
 
     /**
-     * Initialize the prize pool account
+     * Create the program config
+     * Only callable by the program upgrade authority, who becomes admin
+     */
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        creator: Pubkey,
+        creator_share_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_config::handler(ctx, creator, creator_share_bps)
+    }
+
+    /**
+     * Create the protocol-wide GlobalStats account, once; pool creation
+     * needs it
+     * Only callable by the admin
+     */
+    pub fn initialize_global_stats(ctx: Context<InitializeGlobalStats>) -> Result<()> {
+        instructions::initialize_global_stats::handler(ctx)
+    }
+
+    /**
+     * Update the creator royalty settings
+     * Only callable by the config admin
+     */
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        creator: Pubkey,
+        creator_share_bps: u16,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, creator, creator_share_bps)
+    }
+
+    /**
+     * Create the ProgramState account with every feature disabled
+     * Only callable by the config admin
+     */
+    pub fn initialize_program_state(ctx: Context<InitializeProgramState>) -> Result<()> {
+        instructions::initialize_program_state::handler(ctx)
+    }
+
+    /**
+     * Turn one FEATURE_* flag on or off
+     * Only callable by the config admin
+     */
+    pub fn set_feature_flag(ctx: Context<SetFeatureFlag>, feature: u64, enabled: bool) -> Result<()> {
+        instructions::set_feature_flag::handler(ctx, feature, enabled)
+    }
+
+    /**
+     * Record the running program's version in ProgramState
+     * Callable by anyone, e.g. right after an upgrade
+     */
+    pub fn sync_program_version(ctx: Context<SyncProgramVersion>) -> Result<()> {
+        instructions::sync_program_version::handler(ctx)
+    }
+
+    /**
+     * Stop winners ranked 1..=cooldown_rank from repeating a top-3 finish
+     * two weeks running (0 disables it)
+     * Only callable by the config admin
+     */
+    pub fn set_win_cooldown(ctx: Context<SetWinCooldown>, cooldown_rank: u8) -> Result<()> {
+        instructions::set_win_cooldown::handler(ctx, cooldown_rank)
+    }
+
+    /**
+     * Set how judge scores and community votes combine into final_score
+     * Only callable by the config admin
+     */
+    pub fn set_score_weights(
+        ctx: Context<SetScoreWeights>,
+        judge_weight: u16,
+        vote_weight: u16,
+    ) -> Result<()> {
+        instructions::set_score_weights::handler(ctx, judge_weight, vote_weight)
+    }
+
+    /**
+     * Count community votes as sqrt(tokens) instead of tokens
+     * Only callable by the config admin
+     */
+    pub fn set_quadratic_votes(ctx: Context<SetQuadraticVotes>, enabled: bool) -> Result<()> {
+        instructions::set_quadratic_votes::handler(ctx, enabled)
+    }
+
+    /**
+     * Make distribute_prizes and claim_prize wait until the pool's scoring
+     * transcript is posted
+     * Only callable by the config admin
+     */
+    pub fn set_scoring_transcripts_required(
+        ctx: Context<SetScoringTranscriptsRequired>,
+        required: bool,
+    ) -> Result<()> {
+        instructions::set_scoring_transcripts_required::handler(ctx, required)
+    }
+
+    /**
+     * Register the judge enclave's attested ed25519 key, whose signed winners
+     * authorize distribute_prizes from any relayer (None to disable)
+     * Only callable by the config admin
+     */
+    pub fn set_enclave_signer(
+        ctx: Context<SetEnclaveSigner>,
+        enclave_signer: Option<EnclaveSigner>,
+    ) -> Result<()> {
+        instructions::set_enclave_signer::handler(ctx, enclave_signer)
+    }
+
+    /**
+     * Set the stake mint and the tiers that boost a staker's prizes
+     * (empty disables boosts); the mint can't change while tokens are staked
+     * Only callable by the config admin
+     */
+    pub fn set_stake_tiers(ctx: Context<SetStakeTiers>, stake_tiers: Vec<StakeTier>) -> Result<()> {
+        instructions::set_stake_tiers::handler(ctx, stake_tiers)
+    }
+
+    /**
+     * Route `staker_share_bps` of each pool, after the creator royalty, to
+     * stakers pro-rata; creates the staker rewards vault on first use
+     * Only callable by the config admin
+     */
+    pub fn set_staker_share(ctx: Context<SetStakerShare>, staker_share_bps: u16) -> Result<()> {
+        instructions::set_staker_share::handler(ctx, staker_share_bps)
+    }
+
+    /**
+     * Lock `amount` of the stake mint in the stake vault
+     * Each deposit relocks the whole stake for STAKE_LOCK_SECONDS
+     */
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::stake::handler(ctx, amount)
+    }
+
+    /**
+     * Withdraw `amount` of the signer's stake once its lock has expired
+     */
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::unstake::handler(ctx, amount)
+    }
+
+    /**
+     * Pay out the signer's share of staker rewards
+     */
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        instructions::claim_staking_rewards::handler(ctx)
+    }
+
+    /**
+     * Restrict pools and deposits to these mints (empty = any pool mint);
+     * pools accept the other listed mints into per-mint sub-vaults
+     * Only callable by the config admin
+     */
+    pub fn set_funding_mints(
+        ctx: Context<SetFundingMints>,
+        funding_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_funding_mints::handler(ctx, funding_mints)
+    }
+
+    /**
+     * Restrict which programs may CPI into fund_pool and submit_score;
+     * direct calls are unaffected and an empty list allows any program
+     * Only callable by the config admin
+     */
+    pub fn set_cpi_callers(ctx: Context<SetCpiCallers>, cpi_callers: Vec<Pubkey>) -> Result<()> {
+        instructions::set_cpi_callers::handler(ctx, cpi_callers)
+    }
+
+    /**
+     * Feed `jackpot_bps` of every pool in `jackpot_mint` (after the creator
+     * and staker cuts) to the progressive jackpot, won by an entry scoring at
+     * least `trigger_score`; creates the jackpot and its vault on first use
+     * Only callable by the config admin
+     */
+    pub fn configure_jackpot(
+        ctx: Context<ConfigureJackpot>,
+        jackpot_bps: u16,
+        trigger_score: u8,
+    ) -> Result<()> {
+        instructions::configure_jackpot::handler(ctx, jackpot_bps, trigger_score)
+    }
+
+    /**
+     * Pay winners of pools in `streak_mint` an extra `streak_bonus_bps` of
+     * their prize from the streak vault once they've placed
+     * `min_streak_weeks` weeks in a row; creates the vault on first use
+     * Only callable by the config admin
+     */
+    pub fn configure_streak_bonus(
+        ctx: Context<ConfigureStreakBonus>,
+        streak_bonus_bps: u16,
+        min_streak_weeks: u16,
+    ) -> Result<()> {
+        instructions::configure_streak_bonus::handler(ctx, streak_bonus_bps, min_streak_weeks)
+    }
+
+    /**
+     * Set aside `insurance_bps` of every pool in `insurance_mint` (after the
+     * creator, staker and jackpot cuts) in the insurance vault; creates the
+     * vault on first use
+     * Only callable by the config admin
+     */
+    pub fn configure_insurance(ctx: Context<ConfigureInsurance>, insurance_bps: u16) -> Result<()> {
+        instructions::configure_insurance::handler(ctx, insurance_bps)
+    }
+
+    /**
+     * Compensate a winner whose payout from `prize_pool` a program or judge
+     * failure got wrong, out of the insurance vault; `incident_hash` points at
+     * the incident report the claim was approved on
+     * Only callable by the guardian
+     */
+    pub fn pay_insurance_claim(
+        ctx: Context<PayInsuranceClaim>,
+        amount: u64,
+        incident_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::pay_insurance_claim::handler(ctx, amount, incident_hash)
+    }
+
+    /**
+     * Set the key allowed to announce and execute emergency withdrawals
+     * Only callable by the config admin
+     */
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    /**
+     * Let anyone close an emptied pool `auto_close_after_secs` after it was
+     * archived, 0 to turn it off
+     * Only callable by the config admin
+     */
+    pub fn set_auto_close(ctx: Context<SetAutoClose>, auto_close_after_secs: i64) -> Result<()> {
+        instructions::set_auto_close::handler(ctx, auto_close_after_secs)
+    }
+
+    /**
+     * Set the unix timestamp week 0 of the numbered pools starts at; every
+     * later week starts WEEK_SECONDS after the one before. Can only be set once
+     * Only callable by the config admin
+     */
+    pub fn set_week_schedule(ctx: Context<SetWeekSchedule>, week_epoch_start: i64) -> Result<()> {
+        instructions::set_week_schedule::handler(ctx, week_epoch_start)
+    }
+
+    /**
+     * Set the split rollover_week gives each new numbered pool: `max_winners`
+     * paid ranks and `rank_shares_bps` as in initialize_prize_pool (0 winners
+     * turns rollover off)
+     * Only callable by the config admin
+     */
+    pub fn set_pool_defaults(
+        ctx: Context<SetPoolDefaults>,
+        max_winners: u8,
+        rank_shares_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::set_pool_defaults::handler(ctx, max_winners, rank_shares_bps)
+    }
+
+    /**
+     * Set the wallet that receives the rake of entry-fee pools
+     * Only callable by the config admin
+     */
+    pub fn set_treasury(ctx: Context<SetTreasury>, treasury: Pubkey) -> Result<()> {
+        instructions::set_treasury::handler(ctx, treasury)
+    }
+
+    /**
+     * Initialize the prize pool for one category of a week
+     * `max_winners` is the number of paid ranks, at most 10; `rank_shares_bps`
+     * sets the category's own split, or is empty for the default curve
      */
     pub fn initialize_prize_pool(
         ctx: Context<InitializePrizePool>,
         week_id: String,
+        category: String,
+        max_winners: u8,
+        rank_shares_bps: Vec<u16>,
     ) -> Result<()> {
-        let prize_pool = &mut ctx.accounts.prize_pool;
-        prize_pool.authority = ctx.accounts.authority.key();
-        prize_pool.week_id = week_id;
-        prize_pool.total_distributed = 0;
-        prize_pool.bump = ctx.bumps.prize_pool;
-        Ok(())
+        instructions::initialize_prize_pool::handler(
+            ctx,
+            week_id,
+            category,
+            max_winners,
+            rank_shares_bps,
+        )
     }
 
     /**
-     * Distribute prizes to winners based on their rank
+     * Initialize the prize pool for one category of a numbered week, at
+     * ["prize_pool_v2", week_index, category]; only the current week and the
+     * next one are open, and the pool's week_id is the ISO week it starts in
+     */
+    pub fn initialize_weekly_pool(
+        ctx: Context<InitializeWeeklyPool>,
+        week_index: u32,
+        category: String,
+        max_winners: u8,
+        rank_shares_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::initialize_weekly_pool::handler(
+            ctx,
+            week_index,
+            category,
+            max_winners,
+            rank_shares_bps,
+        )
+    }
+
+    /**
+     * Open the next numbered week's pool once `previous_pool`'s week has
+     * ended, split per the config's pool defaults, carrying over the surplus
+     * of an archived earlier week if one is passed
+     * Callable by anyone
+     */
+    pub fn rollover_week(ctx: Context<RolloverWeek>) -> Result<()> {
+        instructions::rollover_week::handler(ctx)
+    }
+
+    /**
+     * Deposit prize tokens into the pool's vault, or allowlisted tokens of
+     * another mint into that mint's sub-vault
+     * Callable by anyone; pool mint deposits are recorded in total_funded
+     */
+    pub fn fund_pool(ctx: Context<FundPool>, amount: u64, memo: String) -> Result<()> {
+        instructions::fund_pool::handler(ctx, amount, memo)
+    }
+
+    /**
+     * Create the pool's sub-vault for an allowlisted mint other than its own
+     * Callable by anyone, who pays the rent
+     */
+    pub fn open_mint_vault(ctx: Context<OpenMintVault>) -> Result<()> {
+        instructions::open_mint_vault::handler(ctx)
+    }
+
+    /**
+     * Move a sub-vault's whole balance out, e.g. to redeem bridged tokens
+     * into the pool mint and fund the pool with them
+     * Only callable by the pool's funder role
+     */
+    pub fn sweep_mint_vault(ctx: Context<SweepMintVault>) -> Result<()> {
+        instructions::sweep_mint_vault::handler(ctx)
+    }
+
+    /**
+     * Run the pool at `bonus_multiplier_bps` (e.g. 20000 = 2x, 0 = off),
+     * paid for by `sponsor` through fund_bonus; locked once funded
+     * Only callable by the authority, before any payout
+     */
+    pub fn set_bonus_multiplier(
+        ctx: Context<SetBonusMultiplier>,
+        bonus_multiplier_bps: u16,
+        sponsor: Pubkey,
+    ) -> Result<()> {
+        instructions::set_bonus_multiplier::handler(ctx, bonus_multiplier_bps, sponsor)
+    }
+
+    /**
+     * Deposit the bonus reserve that pays the multiplier's extra on each prize
+     * Only callable by the pool's bonus sponsor, before any payout
+     */
+    pub fn fund_bonus(ctx: Context<FundBonus>, amount: u64) -> Result<()> {
+        instructions::fund_bonus::handler(ctx, amount)
+    }
+
+    /**
+     * Return what's left of the bonus reserve to the sponsor
+     * Only callable by the authority, once payouts have started or the week
+     * is cancelled
+     */
+    pub fn refund_bonus(ctx: Context<RefundBonus>) -> Result<()> {
+        instructions::refund_bonus::handler(ctx)
+    }
+
+    /**
+     * Advertise the pool's prizes as `usd_cents` in total, converted to the
+     * pool mint at distribution through the Pyth feed `feed_id`, with price
+     * updates at most `max_price_age_secs` old and a confidence interval of
+     * at most `max_conf_bps` of the price (0 cents = pay the vault as is)
+     * Only callable by the authority, before any payout
+     */
+    pub fn set_usd_prizes(
+        ctx: Context<SetUsdPrizes>,
+        usd_cents: u64,
+        feed_id: [u8; 32],
+        max_price_age_secs: u32,
+        max_conf_bps: u16,
+    ) -> Result<()> {
+        instructions::set_usd_prizes::handler(
+            ctx,
+            usd_cents,
+            feed_id,
+            max_price_age_secs,
+            max_conf_bps,
+        )
+    }
+
+    /**
+     * Cap per-wallet deposits (0 = no cap) and optionally require a whitelist
+     * Only callable by the pool's funder role
+     */
+    pub fn set_funding_rules(
+        ctx: Context<SetFundingRules>,
+        funder_cap: u64,
+        whitelist_only: bool,
+    ) -> Result<()> {
+        instructions::set_funding_rules::handler(ctx, funder_cap, whitelist_only)
+    }
+
+    /**
+     * Hand one of the pool's roles to `key`, e.g. a least-privilege hot key
+     * Only callable by the authority
+     */
+    pub fn set_pool_role(ctx: Context<SetPoolRole>, role: PoolRole, key: Pubkey) -> Result<()> {
+        instructions::set_pool_role::handler(ctx, role, key)
+    }
+
+    /**
+     * Let `key` run the role-gated instructions in `allowed_ixs` (DELEGATE_*
+     * bits) until `expires_at`, e.g. for an automation service; replaces any
+     * previous delegate, and 0 bits revokes it
      * Only callable by the authority
      */
-    pub fn distribute_prizes(
-        ctx: Context<DistributePrizes>,
+    pub fn delegate_authority(
+        ctx: Context<DelegateAuthority>,
+        key: Pubkey,
+        expires_at: i64,
+        allowed_ixs: u64,
+    ) -> Result<()> {
+        instructions::delegate_authority::handler(ctx, key, expires_at, allowed_ixs)
+    }
+
+    /**
+     * Add or remove `funder` from the pool's funding whitelist
+     * Only callable by the pool's funder role
+     */
+    pub fn set_funder_allowed(
+        ctx: Context<SetFunderAllowed>,
+        funder: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::set_funder_allowed::handler(ctx, funder, allowed)
+    }
+
+    /**
+     * Allow winners of this pool at most one exclusive category per week
+     * Only callable by the authority
+     */
+    pub fn set_exclusive_winners(ctx: Context<SetExclusiveWinners>, exclusive: bool) -> Result<()> {
+        instructions::set_exclusive_winners::handler(ctx, exclusive)
+    }
+
+    /**
+     * Let winners lists skip ranks, e.g. when a placed entry is disqualified
+     * Only callable by the authority
+     */
+    pub fn set_rank_gaps_allowed(ctx: Context<SetRankGapsAllowed>, allowed: bool) -> Result<()> {
+        instructions::set_rank_gaps_allowed::handler(ctx, allowed)
+    }
+
+    /**
+     * Pay every leaderboard leaf scoring at least `threshold` an equal share
+     * through claim_prize instead of splitting by rank (None for ranked payouts);
+     * the leaderboard finalized afterwards must list only qualifiers
+     * Only callable by the authority
+     */
+    pub fn set_airdrop_mode(ctx: Context<SetAirdropMode>, threshold: Option<u8>) -> Result<()> {
+        instructions::set_airdrop_mode::handler(ctx, threshold)
+    }
+
+    /**
+     * Weight airdrop shares by score tier: a qualifier reaching a tier's
+     * threshold claims its multiplier's worth of shares (empty for equal shares)
+     * Only callable by the authority
+     */
+    pub fn set_qualification_tiers(
+        ctx: Context<SetQualificationTiers>,
+        tiers: Vec<QualificationTier>,
+    ) -> Result<()> {
+        instructions::set_qualification_tiers::handler(ctx, tiers)
+    }
+
+    /**
+     * Post the sum of every qualifier's tier multiplier on a tiered airdrop,
+     * after the leaderboard is final and before the first claim
+     * Only callable by the pool's poster role
+     */
+    pub fn set_airdrop_weight(ctx: Context<SetAirdropWeight>, total_weight: u64) -> Result<()> {
+        instructions::set_airdrop_weight::handler(ctx, total_weight)
+    }
+
+    /**
+     * Require players to register holding an NFT verified in `collection`
+     * (None lifts the requirement); with `burn_ticket` the NFT is a ticket
+     * burned on registration
+     * Only callable by the authority
+     */
+    pub fn set_entry_collection(
+        ctx: Context<SetEntryCollection>,
+        collection: Option<Pubkey>,
+        burn_ticket: bool,
+    ) -> Result<()> {
+        instructions::set_entry_collection::handler(ctx, collection, burn_ticket)
+    }
+
+    /**
+     * Require players to hold a Civic pass from the gatekeeper `network` to
+     * register and again to claim (None lifts the requirement)
+     * Only callable by the authority
+     */
+    pub fn set_gatekeeper_network(
+        ctx: Context<SetGatekeeperNetwork>,
+        network: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_gatekeeper_network::handler(ctx, network)
+    }
+
+    /**
+     * Require players to register with a wallet whose first on-chain activity
+     * the `requirement`'s registrar attested at least min_age_days ago
+     * (None lifts the requirement)
+     * Only callable by the authority
+     */
+    pub fn set_wallet_age_requirement(
+        ctx: Context<SetWalletAgeRequirement>,
+        requirement: Option<WalletAgeRequirement>,
+    ) -> Result<()> {
+        instructions::set_wallet_age_requirement::handler(ctx, requirement)
+    }
+
+    /**
+     * Record when `wallet` was first seen on-chain, as signed by `registrar`
+     * in an ed25519 instruction just before this one; creates the wallet's
+     * player profile if needed
+     * Callable by anyone relaying the registrar's signature
+     */
+    pub fn attest_wallet_age(
+        ctx: Context<AttestWalletAge>,
+        wallet: Pubkey,
+        registrar: Pubkey,
+        first_seen_at: i64,
+    ) -> Result<()> {
+        instructions::attest_wallet_age::handler(ctx, wallet, registrar, first_seen_at)
+    }
+
+    /**
+     * Charge `entry_fee` of the pool mint per registration, growing the pool
+     * with each player; `rake_bps` of the fees goes to the treasury when
+     * entries close. Locked once any fee has been paid.
+     * Only callable by the authority
+     */
+    pub fn set_entry_fee(ctx: Context<SetEntryFee>, entry_fee: u64, rake_bps: u16) -> Result<()> {
+        instructions::set_entry_fee::handler(ctx, entry_fee, rake_bps)
+    }
+
+    /**
+     * Hold `entry_bond` of the pool mint per registration in the bond vault,
+     * refunded once the week pays out unless the guardian slashes it for
+     * cheating. Locked once any bond has been posted.
+     * Only callable by the authority
+     */
+    pub fn set_entry_bond(ctx: Context<SetEntryBond>, entry_bond: u64) -> Result<()> {
+        instructions::set_entry_bond::handler(ctx, entry_bond)
+    }
+
+    /**
+     * Move a registration's bond into the prize vault after cheating was confirmed
+     * Only callable by the guardian
+     */
+    pub fn slash_entry_bond(ctx: Context<SlashEntryBond>) -> Result<()> {
+        instructions::slash_entry_bond::handler(ctx)
+    }
+
+    /**
+     * Return the player's entry bond once the pool has started paying out,
+     * or right away if it was cancelled
+     */
+    pub fn refund_entry_bond(ctx: Context<RefundEntryBond>) -> Result<()> {
+        instructions::refund_entry_bond::handler(ctx)
+    }
+
+    /**
+     * Take cheat reports bonded with `report_bond` of the pool mint; an upheld
+     * report pays the reporter `bounty_bps` of the slashed entry bond
+     * (report_bond 0 stops new reports)
+     * Only callable by the authority
+     */
+    pub fn set_report_bounty(
+        ctx: Context<SetReportBounty>,
+        report_bond: u64,
+        bounty_bps: u16,
+    ) -> Result<()> {
+        instructions::set_report_bounty::handler(ctx, report_bond, bounty_bps)
+    }
+
+    /**
+     * Report `wallet` for cheating, posting the pool's report bond and the
+     * hash of the evidence handed to the guardian
+     * Callable by anyone but the reported player
+     */
+    pub fn report_cheater(
+        ctx: Context<ReportCheater>,
+        wallet: Pubkey,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::report_cheater::handler(ctx, wallet, evidence_hash)
+    }
+
+    /**
+     * Rule on a cheat report: uphold it to slash the player's entry bond and
+     * pay the reporter's bounty, or reject it to forfeit the report bond
+     * Only callable by the guardian
+     */
+    pub fn resolve_report(ctx: Context<ResolveReport>, upheld: bool) -> Result<()> {
+        instructions::resolve_report::handler(ctx, upheld)
+    }
+
+    /**
+     * Sign the player up for a pool; collection-gated pools check their NFT,
+     * entry-fee pools charge the fee and bonded pools hold the bond
+     */
+    pub fn register_player(ctx: Context<RegisterPlayer>) -> Result<()> {
+        instructions::register_player::handler(ctx)
+    }
+
+    /**
+     * Stop registrations and pay the rake on collected entry fees to the
+     * treasury, locking the rest as the prize pool
+     * Only callable by the authority
+     */
+    pub fn close_entries(ctx: Context<CloseEntries>) -> Result<()> {
+        instructions::close_entries::handler(ctx)
+    }
+
+    /**
+     * Require winners to have snapshotted at least `min_holding` of the
+     * pool mint between the two slots (0 disables it); locked once the
+     * window opens
+     * Only callable by the authority
+     */
+    pub fn set_holder_requirement(
+        ctx: Context<SetHolderRequirement>,
+        min_holding: u64,
+        snapshot_start_slot: u64,
+        snapshot_end_slot: u64,
+    ) -> Result<()> {
+        instructions::set_holder_requirement::handler(
+            ctx,
+            min_holding,
+            snapshot_start_slot,
+            snapshot_end_slot,
+        )
+    }
+
+    /**
+     * Record the signer's current balance of the pool mint, once per wallet
+     * and only inside the pool's snapshot window
+     */
+    pub fn record_balance_snapshot(ctx: Context<RecordBalanceSnapshot>) -> Result<()> {
+        instructions::record_balance_snapshot::handler(ctx)
+    }
+
+    /**
+     * Set the pool's display name and metadata URI (empty strings clear them)
+     * Only callable by the authority
+     */
+    pub fn set_pool_metadata(
+        ctx: Context<SetPoolMetadata>,
+        name: String,
+        metadata_uri: String,
+    ) -> Result<()> {
+        instructions::set_pool_metadata::handler(ctx, name, metadata_uri)
+    }
+
+    /**
+     * Set how this pool's raw judge scores are normalized onto 0-100
+     * Only callable by the authority
+     */
+    pub fn set_score_normalization(
+        ctx: Context<SetScoreNormalization>,
+        raw_score_max: u16,
+        score_curve: ScoreCurve,
+    ) -> Result<()> {
+        instructions::set_score_normalization::handler(ctx, raw_score_max, score_curve)
+    }
+
+    /**
+     * Replace the pool's happy hours: windows where submit_score multiplies
+     * the normalized score (capped at 100), judged by the on-chain clock
+     * Only callable by the authority
+     */
+    pub fn set_happy_hours(ctx: Context<SetHappyHours>, happy_hours: Vec<HappyHour>) -> Result<()> {
+        instructions::set_happy_hours::handler(ctx, happy_hours)
+    }
+
+    /**
+     * Record `player`'s raw judge score, normalized with the pool's curve
+     * Only callable by the pool's poster role, on pools without judges;
+     * resubmitting overwrites the entry
+     */
+    pub fn submit_score(ctx: Context<SubmitScore>, player: Pubkey, raw_score: u16) -> Result<()> {
+        instructions::submit_score::handler(ctx, player, raw_score)
+    }
+
+    /**
+     * Replace the pool's judge panel, the scores aggregate_scores needs per
+     * player (at least 3) and when judging ends; an empty panel goes back to
+     * submit_score. Locked once the leaderboard root is posted or payouts start
+     * Only callable by the authority
+     */
+    pub fn set_judges(
+        ctx: Context<SetJudges>,
+        judges: Vec<Pubkey>,
+        quorum: u8,
+        judging_ends_at: i64,
+    ) -> Result<()> {
+        instructions::set_judges::handler(ctx, judges, quorum, judging_ends_at)
+    }
+
+    /**
+     * Record the signing judge's raw score for `player`, once per judge
+     * Only callable by one of the pool's judges
+     */
+    pub fn submit_judge_score(
+        ctx: Context<SubmitJudgeScore>,
+        player: Pubkey,
+        raw_score: u16,
+    ) -> Result<()> {
+        instructions::submit_judge_score::handler(ctx, player, raw_score)
+    }
+
+    /**
+     * Once a quorum of judges scored the player, drop the single highest and
+     * lowest score and record the mean of the rest as the entry's raw score
+     * Callable by anyone
+     */
+    pub fn aggregate_scores(ctx: Context<AggregateScores>) -> Result<()> {
+        instructions::aggregate_scores::handler(ctx)
+    }
+
+    /**
+     * Cast `weight` community votes for an entry, locking that many of the
+     * voter's tokens in the pool's vote vault; one vote per wallet per entry
+     */
+    pub fn vote_for_entry(ctx: Context<VoteForEntry>, weight: u64) -> Result<()> {
+        instructions::vote_for_entry::handler(ctx, weight)
+    }
+
+    /**
+     * Return the voter's escrowed tokens once the pool has distributed
+     */
+    pub fn unlock_votes(ctx: Context<UnlockVotes>) -> Result<()> {
+        instructions::unlock_votes::handler(ctx)
+    }
+
+    /**
+     * Lock an entry, compute its final_score from the config weights and
+     * count it toward the jackpot draw if it reached the trigger
+     * Only callable by the pool's poster role
+     */
+    pub fn finalize_entry(ctx: Context<FinalizeEntry>) -> Result<()> {
+        instructions::finalize_entry::handler(ctx)
+    }
+
+    /**
+     * Store the Merkle root of the week's full final leaderboard so anyone
+     * can prove their rank and score, opening a CLAIM_WINDOW_SECONDS claim
+     * window; can only be set once
+     * Only callable by the pool's poster role
+     */
+    pub fn finalize_leaderboard(
+        ctx: Context<FinalizeLeaderboard>,
+        root: [u8; 32],
+        entry_count: u32,
+    ) -> Result<()> {
+        instructions::finalize_leaderboard::handler(ctx, root, entry_count)
+    }
+
+    /**
+     * Publish the hash and URI of the judge's full scoring transcript for the
+     * pool's week, giving players an artifact to dispute against; can only
+     * be set once, before payouts start
+     * Only callable by the pool's poster role
+     */
+    pub fn post_scoring_transcript(
+        ctx: Context<PostScoringTranscript>,
+        transcript_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::post_scoring_transcript::handler(ctx, transcript_hash, uri)
+    }
+
+    /**
+     * Set the Groth16 verifying key of the judge's scoring circuit; creates
+     * the scoring verifier on first use
+     * Only callable by the config admin
+     */
+    pub fn set_scoring_verifier(
+        ctx: Context<SetScoringVerifier>,
+        key: ScoringVerifyingKey,
+    ) -> Result<()> {
+        instructions::set_scoring_verifier::handler(ctx, key)
+    }
+
+    /**
+     * Check a Groth16 proof that the pool's leaderboard root follows from the
+     * scoring inputs committed in its transcript, and mark the pool verified
+     * Callable by anyone
+     */
+    pub fn verify_scoring_proof(
+        ctx: Context<VerifyScoringProof>,
+        proof: groth16::ScoringProof,
+    ) -> Result<()> {
+        instructions::verify_scoring_proof::handler(ctx, proof)
+    }
+
+    /**
+     * Post the pool's leaderboard root, winners hash and totals as a Wormhole
+     * message so other chains can verify the results; once per pool, after its
+     * payouts settle, anyone can call
+     */
+    pub fn attest_results(ctx: Context<AttestResults>) -> Result<()> {
+        instructions::attest_results::handler(ctx)
+    }
+
+    /**
+     * Pay the signer their rank's prize, or their equal share on airdrop pools,
+     * by proving (wallet, rank, score) against the finalized leaderboard root;
+     * once per wallet per pool
+     * Requires FEATURE_CLAIMS; the first claim takes the pool's cuts
+     */
+    pub fn claim_prize(
+        ctx: Context<ClaimPrize>,
+        rank: u32,
+        score: u8,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_prize::handler(ctx, rank, score, proof)
+    }
+
+    /**
+     * Set the most lamports one claim is rebated for the rent it paid, and add
+     * `amount` lamports to the pool's rent rebate reserve; what is left goes
+     * back to the authority when the pool closes
+     * Only callable by the authority
+     */
+    pub fn fund_rent_rebates(ctx: Context<FundRentRebates>, cap: u64, amount: u64) -> Result<()> {
+        instructions::fund_rent_rebates::handler(ctx, cap, amount)
+    }
+
+    /**
+     * claim_prize for several pools of one mint in one transaction, e.g. the
+     * weeks a player missed; remaining accounts hold each claim's pool, vault,
+     * receipt, week stats and balance snapshot
+     * Only callable by the winner
+     */
+    pub fn claim_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMany<'info>>,
+        claims: Vec<ClaimRequest>,
+    ) -> Result<()> {
+        instructions::claim_many::handler(ctx, claims)
+    }
+
+    /**
+     * Pay the whole jackpot to one of the entries finalize_entry counted as
+     * qualifying, drawn by ORAO VRF when several qualify
+     * Only callable by the pool's distributor role, once per pool
+     */
+    pub fn award_jackpot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AwardJackpot<'info>>,
+    ) -> Result<()> {
+        instructions::award_jackpot::handler(ctx)
+    }
+
+    /**
+     * Create the pool's empty WinnerList, for winner lists too large to pass
+     * to distribute_prizes as instruction data
+     * Only callable by the pool's distributor role
+     */
+    pub fn open_winner_list(ctx: Context<OpenWinnerList>) -> Result<()> {
+        instructions::open_winner_list::handler(ctx)
+    }
+
+    /**
+     * Append packed ListedWinner records to the pool's WinnerList, in rank order
+     * Only callable by the pool's distributor role
+     */
+    pub fn append_winners(ctx: Context<AppendWinners>, entries: Vec<u8>) -> Result<()> {
+        instructions::append_winners::handler(ctx, entries)
+    }
+
+    /**
+     * Close the pool's WinnerList, returning its rent, e.g. to start over
+     * Only callable by the pool's distributor role
+     */
+    pub fn close_winner_list(ctx: Context<CloseWinnerList>) -> Result<()> {
+        instructions::close_winner_list::handler(ctx)
+    }
+
+    /**
+     * Distribute prizes to winners based on their rank
+     * Only callable by the pool's distributor role, or by anyone right after an
+     * ed25519 sig-verify of the config's enclave signer over the winners;
+     * `nonce` must equal the pool's current distribution_nonce, which is
+     * bumped on success
+     */
+    pub fn distribute_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
         winners: Vec<WinnerEntry>,
+        nonce: u64,
     ) -> Result<()> {
-        require!(
-            ctx.accounts.prize_pool.authority == ctx.accounts.authority.key(),
-            ErrorCode::Unauthorized
-        );
-
-        let prize_pool_account = &ctx.accounts.prize_pool_token_account;
-        let total_available = prize_pool_account.amount;
-        
-        require!(total_available > 0, ErrorCode::InsufficientFunds);
-
-        let prize_pool = &mut ctx.accounts.prize_pool;
-        
-        // Validate all winners before distributing
-        for winner in &winners {
-            require!(winner.rank > 0 && winner.rank <= 10, ErrorCode::InvalidRank);
-            require!(winner.score >= 80, ErrorCode::ScoreTooLow);
-        }
-
-        // Calculate and transfer prizes
-        for winner in &winners {
-            let prize_amount = calculate_prize(winner.rank, total_available);
-            
-            if prize_amount > 0 {
-                // Transfer tokens from prize pool to winner
-                let seeds = &[
-                    b"prize_pool",
-                    prize_pool.week_id.as_bytes(),
-                    &[prize_pool.bump],
-                ];
-                let signer = &[&seeds[..]];
-
-                let cpi_accounts = Transfer {
-                    from: ctx.accounts.prize_pool_token_account.to_account_info(),
-                    to: ctx.accounts.winner_token_account.to_account_info(),
-                    authority: prize_pool.to_account_info(),
-                };
-                let cpi_program = ctx.accounts.token_program.to_account_info();
-                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-
-                token::transfer(cpi_ctx, prize_amount)?;
-                
-                prize_pool.total_distributed += prize_amount;
-                
-                emit!(PrizeDistributed {
-                    winner: winner.wallet,
-                    rank: winner.rank,
-                    score: winner.score,
-                    amount: prize_amount,
-                    week_id: prize_pool.week_id.clone(),
-                });
-            }
-        }
-
-        Ok(())
-    }
-
-    /**
-     * Close prize pool and return remaining funds to authority
-     * Only callable after distribution is complete
+        instructions::distribute_prizes::handler(ctx, winners, nonce)
+    }
+
+    /**
+     * Deliver the `index`th payout distribution pass `nonce` left unpaid,
+     * once its owner has a token account that can receive it
+     * Callable by anyone; the destination must belong to the leg's owner
+     */
+    pub fn retry_unpaid(ctx: Context<RetryUnpaid>, nonce: u64, index: u8) -> Result<()> {
+        instructions::retry_unpaid::handler(ctx, nonce, index)
+    }
+
+    /**
+     * Register the signer's team and how its prizes are split
+     */
+    pub fn register_team(ctx: Context<RegisterTeam>, members: Vec<TeamMember>) -> Result<()> {
+        instructions::register_team::handler(ctx, members)
+    }
+
+    /**
+     * Replace the team's members and shares
+     * Only callable by the captain
+     */
+    pub fn update_team(ctx: Context<UpdateTeam>, members: Vec<TeamMember>) -> Result<()> {
+        instructions::update_team::handler(ctx, members)
+    }
+
+    /**
+     * Create the signer's player profile
+     */
+    pub fn create_player_profile(ctx: Context<CreatePlayerProfile>) -> Result<()> {
+        instructions::create_player_profile::handler(ctx)
+    }
+
+    /**
+     * Route `split_bps` of the player's future prizes to `recipient`
+     * Pass None to stop splitting
+     */
+    pub fn set_prize_split(
+        ctx: Context<SetPrizeSplit>,
+        recipient: Option<Pubkey>,
+        split_bps: u16,
+    ) -> Result<()> {
+        instructions::set_prize_split::handler(ctx, recipient, split_bps)
+    }
+
+    /**
+     * Pay the player's future solo prizes to `payout_wallet`, e.g. a cold wallet
+     * Pass None to be paid directly again
+     */
+    pub fn set_payout_wallet(
+        ctx: Context<SetPayoutWallet>,
+        payout_wallet: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_payout_wallet::handler(ctx, payout_wallet)
+    }
+
+    /**
+     * Pay `wallet`'s future solo prizes to `payout_wallet`, authorized by an
+     * ed25519 signature from `wallet` in the preceding instruction
+     * Callable by anyone, so custodial wallets can use a relayer
+     */
+    pub fn authorize_payout_wallet(
+        ctx: Context<AuthorizePayoutWallet>,
+        wallet: Pubkey,
+        payout_wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::authorize_payout_wallet::handler(ctx, wallet, payout_wallet)
+    }
+
+    /**
+     * Publicly announce that the pool's vault will be emptied into
+     * `destination`, executable after EMERGENCY_TIMELOCK_SECONDS
+     * Only callable by the guardian
+     */
+    pub fn announce_emergency_withdraw(ctx: Context<AnnounceEmergencyWithdraw>) -> Result<()> {
+        instructions::announce_emergency_withdraw::handler(ctx)
+    }
+
+    /**
+     * Move the vault balance, less the unpaid and bonus reserves, to the
+     * announced destination once the timelock has elapsed
+     * Only callable by the guardian
+     */
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        instructions::emergency_withdraw::handler(ctx)
+    }
+
+    /**
+     * Fold the pool's claim receipts, passed as (claim_record, winner) pairs,
+     * into its WeekArchive and close them, refunding rent to the winners;
+     * claims stay closed afterwards
+     * Only callable by the pool's closer role, once payouts have started and
+     * any claim window has ended
+     */
+    pub fn archive_week<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ArchiveWeek<'info>>,
+    ) -> Result<()> {
+        instructions::archive_week::handler(ctx)
+    }
+
+    /**
+     * Once the claim deadline has passed, take the pool's cuts if nobody
+     * claimed, send what the winners left unclaimed to the treasury, and go
+     * through `leaves` in ascending rank: a claimed leaf's receipt is archived
+     * and closed, an unclaimed one is reported with PrizeExpired. Runs before
+     * archive_week; later calls only go through more leaves
+     * Callable by anyone
+     */
+    pub fn sweep_expired<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepExpired<'info>>,
+        leaves: Vec<SweptLeaf>,
+    ) -> Result<()> {
+        instructions::sweep_expired::handler(ctx, leaves)
+    }
+
+    /**
+     * Close registrations of an archived pool, passed as (registration,
+     * player) pairs, refunding their rent to the players
+     * Only callable by the pool's closer role
+     */
+    pub fn close_registrations<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseRegistrations<'info>>,
+    ) -> Result<()> {
+        instructions::close_registrations::handler(ctx)
+    }
+
+    /**
+     * Close an emptied prize pool and return its rent to the authority
+     * Only callable by the pool's closer role; refused while the vault holds
+     * tokens, votes are escrowed or entry bonds are held
      */
     pub fn close_prize_pool(ctx: Context<ClosePrizePool>) -> Result<()> {
-        require!(
-            ctx.accounts.prize_pool.authority == ctx.accounts.authority.key(),
-            ErrorCode::Unauthorized
-        );
-
-        // Transfer remaining tokens back to authority
-        let remaining = ctx.accounts.prize_pool_token_account.amount;
-        if remaining > 0 {
-            let seeds = &[
-                b"prize_pool",
-                ctx.accounts.prize_pool.week_id.as_bytes(),
-                &[ctx.accounts.prize_pool.bump],
-            ];
-            let signer = &[&seeds[..]];
-
-            let cpi_accounts = Transfer {
-                from: ctx.accounts.prize_pool_token_account.to_account_info(),
-                to: ctx.accounts.authority_token_account.to_account_info(),
-                authority: ctx.accounts.prize_pool.to_account_info(),
-            };
-            let cpi_program = ctx.accounts.token_program.to_account_info();
-            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-
-            token::transfer(cpi_ctx, remaining)?;
-        }
-
-        Ok(())
+        instructions::close_prize_pool::handler(ctx)
     }
-}
 
-/**
- * Calculate prize amount based on rank
- * 1st: 50%, 2nd: 20%, 3rd: 10%, 4th-10th: 20% / 7
- */
-fn calculate_prize(rank: u8, total: u64) -> u64 {
-    match rank {
-        1 => total * 50 / 100,        // 50%
-        2 => total * 20 / 100,        // 20%
-        3 => total * 10 / 100,        // 10%
-        4..=10 => total * 20 / 100 / 7, // ~2.86% each
-        _ => 0,
+    /**
+     * Close an emptied, archived prize pool once the config's retention period
+     * has passed, returning its rent to the authority
+     * Callable by anyone
+     */
+    pub fn auto_close_prize_pool(ctx: Context<AutoClosePrizePool>) -> Result<()> {
+        instructions::auto_close_prize_pool::handler(ctx)
     }
-}
 
-#[derive(Accounts)]
-#[instruction(week_id: String)]
-pub struct InitializePrizePool<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + PrizePool::LEN,
-        seeds = [b"prize_pool", week_id.as_bytes()],
-        bump
-    )]
-    pub prize_pool: Account<'info, PrizePool>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /**
+     * Open a parimutuel market on who finishes rank 1 of a pool, taking bets
+     * until `closes_at` with `rake_bps` of the losing stakes going to the treasury
+     * Only callable by the authority, before the leaderboard is finalized
+     */
+    pub fn open_bet_market(
+        ctx: Context<OpenBetMarket>,
+        closes_at: i64,
+        rake_bps: u16,
+    ) -> Result<()> {
+        instructions::open_bet_market::handler(ctx, closes_at, rake_bps)
+    }
 
-#[derive(Accounts)]
-pub struct DistributePrizes<'info> {
-    #[account(mut)]
-    pub prize_pool: Account<'info, PrizePool>,
-    
-    #[account(mut)]
-    pub prize_pool_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub winner_token_account: Account<'info, TokenAccount>,
-    
-    pub authority: Signer<'info>,
-    
-    pub token_program: Program<'info, Token>,
-}
+    /**
+     * Stake `amount` on a registered `player` winning the pool; a bettor can
+     * add to their bet but only ever back one player per market
+     */
+    pub fn place_bet(ctx: Context<PlaceBet>, player: Pubkey, amount: u64) -> Result<()> {
+        instructions::place_bet::handler(ctx, player, amount)
+    }
 
-#[derive(Accounts)]
-pub struct ClosePrizePool<'info> {
-    #[account(
-        mut,
-        close = authority,
-        has_one = authority
-    )]
-    pub prize_pool: Account<'info, PrizePool>,
-    
-    #[account(mut)]
-    pub prize_pool_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub authority_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub token_program: Program<'info, Token>,
-}
+    /**
+     * Settle a closed market on the leaderboard's rank 1, proven against the
+     * finalized root, and pay the rake to the treasury; refused once the
+     * market's settle deadline has passed
+     * Only callable by the authority
+     */
+    pub fn settle_bets(
+        ctx: Context<SettleBets>,
+        winner: Pubkey,
+        score: u8,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::settle_bets::handler(ctx, winner, score, proof)
+    }
 
-#[account]
-pub struct PrizePool {
-    pub authority: Pubkey,
-    pub week_id: String,
-    pub total_distributed: u64,
-    pub bump: u8,
-}
+    /**
+     * Pay a winning bet its share of the payout pool, or refund any bet when
+     * nobody backed the winner, closing the bet account
+     */
+    pub fn claim_bet_winnings(ctx: Context<ClaimBetWinnings>) -> Result<()> {
+        instructions::claim_bet_winnings::handler(ctx)
+    }
 
-impl PrizePool {
-    pub const LEN: usize = 32 + // authority
-                           64 + // week_id (String with max length)
-                           8 +  // total_distributed
-                           1;   // bump
-}
+    /**
+     * Refund an unsettled bet in full, closing it, once the pool's week is
+     * cancelled by an emergency withdrawal announcement, the backed player's
+     * entry bond is slashed, or the market's settle deadline has passed
+     */
+    pub fn refund_bet(ctx: Context<RefundBet>) -> Result<()> {
+        instructions::refund_bet::handler(ctx)
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct WinnerEntry {
-    pub wallet: Pubkey,
-    pub rank: u8,
-    pub score: u8,
-}
+    /**
+     * Check the pool's vault against its counters and emit AccountingOk or
+     * AccountingMismatch; never fails on a mismatch
+     * Callable by anyone, e.g. a watchdog bot
+     */
+    pub fn verify_accounting(ctx: Context<VerifyAccounting>) -> Result<()> {
+        instructions::verify_accounting::handler(ctx)
+    }
 
-#[event]
-pub struct PrizeDistributed {
-    pub winner: Pubkey,
-    pub rank: u8,
-    pub score: u8,
-    pub amount: u64,
-    pub week_id: String,
-}
+    /**
+     * Return what `rank` would be paid if the pool paid out now, before stake
+     * and streak bonuses, which depend on the winner; meant for simulation.
+     * Airdrop pools ignore `rank` and return the share of a 1x qualifier.
+     */
+    pub fn preview_prize(ctx: Context<PreviewPrize>, rank: u8) -> Result<PrizePreview> {
+        instructions::preview_prize::handler(ctx, rank)
+    }
 
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Unauthorized: Only authority can call this function")]
-    Unauthorized,
-    #[msg("Invalid rank: Rank must be between 1 and 10")]
-    InvalidRank,
-    #[msg("Score too low: Winner must have at least 80 points")]
-    ScoreTooLow,
-    #[msg("Insufficient funds in prize pool")]
-    InsufficientFunds,
-}
+    /**
+     * Return the pool's balances and where it is in its week; meant for
+     * simulation
+     */
+    pub fn pool_status(ctx: Context<GetPoolStatus>) -> Result<PoolStatus> {
+        instructions::pool_status::handler(ctx)
+    }
 
+    /**
+     * Return the numbered week the clock is in and its bounds; meant for
+     * simulation, to find the week_index initialize_weekly_pool expects
+     */
+    pub fn current_week(ctx: Context<CurrentWeek>) -> Result<WeekInfo> {
+        instructions::current_week::handler(ctx)
+    }
+}
@@ -0,0 +1,334 @@
+//! Prize split math, kept free of account types so it can be tested directly.
+
+use crate::state::{HappyHour, QualificationTier, ScoreCurve, StakeTier, SECONDS_PER_DAY};
+
+/// Highest rank that receives a share of the pool
+pub const MAX_RANK: u8 = 10;
+
+/// Ranks that count as a podium finish for the win cooldown
+pub const PODIUM_RANK: u8 = 3;
+
+/// Top of the normalized score scale
+pub const MAX_SCORE: u8 = 100;
+
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Highest prize multiplier staking can earn (2x)
+pub const MAX_STAKE_MULTIPLIER_BPS: u16 = 20_000;
+
+/// Highest sponsored bonus multiplier a pool can run (3x)
+pub const MAX_BONUS_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Highest score multiplier a happy hour can apply (2x)
+pub const MAX_HAPPY_HOUR_MULTIPLIER_BPS: u16 = 20_000;
+
+/// Highest share multiplier a qualification tier can earn (3x)
+pub const MAX_TIER_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Fixed-point scale of Config.reward_per_share
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/**
+ * Calculate prize amount based on rank
+ * 1st: 50%, 2nd: 20%, 3rd: 10%, 4th-10th: 20% / 7
+ *
+ * Intermediate products are computed in u128 so the split stays exact
+ * for any pool size up to u64::MAX.
+ */
+pub fn calculate_prize(rank: u8, total: u64) -> u64 {
+    let total = total as u128;
+    let amount = match rank {
+        1 => total * 50 / 100,        // 50%
+        2 => total * 20 / 100,        // 20%
+        3 => total * 10 / 100,        // 10%
+        4..=MAX_RANK => total * 20 / 100 / 7, // ~2.86% each
+        _ => 0,
+    };
+    // Every share is a fraction of `total`, so this never truncates
+    amount as u64
+}
+
+/**
+ * Prize for `rank` under a pool's own split
+ * `rank_shares_bps[i]` is what rank i + 1 receives; an empty split
+ * falls back to the default curve of calculate_prize.
+ */
+pub fn calculate_prize_with(rank: u8, total: u64, rank_shares_bps: &[u16]) -> u64 {
+    if rank_shares_bps.is_empty() {
+        return calculate_prize(rank, total);
+    }
+    match (rank as usize).checked_sub(1).and_then(|i| rank_shares_bps.get(i)) {
+        Some(&bps) => bps_of(total, bps),
+        None => 0,
+    }
+}
+
+/// Bit of `rank` (1..=MAX_RANK) in PrizePool::paid_ranks
+pub fn rank_bit(rank: u8) -> u16 {
+    1 << (rank - 1)
+}
+
+/// Highest rank set in a paid_ranks bitmap, 0 when it is empty
+pub fn highest_paid_rank(paid_ranks: u16) -> u8 {
+    (u16::BITS - paid_ranks.leading_zeros()) as u8
+}
+
+/**
+ * The (year, week) of a "YYYY-Www" week id, which orders weeks in time
+ * Returns None if `week_id` is not a valid ISO week.
+ */
+pub fn parse_week_id(week_id: &str) -> Option<(i32, u8)> {
+    let (year, week) = week_id.split_once("-W")?;
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if year.len() != 4 || week.len() != 2 || !is_digits(year) || !is_digits(week) {
+        return None;
+    }
+    let year: i32 = year.parse().ok()?;
+    let week: u8 = week.parse().ok()?;
+    if week == 0 || week > iso_weeks_in_year(year) {
+        return None;
+    }
+    Some((year, week))
+}
+
+/**
+ * The ISO week before `week_id`, both in "YYYY-Www" form
+ * Returns None if `week_id` is not a valid ISO week.
+ */
+pub fn previous_week_id(week_id: &str) -> Option<String> {
+    let (year, week) = parse_week_id(week_id)?;
+    let (year, week) = if week == 1 {
+        (year - 1, iso_weeks_in_year(year - 1))
+    } else {
+        (year, week - 1)
+    };
+    Some(format!("{:04}-W{:02}", year, week))
+}
+
+/// 53 for years starting on a Thursday (or leap years starting on a Wednesday), else 52
+pub fn iso_weeks_in_year(year: i32) -> u8 {
+    let p = |y: i32| (y + y / 4 - y / 100 + y / 400) % 7;
+    if p(year) == 4 || p(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// Length of a numbered week, see Config::week_epoch_start
+pub const WEEK_SECONDS: i64 = 7 * SECONDS_PER_DAY;
+
+/// Index of the week `now` falls in, counting from 0 at `epoch_start`;
+/// None before the epoch or past u32 weeks
+pub fn week_index_at(epoch_start: i64, now: i64) -> Option<u32> {
+    let elapsed = now.checked_sub(epoch_start).filter(|elapsed| *elapsed >= 0)?;
+    u32::try_from(elapsed / WEEK_SECONDS).ok()
+}
+
+/// Unix timestamp week `week_index` starts at
+pub fn week_start(epoch_start: i64, week_index: u32) -> i64 {
+    epoch_start + week_index as i64 * WEEK_SECONDS
+}
+
+/**
+ * The ISO week containing `timestamp`, in "YYYY-Www" form
+ * Weeks exactly WEEK_SECONDS apart always land in consecutive ISO weeks,
+ * so numbered pools keep previous_week_id working for streaks and cooldowns.
+ */
+pub fn iso_week_id(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    // 1970-01-01 was a Thursday, and an ISO week belongs to the year of its Thursday
+    let thursday = days - (days + 3).rem_euclid(7) + 3;
+    let mut year = 1970 + thursday.div_euclid(365);
+    while days_before_year(year) > thursday {
+        year -= 1;
+    }
+    while days_before_year(year + 1) <= thursday {
+        year += 1;
+    }
+    let week = (thursday - days_before_year(year)) / 7 + 1;
+    format!("{:04}-W{:02}", year, week)
+}
+
+/// Days from 1970-01-01 to January 1st of `year`
+fn days_before_year(year: i64) -> i64 {
+    let leap_days = |y: i64| y / 4 - y / 100 + y / 400;
+    365 * (year - 1970) + leap_days(year - 1) - leap_days(1969)
+}
+
+/**
+ * Map a raw judge score in 0..=raw_max onto 0..=MAX_SCORE
+ * SquareRoot lifts the low end of a harsh rubric, Square spreads out the
+ * top of a generous one. Caller guarantees raw <= raw_max and raw_max > 0.
+ */
+pub fn normalize_score(raw: u16, raw_max: u16, curve: ScoreCurve) -> u8 {
+    let (raw, raw_max, max) = (raw as u64, raw_max as u64, MAX_SCORE as u64);
+    let score = match curve {
+        ScoreCurve::Linear => raw * max / raw_max,
+        ScoreCurve::SquareRoot => isqrt(raw * max * max / raw_max),
+        ScoreCurve::Square => raw * raw * max / (raw_max * raw_max),
+    };
+    score as u8
+}
+
+/**
+ * Mean of `scores` without the single highest and lowest, rounded down
+ * None for fewer than three scores
+ */
+pub fn trimmed_mean(scores: &[u16]) -> Option<u16> {
+    if scores.len() < 3 {
+        return None;
+    }
+    let mut sorted = scores.to_vec();
+    sorted.sort_unstable();
+    let kept = &sorted[1..sorted.len() - 1];
+    let sum: u32 = kept.iter().map(|&score| score as u32).sum();
+    Some((sum / kept.len() as u32) as u16)
+}
+
+/// Multiplier of the happy hour containing `now` (start inclusive, end exclusive), or 1x
+pub fn happy_hour_multiplier_bps(now: i64, happy_hours: &[HappyHour]) -> u16 {
+    happy_hours
+        .iter()
+        .find(|window| (window.start..window.end).contains(&now))
+        .map_or(BPS_DENOMINATOR, |window| window.multiplier_bps)
+}
+
+/// `score` scaled by `multiplier_bps`, never above MAX_SCORE
+pub fn boosted_score(score: u8, multiplier_bps: u16) -> u8 {
+    let boosted = score as u32 * multiplier_bps as u32 / BPS_DENOMINATOR as u32;
+    boosted.min(MAX_SCORE as u32) as u8
+}
+
+/// judge_weight * score + vote_weight * votes, saturating at u64::MAX
+pub fn hybrid_score(score: u8, votes: u64, judge_weight: u16, vote_weight: u16) -> u64 {
+    let total = score as u128 * judge_weight as u128 + votes as u128 * vote_weight as u128;
+    total.min(u64::MAX as u128) as u64
+}
+
+/// Votes counted for `weight` tokens: the weight itself, or its square root when quadratic
+pub fn counted_votes(weight: u64, quadratic: bool) -> u64 {
+    if quadratic {
+        isqrt(weight)
+    } else {
+        weight
+    }
+}
+
+/**
+ * Prize multiplier for `staked` tokens: the highest tier it reaches, or 1x
+ * below every tier. Never above MAX_STAKE_MULTIPLIER_BPS.
+ */
+pub fn stake_multiplier_bps(staked: u64, stake_tiers: &[StakeTier]) -> u16 {
+    stake_tiers
+        .iter()
+        .filter(|tier| staked >= tier.min_stake)
+        .map(|tier| tier.multiplier_bps)
+        .max()
+        .unwrap_or(BPS_DENOMINATOR)
+        .clamp(BPS_DENOMINATOR, MAX_STAKE_MULTIPLIER_BPS)
+}
+
+/// Share multiplier of the highest tier `score` reaches, or 1x below every tier
+pub fn tier_multiplier_bps(score: u8, tiers: &[QualificationTier]) -> u16 {
+    tiers
+        .iter()
+        .filter(|tier| score >= tier.threshold)
+        .map(|tier| tier.multiplier_bps)
+        .max()
+        .unwrap_or(BPS_DENOMINATOR)
+}
+
+/// The part of `amount` that `weight` out of `total_weight` earns, rounded down
+pub fn weighted_share(amount: u64, weight: u64, total_weight: u64) -> u64 {
+    if total_weight == 0 {
+        return 0;
+    }
+    (amount as u128 * weight as u128 / total_weight as u128) as u64
+}
+
+/// What `multiplier_bps` adds on top of `amount`
+pub fn stake_bonus(amount: u64, multiplier_bps: u16) -> u64 {
+    bps_of(amount, multiplier_bps.saturating_sub(BPS_DENOMINATOR))
+}
+
+/// Growth of reward_per_share when `reward` is shared across `total_staked` tokens
+pub fn reward_per_share_increase(reward: u64, total_staked: u64) -> u128 {
+    reward as u128 * REWARD_PRECISION / total_staked as u128
+}
+
+/// Rewards `staked` tokens have earned at `reward_per_share`, rounded down
+pub fn accrued_rewards(staked: u64, reward_per_share: u128) -> u128 {
+    (staked as u128).saturating_mul(reward_per_share) / REWARD_PRECISION
+}
+
+/// Largest r with r * r <= n
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// `bps` basis points of `amount`, rounded down
+pub fn bps_of(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+/**
+ * Split `amount` into parts proportional to `shares_bps`
+ * Shares must sum to BPS_DENOMINATOR; any rounding dust goes to the
+ * first part so the parts always add up to `amount`.
+ */
+pub fn split_by_bps(amount: u64, shares_bps: &[u16]) -> Vec<u64> {
+    let mut parts: Vec<u64> = shares_bps
+        .iter()
+        .map(|&bps| bps_of(amount, bps))
+        .collect();
+    let assigned: u64 = parts.iter().sum();
+    if let Some(first) = parts.first_mut() {
+        *first += amount - assigned;
+    }
+    parts
+}
+
+/// A winning bet's share of `payout_pool`, pro-rata to its `stake` of `winning_stake`
+pub fn parimutuel_payout(stake: u64, payout_pool: u64, winning_stake: u64) -> u64 {
+    if winning_stake == 0 {
+        return 0;
+    }
+    (stake as u128 * payout_pool as u128 / winning_stake as u128) as u64
+}
+
+/**
+ * Base units of a token with `decimals` worth `usd_cents`, at a price of
+ * `price * 10^exponent` USD per whole token, rounded down
+ * None for non-positive prices or amounts that don't fit a u64
+ */
+pub fn usd_to_tokens(usd_cents: u64, price: i64, exponent: i32, decimals: u8) -> Option<u64> {
+    if price <= 0 {
+        return None;
+    }
+    // usd_cents / 100 / (price * 10^exponent) * 10^decimals
+    let scale = decimals as i32 - exponent;
+    let mut numerator = usd_cents as u128;
+    let mut denominator = price as u128 * 100;
+    if scale >= 0 {
+        numerator = numerator.checked_mul(10u128.checked_pow(scale as u32)?)?;
+    } else {
+        denominator = denominator.checked_mul(10u128.checked_pow(scale.unsigned_abs())?)?;
+    }
+    u64::try_from(numerator / denominator).ok()
+}
+
+/// The confidence interval is at most `max_conf_bps` of the price
+pub fn price_within_confidence(price: i64, conf: u64, max_conf_bps: u16) -> bool {
+    price > 0 && conf as u128 * BPS_DENOMINATOR as u128 <= price as u128 * max_conf_bps as u128
+}
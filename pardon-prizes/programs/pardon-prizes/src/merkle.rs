@@ -0,0 +1,27 @@
+//! Merkle tree over the final leaderboard, as published by
+//! finalize_leaderboard and proven against by claim_prize.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Prefix of leaf hashes, so an inner node can never pass as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Prefix of inner node hashes
+const NODE_PREFIX: &[u8] = &[1];
+
+/// sha256(0x00 || wallet || rank (u32 LE) || score)
+pub fn leaderboard_leaf(wallet: &Pubkey, rank: u32, score: u8) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref(), &rank.to_le_bytes(), &[score]]).to_bytes()
+}
+
+/// sha256(0x01 || min(a, b) || max(a, b)), so proofs need no left/right bits
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, low, high]).to_bytes()
+}
+
+/// Folds `proof` (sibling hashes, leaf to root) over `leaf` and compares with `root`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
@@ -0,0 +1,92 @@
+//! Reads the few Metaplex Token Metadata fields entry checks need, without
+//! pulling in the full mpl-token-metadata crate.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+pub const METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Metadata PDA of `mint`: ["metadata", program, mint] under the metadata program
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Borsh reader that gives up (None) instead of erroring on short data
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let (head, tail) = (self.data.get(..len)?, self.data.get(len..)?);
+        self.data = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4).map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    fn skip_string(&mut self) -> Option<()> {
+        let len = self.u32()? as usize;
+        self.take(len).map(|_| ())
+    }
+
+    /// Skips a borsh Option<T> whose payload is `len` bytes
+    fn skip_option(&mut self, len: usize) -> Option<()> {
+        match self.u8()? {
+            0 => Some(()),
+            _ => self.take(len).map(|_| ()),
+        }
+    }
+}
+
+/**
+ * Layout up to the collection field:
+ * key (u8), update_authority (32), mint (32), name, symbol, uri (strings),
+ * seller_fee_basis_points (u16), creators (Option<Vec<34-byte Creator>>),
+ * primary_sale_happened (bool), is_mutable (bool), edition_nonce (Option<u8>),
+ * token_standard (Option<u8>), collection (Option<{ verified: bool, key }>)
+ */
+pub struct MetadataInfo {
+    pub mint: Pubkey,
+    /// Collection key, only if the collection authority verified it
+    pub verified_collection: Option<Pubkey>,
+}
+
+/// Parses a metadata account's data, None if it isn't laid out as expected
+pub fn parse_metadata(data: &[u8]) -> Option<MetadataInfo> {
+    let mut cursor = Cursor { data };
+    cursor.take(1 + 32)?;
+    let mint = Pubkey::try_from(cursor.take(32)?).ok()?;
+    cursor.skip_string()?;
+    cursor.skip_string()?;
+    cursor.skip_string()?;
+    cursor.take(2)?;
+    if cursor.u8()? != 0 {
+        let creators = cursor.u32()? as usize;
+        cursor.take(creators.checked_mul(34)?)?;
+    }
+    cursor.take(2)?;
+    cursor.skip_option(1)?;
+    cursor.skip_option(1)?;
+
+    // Metadata written before collections existed simply ends here
+    let verified_collection = match cursor.u8() {
+        Some(1) => {
+            let verified = cursor.u8()? == 1;
+            let key = Pubkey::try_from(cursor.take(32)?).ok()?;
+            verified.then_some(key)
+        }
+        _ => None,
+    };
+    Some(MetadataInfo { mint, verified_collection })
+}
@@ -0,0 +1,48 @@
+//! Reads Pyth price updates posted by the pull-oracle receiver, so prizes
+//! advertised in USD are converted at the price when they are paid.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+/// Pyth Solana receiver, same address on mainnet-beta and devnet
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of the receiver's PriceUpdateV2 account
+pub const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// VerificationLevel::Full, i.e. checked against a guardian quorum
+const VERIFICATION_FULL: u8 = 1;
+
+pub struct PriceFeed {
+    pub feed_id: [u8; 32],
+    /// Price is `price * 10^exponent` USD per whole token
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+/**
+ * PriceUpdateV2 layout: discriminator (8), write_authority (32),
+ * verification_level (u8 tag, plus num_signatures (u8) when Partial),
+ * then the price message: feed_id (32), price (i64), conf (u64),
+ * exponent (i32), publish_time (i64), ...
+ * Returns None unless the update is fully verified and laid out as expected.
+ */
+pub fn parse_price_update(data: &[u8]) -> Option<PriceFeed> {
+    if data.get(..8)? != PRICE_UPDATE_V2_DISCRIMINATOR {
+        return None;
+    }
+    if *data.get(8 + 32)? != VERIFICATION_FULL {
+        return None;
+    }
+    let message = data.get(8 + 32 + 1..)?;
+    let field = |start: usize, len: usize| message.get(start..start + len);
+    Some(PriceFeed {
+        feed_id: field(0, 32)?.try_into().ok()?,
+        price: i64::from_le_bytes(field(32, 8)?.try_into().ok()?),
+        conf: u64::from_le_bytes(field(40, 8)?.try_into().ok()?),
+        exponent: i32::from_le_bytes(field(48, 4)?.try_into().ok()?),
+        publish_time: i64::from_le_bytes(field(52, 8)?.try_into().ok()?),
+    })
+}
@@ -0,0 +1,1571 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::errors::ErrorCode;
+use crate::math::{
+    accrued_rewards, parse_week_id, previous_week_id, BPS_DENOMINATOR,
+    MAX_HAPPY_HOUR_MULTIPLIER_BPS, MAX_RANK, MAX_SCORE, MAX_STAKE_MULTIPLIER_BPS,
+    MAX_TIER_MULTIPLIER_BPS,
+};
+
+/**
+ * Program-wide settings
+ * Seeds: ["config"]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    /// Set to the program upgrade authority at initialization
+    pub admin: Pubkey,
+    /// Game creator receiving creator_share_bps of each week's pool
+    pub creator: Pubkey,
+    pub creator_share_bps: u16,
+    /// Winners ranked 1..=cooldown_rank must not have placed in the top
+    /// PODIUM_RANK the previous week; 0 disables the cooldown
+    pub cooldown_rank: u8,
+    /// Weight of the normalized judge score in an entry's final_score
+    pub judge_weight: u16,
+    /// Weight of community votes in an entry's final_score
+    pub vote_weight: u16,
+    /// Count sqrt(tokens) per vote instead of tokens, so whales don't dominate
+    pub quadratic_votes: bool,
+    /// Project token players stake for a prize boost, default until set_stake_tiers
+    pub stake_mint: Pubkey,
+    /// Stake thresholds and the multiplier each earns; empty disables boosts
+    #[max_len(MAX_STAKE_TIERS)]
+    pub stake_tiers: Vec<StakeTier>,
+    /// Tokens held in the stake vault, seeds ["stake_vault"]
+    pub total_staked: u64,
+    /// Share of each pool, after the creator royalty, paid to stakers
+    pub staker_share_bps: u16,
+    /// Staker rewards per staked token since launch, scaled by REWARD_PRECISION
+    pub reward_per_share: u128,
+    /// Only key that can announce and execute emergency withdrawals, default if unset
+    pub guardian: Pubkey,
+    /// Mints fund_pool accepts, e.g. native and bridged USDC; empty allows pool mints only
+    #[max_len(MAX_FUNDING_MINTS)]
+    pub funding_mints: Vec<Pubkey>,
+    /// Wallet receiving the rake on entry-fee pools, default if unset
+    pub treasury: Pubkey,
+    /// Mint of the progressive jackpot; only pools in it feed the jackpot
+    pub jackpot_mint: Pubkey,
+    /// Share of each pool in jackpot_mint, after the creator and staker cuts,
+    /// fed to the jackpot
+    pub jackpot_bps: u16,
+    /// Mint of the streak vault, seeds ["streak_vault"]; only pools in it pay streak bonuses
+    pub streak_mint: Pubkey,
+    /// Extra on a streaking winner's prize, paid from the streak vault
+    pub streak_bonus_bps: u16,
+    /// Consecutive placing weeks, this one included, that earn the bonus
+    pub min_streak_weeks: u16,
+    /// Mint of the insurance vault, seeds ["insurance_vault"]; only pools in it feed it
+    pub insurance_mint: Pubkey,
+    /// Share of each pool in insurance_mint, after the creator, staker and
+    /// jackpot cuts, set aside for insurance claims
+    pub insurance_bps: u16,
+    /// Paid out of the insurance vault by pay_insurance_claim so far
+    pub insurance_paid: u64,
+    /// Anyone may close an emptied pool this long after it was archived, 0 to disable
+    pub auto_close_after_secs: i64,
+    /// Start of week 0 for numbered pools, see week_index_at; 0 until set_week_schedule
+    pub week_epoch_start: i64,
+    /// Split rollover_week gives each next numbered pool; None disables rollover
+    pub pool_defaults: Option<PoolDefaults>,
+    /// distribute_prizes and claim_prize refuse pools without a scoring transcript
+    pub require_scoring_transcripts: bool,
+    /// Judge enclave whose signed winners authorize distribute_prizes, None to disable
+    pub enclave_signer: Option<EnclaveSigner>,
+    /// Programs allowed to CPI into fund_pool and submit_score; empty allows any caller
+    #[max_len(MAX_CPI_CALLERS)]
+    pub cpi_callers: Vec<Pubkey>,
+    /// Latest guardian, treasury and creator changes, at most MAX_AUTHORITY_HISTORY
+    #[max_len(MAX_AUTHORITY_HISTORY)]
+    pub authority_history: Vec<AuthorityChange>,
+    pub bump: u8,
+}
+
+impl Config {
+    /// Pools in `mint` may be created and funded
+    pub fn accepts_mint(&self, mint: &Pubkey) -> bool {
+        self.funding_mints.is_empty() || self.funding_mints.contains(mint)
+    }
+
+    /// `program` may invoke fund_pool and submit_score through CPI
+    pub fn accepts_caller(&self, program: &Pubkey) -> bool {
+        self.cpi_callers.is_empty() || self.cpi_callers.contains(program)
+    }
+
+    /// At most MAX_STAKE_TIERS tiers, ascending in both stake and multiplier,
+    /// each multiplier between 1x and MAX_STAKE_MULTIPLIER_BPS
+    pub fn validate_stake_tiers(stake_tiers: &[StakeTier]) -> Result<()> {
+        require!(
+            stake_tiers.len() <= MAX_STAKE_TIERS,
+            ErrorCode::InvalidStakeTiers
+        );
+        for (i, tier) in stake_tiers.iter().enumerate() {
+            require!(
+                (BPS_DENOMINATOR..=MAX_STAKE_MULTIPLIER_BPS).contains(&tier.multiplier_bps),
+                ErrorCode::InvalidStakeTiers
+            );
+            if let Some(previous) = i.checked_sub(1).map(|j| &stake_tiers[j]) {
+                require!(
+                    tier.min_stake > previous.min_stake
+                        && tier.multiplier_bps >= previous.multiplier_bps,
+                    ErrorCode::InvalidStakeTiers
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Progressive jackpot carried across weeks, fed by a cut of every pool in
+ * the jackpot mint and won outright by an entry reaching the trigger score
+ * Seeds: ["jackpot"]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Jackpot {
+    /// Token account holding the jackpot, seeds ["jackpot_vault"]
+    pub vault: Pubkey,
+    /// Entries scoring at least this qualify for the jackpot
+    pub trigger_score: u8,
+    /// Jackpots paid out since launch
+    pub total_awarded: u64,
+    pub bump: u8,
+}
+
+/// Most mints the funding allowlist can hold
+pub const MAX_FUNDING_MINTS: usize = 4;
+
+/// Most programs the CPI caller allowlist can hold
+pub const MAX_CPI_CALLERS: usize = 4;
+
+/// Most stake tiers the config can hold
+pub const MAX_STAKE_TIERS: usize = 5;
+
+/// Seconds a stake stays locked after each deposit
+pub const STAKE_LOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Seconds between announcing an emergency withdrawal and executing it
+pub const EMERGENCY_TIMELOCK_SECONDS: i64 = 72 * 60 * 60;
+
+/// Seconds winners have to claim once the leaderboard is final
+pub const CLAIM_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Seconds settle_bets has after a bet market closes, then every bet is refundable
+pub const BET_SETTLE_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct StakeTier {
+    /// Smallest stake that reaches this tier
+    pub min_stake: u64,
+    /// Prize multiplier in basis points, 10000 = 1x
+    pub multiplier_bps: u16,
+}
+
+/**
+ * Project tokens a player locked in the stake vault to boost their prizes
+ * Seeds: ["stake", wallet]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    pub wallet: Pubkey,
+    pub amount: u64,
+    /// unstake is refused before this unix timestamp
+    pub locked_until: i64,
+    /// Staker rewards earned but not yet claimed
+    pub pending_rewards: u64,
+    /// Rewards `amount` had already earned at the last settle
+    pub reward_debt: u128,
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    /// Moves rewards earned since the last settle into pending_rewards;
+    /// call before changing `amount`, then reset_reward_debt after
+    pub fn settle(&mut self, reward_per_share: u128) {
+        let earned = accrued_rewards(self.amount, reward_per_share).saturating_sub(self.reward_debt);
+        self.pending_rewards += earned as u64;
+    }
+
+    pub fn reset_reward_debt(&mut self, reward_per_share: u128) {
+        self.reward_debt = accrued_rewards(self.amount, reward_per_share);
+    }
+}
+
+/// Longest version string ProgramState can hold, in bytes
+pub const MAX_VERSION_LEN: usize = 16;
+
+/// Claim-based payouts
+pub const FEATURE_CLAIMS: u64 = 1 << 0;
+/// Raffle distribution mode
+pub const FEATURE_RAFFLES: u64 = 1 << 1;
+/// Pools in Token-2022 mints
+pub const FEATURE_TOKEN_2022: u64 = 1 << 2;
+
+/// Every flag set_feature_flag accepts
+pub const KNOWN_FEATURES: u64 = FEATURE_CLAIMS | FEATURE_RAFFLES | FEATURE_TOKEN_2022;
+
+/**
+ * Deployed logic version and feature flags, so new modes can ship dark and
+ * be switched on without a redeploy
+ * Seeds: ["program_state"]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramState {
+    /// Crate version of the code that last wrote this account
+    #[max_len(MAX_VERSION_LEN)]
+    pub version: String,
+    /// FEATURE_* bits currently enabled
+    pub features: u64,
+    pub bump: u8,
+}
+
+impl ProgramState {
+    /// Version of the running program
+    pub const CURRENT_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+    pub fn is_enabled(&self, feature: u64) -> bool {
+        self.features & feature == feature
+    }
+}
+
+/// Longest week id, e.g. "2024-W45", in bytes; a PDA seed can't be longer
+pub const MAX_WEEK_ID_LEN: usize = 32;
+
+/// Longest category label, e.g. "funniest-pardon", in bytes
+pub const MAX_CATEGORY_LEN: usize = 32;
+
+/// Longest display name a pool can carry, in bytes
+pub const MAX_POOL_NAME_LEN: usize = 64;
+
+/// Longest off-chain metadata URI a pool can carry, in bytes
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+/// How rollover_week sets up the next week's pool; the rest comes from the previous one
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolDefaults {
+    /// Number of paid ranks, 1..=MAX_RANK
+    pub max_winners: u8,
+    /// Share per rank as in PrizePool::rank_shares_bps; empty for the default curve
+    #[max_len(MAX_RANK)]
+    pub rank_shares_bps: Vec<u16>,
+}
+
+/// Seed prefix of pools keyed by a numbered week instead of a week id string
+pub const WEEKLY_POOL_SEED: &[u8] = b"prize_pool_v2";
+
+/**
+ * One prize category for one week
+ * Seeds: ["prize_pool", week_id, category], or for numbered weeks
+ * ["prize_pool_v2", week_index (u32 LE), category]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct PrizePool {
+    /// Owns the pool's settings and hands out its roles
+    pub authority: Pubkey,
+    /// Hot keys for day-to-day operations, the authority's own key by default
+    pub roles: PoolRoles,
+    /// Temporary operator allowed some role-gated instructions until it expires
+    pub delegation: Option<Delegation>,
+    /// Latest role and delegate changes, at most MAX_AUTHORITY_HISTORY
+    #[max_len(MAX_AUTHORITY_HISTORY)]
+    pub authority_history: Vec<AuthorityChange>,
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub week_id: String,
+    /// Week number under Config::week_epoch_start for pools made by
+    /// initialize_weekly_pool, whose week_id is then the ISO week it starts in
+    pub week_index: Option<u32>,
+    /// Category within the week, e.g. "top-score" or "community-choice"
+    #[max_len(MAX_CATEGORY_LEN)]
+    pub category: String,
+    /// Share of the pool per rank, index 0 = rank 1; empty for the default curve
+    #[max_len(MAX_RANK)]
+    pub rank_shares_bps: Vec<u16>,
+    /// Display name for wallets and explorers, empty if unset
+    #[max_len(MAX_POOL_NAME_LEN)]
+    pub name: String,
+    /// JSON metadata (description, image), empty if unset
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String,
+    pub mint: Pubkey,
+    /// Token account holding the prizes, seeds ["vault", prize_pool]
+    pub vault: Pubkey,
+    /// Deposits made through fund_pool
+    pub total_funded: u64,
+    /// Most a single wallet may deposit, 0 for no cap
+    pub funder_cap: u64,
+    /// Only wallets allowed via set_funder_allowed may deposit
+    pub whitelist_only: bool,
+    /// Winners must not have won another exclusive category this week
+    pub exclusive_winners: bool,
+    /// Winners lists may skip ranks (e.g. 1, 3) instead of listing them consecutively
+    pub allow_rank_gaps: bool,
+    /// Set for equal-split airdrops: every leaderboard leaf scoring at least this
+    /// claims the same share instead of a rank's, see set_airdrop_mode
+    pub airdrop_threshold: Option<u8>,
+    /// Score tiers that weight an airdrop's shares; empty for equal shares
+    #[max_len(MAX_QUALIFICATION_TIERS)]
+    pub qualification_tiers: Vec<QualificationTier>,
+    /// Top of the judge rubric submit_score accepts for this pool
+    pub raw_score_max: u16,
+    /// How raw scores are mapped onto 0..=MAX_SCORE
+    pub score_curve: ScoreCurve,
+    /// Judges whose scores aggregate_scores combines; empty while submit_score
+    /// takes a single judge's scores
+    #[max_len(MAX_JUDGES)]
+    pub judges: Vec<Pubkey>,
+    /// Judge scores aggregate_scores needs per player, 0 without judges
+    pub judge_quorum: u8,
+    /// Unix time judge scores close; before it aggregate_scores waits for the
+    /// whole panel, 0 without judges
+    pub judging_ends_at: i64,
+    /// Windows where submit_score boosts scores, checked against the on-chain clock
+    #[max_len(MAX_HAPPY_HOURS)]
+    pub happy_hours: Vec<HappyHour>,
+    /// Voter tokens held in the vote vault, seeds ["vote_vault", prize_pool]
+    pub votes_escrowed: u64,
+    /// Winners must have a BalanceSnapshot of at least this much, 0 for none
+    pub min_holding: u64,
+    /// First slot record_balance_snapshot accepts while min_holding is set
+    pub snapshot_start_slot: u64,
+    /// Slot record_balance_snapshot stops accepting at
+    pub snapshot_end_slot: u64,
+    /// Players must register holding an NFT verified in this Metaplex collection
+    pub entry_collection: Option<Pubkey>,
+    /// The collection NFT is a ticket, burned when the player registers
+    pub burn_entry_ticket: bool,
+    /// Players must hold a Civic pass from this gatekeeper network to register and claim
+    pub gatekeeper_network: Option<Pubkey>,
+    /// Players must register with a wallet attested to be at least this old
+    pub wallet_age: Option<WalletAgeRequirement>,
+    /// Pool mint tokens register_player charges into the vault, 0 for free entry
+    pub entry_fee: u64,
+    /// House cut of the collected entry fees, paid to the treasury by close_entries
+    pub rake_bps: u16,
+    /// Entry fees paid into the vault so far
+    pub entry_fees_collected: u64,
+    /// Refundable pool mint tokens register_player holds in the bond vault,
+    /// seeds ["bond_vault", prize_pool]; 0 for none
+    pub entry_bond: u64,
+    /// Bonds posted and neither refunded nor slashed yet
+    pub bonds_held: u64,
+    /// Bonds the guardian slashed from the bond vault into the prize vault,
+    /// less the bounties paid from them
+    pub bonds_slashed: u64,
+    /// Pool mint tokens report_cheater holds from the reporter, 0 while reports are off
+    pub report_bond: u64,
+    /// Share of an upheld report's slashed entry bond paid to the reporter
+    pub report_bounty_bps: u16,
+    /// Set by close_entries; no further registrations
+    pub entries_closed: bool,
+    /// Set once award_jackpot paid the jackpot to one of this pool's entries
+    pub jackpot_drawn: bool,
+    /// Entries finalize_entry found at or above the jackpot trigger, all of
+    /// which award_jackpot must draw from
+    pub jackpot_qualifiers: u32,
+    /// Sponsored prize multiplier, 10000 = 1x; 0 or 1x for none
+    pub bonus_multiplier_bps: u16,
+    /// Partner whose fund_bonus deposits pay for the multiplier
+    pub bonus_sponsor: Pubkey,
+    /// Sponsor tokens in the vault held back for bonuses, outside the prize math
+    pub bonus_reserve: u64,
+    /// Prizes distribute_prizes couldn't deliver, held in the vault for retry_unpaid
+    pub unpaid_reserve: u64,
+    /// Set when prizes are advertised in USD and priced through Pyth at payout
+    pub usd_prizes: Option<UsdPrizes>,
+    /// Token account the guardian announced an emergency withdrawal to
+    pub emergency_destination: Option<Pubkey>,
+    /// emergency_withdraw is refused before this unix timestamp
+    pub emergency_unlock_at: i64,
+    /// The judge's full scoring transcript, set once by post_scoring_transcript
+    pub scoring_transcript: Option<ScoringTranscript>,
+    /// Set by verify_scoring_proof once a Groth16 proof tied leaderboard_root
+    /// to the scoring transcript
+    pub scoring_proof_verified: bool,
+    /// Merkle root of the full final leaderboard, set once by finalize_leaderboard
+    pub leaderboard_root: Option<[u8; 32]>,
+    /// Number of leaves under leaderboard_root; an airdrop's leaderboard lists
+    /// only qualifiers, so this is also its number of shares
+    pub leaderboard_entries: u32,
+    /// claim_prize closes and archive_week may run from then on, set by
+    /// finalize_leaderboard; 0 until then
+    pub claim_deadline: i64,
+    /// Vault balance after fees when the first claim_prize ran, 0 until then
+    pub claim_basis: u64,
+    /// Airdrop shares claimed so far
+    pub airdrop_claims: u32,
+    /// Sum of every qualifier's tier multiplier, posted by set_airdrop_weight
+    /// for tiered airdrops; 0 until then
+    pub airdrop_weight: u64,
+    /// Tier multipliers of the airdrop shares claimed so far, never more than
+    /// airdrop_total_weight
+    pub airdrop_weight_claimed: u64,
+    /// Set by archive_week; claim_prize is closed from then on
+    pub archived: bool,
+    /// When archive_week first ran, 0 until then
+    pub archived_at: i64,
+    /// Set by the first sweep_expired, which sent the unclaimed prizes to the treasury
+    pub expired_swept: bool,
+    /// What that sweep sent to the treasury
+    pub swept: u64,
+    /// Lowest leaf rank sweep_expired still reports, so each expired prize is reported once
+    pub expired_next_rank: u32,
+    /// Most lamports one claim is rebated for the rent it paid, 0 for no rebates
+    pub rent_rebate_cap: u64,
+    /// Lamports held on the pool, on top of its own rent, to pay rent rebates from
+    pub rent_rebate_reserve: u64,
+    pub total_distributed: u64,
+    /// Cuts taken before winner math (creator royalty, staker share)
+    pub total_fees: u64,
+    /// Sent to the guardian's destination by emergency_withdraw
+    pub emergency_withdrawn: u64,
+    /// Surplus moved into a later week's pool by rollover_week
+    pub rolled_over: u64,
+    pub distribution_nonce: u64,
+    /// sha256 chain over every distribute_prizes pass's winners_hash,
+    /// sha256(previous || pass), zero until the first pass
+    pub winners_hash: [u8; 32],
+    /// What distribute_prizes splits by rank, fixed by the first pass after
+    /// fees (the priced total on USD pools) so later passes pay the same shares
+    pub distribution_basis: u64,
+    /// Ranks distribute_prizes has paid, bit rank - 1
+    pub paid_ranks: u16,
+    /// Number of paid ranks, 1..=MAX_RANK
+    pub max_winners: u8,
+    pub bump: u8,
+}
+
+impl PrizePool {
+    /**
+     * `signer` may run the instruction `ix` (a DELEGATE_* bit): it holds
+     * `role`, or it is the pool's unexpired delegate for `ix`
+     */
+    pub fn authorizes(&self, role: PoolRole, ix: u64, signer: &Pubkey) -> Result<bool> {
+        if self.roles.key(role) == *signer {
+            return Ok(true);
+        }
+        Ok(match &self.delegation {
+            Some(delegation) if delegation.key == *signer && delegation.allowed_ixs & ix == ix => {
+                Clock::get()?.unix_timestamp < delegation.expires_at
+            }
+            _ => false,
+        })
+    }
+
+    /**
+     * What the vault should hold per the pool's counters: deposits, entry fees,
+     * slashed bonds and the sponsor reserve, less payouts, cuts, rollovers and
+     * emergency withdrawals. Bonds still held sit in the bond vault, not here.
+     * Sponsor bonuses already paid sit in total_distributed, not bonus_reserve.
+     * None if the counters record more going out than came in.
+     */
+    pub fn expected_vault_balance(&self) -> Option<u64> {
+        let inflows = (self.total_funded as u128)
+            + self.entry_fees_collected as u128
+            + self.bonds_slashed as u128
+            + self.bonus_reserve as u128;
+        let outflows = (self.total_distributed as u128)
+            + self.total_fees as u128
+            + self.emergency_withdrawn as u128
+            + self.rolled_over as u128
+            + self.swept as u128;
+        inflows.checked_sub(outflows)?.try_into().ok()
+    }
+
+    /**
+     * What of `vault_balance` rollover_week may carry into a later week: only
+     * once the week is archived and not cancelled, and never the unpaid prizes
+     * or the sponsor's reserve. None while the week is still open.
+     */
+    pub fn rollover_surplus(&self, vault_balance: u64) -> Option<u64> {
+        if !self.archived || self.is_cancelled() {
+            return None;
+        }
+        Some(vault_balance.saturating_sub(self.unpaid_reserve + self.bonus_reserve))
+    }
+
+    /// First PDA seed: WEEKLY_POOL_SEED for numbered pools
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        match self.week_index {
+            Some(_) => WEEKLY_POOL_SEED,
+            None => b"prize_pool",
+        }
+    }
+
+    /// Second PDA seed: week_index in little-endian, or the week id string
+    pub fn week_seed(&self) -> Vec<u8> {
+        match self.week_index {
+            Some(week_index) => week_index.to_le_bytes().to_vec(),
+            None => self.week_id.as_bytes().to_vec(),
+        }
+    }
+
+    /**
+     * Payouts are over, so the totals won't change: a distributed pool owes no
+     * unpaid prizes, a claimed one is past its claims and swept or archived
+     */
+    pub fn results_settled(&self) -> bool {
+        if self.distribution_nonce > 0 {
+            self.unpaid_reserve == 0
+        } else {
+            self.expired_swept || self.archived
+        }
+    }
+
+    /// No payout has gone out yet, through either distribute_prizes or claim_prize
+    pub fn payouts_pending(&self) -> bool {
+        self.distribution_nonce == 0 && self.claim_basis == 0
+    }
+
+    /// record_balance_snapshot takes snapshots at `slot`: the pool is holder-gated
+    /// and `slot` falls in its snapshot window
+    pub fn snapshots_open(&self, slot: u64) -> bool {
+        self.min_holding > 0
+            && (self.snapshot_start_slot..self.snapshot_end_slot).contains(&slot)
+    }
+
+    /// The leaderboard is posted or payouts have begun, so no more entries
+    /// are finalized and the jackpot can be drawn
+    pub fn results_final(&self) -> bool {
+        self.leaderboard_root.is_some() || !self.payouts_pending()
+    }
+
+    /// The guardian announced an emergency withdrawal, which can't be taken back,
+    /// so the week won't be paid out as played
+    pub fn is_cancelled(&self) -> bool {
+        self.emergency_destination.is_some()
+    }
+
+    /// What of `vault_balance` an emergency withdrawal may take: never the
+    /// prizes owed to unpaid winners or the sponsor's reserve
+    pub fn emergency_withdrawable(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.unpaid_reserve + self.bonus_reserve)
+    }
+
+    /**
+     * Weight an airdrop's shares are taken of, in tier multiplier bps: 1x per
+     * qualifier without tiers, else the posted airdrop_weight; 0 while unknown
+     */
+    pub fn airdrop_total_weight(&self) -> u64 {
+        if self.qualification_tiers.is_empty() {
+            self.leaderboard_entries as u64 * BPS_DENOMINATOR as u64
+        } else {
+            self.airdrop_weight
+        }
+    }
+
+    /// At most MAX_QUALIFICATION_TIERS tiers, ascending in both threshold and
+    /// multiplier, each threshold on the score scale and each multiplier
+    /// between 1x and MAX_TIER_MULTIPLIER_BPS
+    pub fn validate_qualification_tiers(tiers: &[QualificationTier]) -> Result<()> {
+        require!(
+            tiers.len() <= MAX_QUALIFICATION_TIERS,
+            ErrorCode::InvalidQualificationTiers
+        );
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.threshold <= MAX_SCORE
+                    && (BPS_DENOMINATOR..=MAX_TIER_MULTIPLIER_BPS).contains(&tier.multiplier_bps),
+                ErrorCode::InvalidQualificationTiers
+            );
+            if let Some(previous) = i.checked_sub(1).map(|j| &tiers[j]) {
+                require!(
+                    tier.threshold > previous.threshold
+                        && tier.multiplier_bps >= previous.multiplier_bps,
+                    ErrorCode::InvalidQualificationTiers
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// At most MAX_HAPPY_HOURS non-empty windows in ascending, non-overlapping
+    /// order, each multiplier above 1x and at most MAX_HAPPY_HOUR_MULTIPLIER_BPS
+    pub fn validate_happy_hours(happy_hours: &[HappyHour]) -> Result<()> {
+        require!(
+            happy_hours.len() <= MAX_HAPPY_HOURS,
+            ErrorCode::InvalidHappyHours
+        );
+        for (i, window) in happy_hours.iter().enumerate() {
+            require!(window.start < window.end, ErrorCode::InvalidHappyHours);
+            require!(
+                window.multiplier_bps > BPS_DENOMINATOR
+                    && window.multiplier_bps <= MAX_HAPPY_HOUR_MULTIPLIER_BPS,
+                ErrorCode::InvalidHappyHours
+            );
+            if let Some(previous) = i.checked_sub(1).map(|j| &happy_hours[j]) {
+                require!(window.start >= previous.end, ErrorCode::InvalidHappyHours);
+            }
+        }
+        Ok(())
+    }
+
+    /// Either empty, or one share per paid rank summing to at most 100%
+    pub fn validate_rank_shares(rank_shares_bps: &[u16], max_winners: u8) -> Result<()> {
+        if rank_shares_bps.is_empty() {
+            return Ok(());
+        }
+        require!(
+            rank_shares_bps.len() == max_winners as usize,
+            ErrorCode::InvalidRankShares
+        );
+        let total_bps: u32 = rank_shares_bps.iter().map(|&bps| bps as u32).sum();
+        require!(
+            total_bps <= BPS_DENOMINATOR as u32,
+            ErrorCode::InvalidRankShares
+        );
+        Ok(())
+    }
+}
+
+/// Most happy hours a pool can hold
+pub const MAX_HAPPY_HOURS: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct HappyHour {
+    /// Unix timestamp the window opens at
+    pub start: i64,
+    /// Unix timestamp the window closes at, exclusive
+    pub end: i64,
+    /// Score multiplier in basis points, 10000 = 1x
+    pub multiplier_bps: u16,
+}
+
+/// Off-chain record of how the AI judge scored a week, for players to dispute against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct ScoringTranscript {
+    /// sha256 of the transcript file served at `uri`
+    pub hash: [u8; 32],
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub uri: String,
+    pub posted_at: i64,
+}
+
+/// Public inputs of a scoring proof, see groth16::scoring_inputs
+pub const SCORING_PROOF_INPUTS: usize = 4;
+
+/**
+ * Groth16 verifying key of the judge's scoring circuit, BN254 points in
+ * EIP-197 encoding
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ScoringVerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+    /// The constant term, then one point per public input (SCORING_PROOF_INPUTS + 1)
+    pub ic: [[u8; 64]; 5],
+}
+
+/**
+ * The verifying key verify_scoring_proof checks proofs against
+ * Seeds: ["scoring_verifier"]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct ScoringVerifier {
+    pub key: ScoringVerifyingKey,
+    pub bump: u8,
+}
+
+/**
+ * An ed25519 key generated inside the judge's Nitro or SGX enclave
+ * The attestation report binding it to `measurement` is checked against the
+ * vendor's root certificate off-chain, before set_enclave_signer; anyone can
+ * fetch the report by `report_hash` and check it again.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct EnclaveSigner {
+    pub key: Pubkey,
+    /// PCR0 of a Nitro image or MRENCLAVE of an SGX enclave, zero-padded to 48 bytes
+    pub measurement: [u8; 48],
+    /// sha256 of the raw attestation report
+    pub report_hash: [u8; 32],
+}
+
+/// Most qualification tiers a pool can hold
+pub const MAX_QUALIFICATION_TIERS: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct QualificationTier {
+    /// Lowest score that reaches this tier
+    pub threshold: u8,
+    /// Airdrop share multiplier in basis points, 10000 = 1x
+    pub multiplier_bps: u16,
+}
+
+/// Operations a pool authority can delegate to a separate key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PoolRole {
+    /// Funder allowlist, funding rules and sweeping side vaults
+    Funding,
+    /// Scores, finalized entries and the leaderboard root
+    Posting,
+    /// Prize distribution and the jackpot award
+    Distribution,
+    /// Archiving the week and closing its accounts
+    Closing,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PoolRoles {
+    pub funder: Pubkey,
+    pub poster: Pubkey,
+    pub distributor: Pubkey,
+    pub closer: Pubkey,
+}
+
+impl PoolRoles {
+    /// Every role held by `authority`
+    pub fn held_by(authority: Pubkey) -> Self {
+        Self {
+            funder: authority,
+            poster: authority,
+            distributor: authority,
+            closer: authority,
+        }
+    }
+
+    pub fn key(&self, role: PoolRole) -> Pubkey {
+        match role {
+            PoolRole::Funding => self.funder,
+            PoolRole::Posting => self.poster,
+            PoolRole::Distribution => self.distributor,
+            PoolRole::Closing => self.closer,
+        }
+    }
+
+    pub fn set(&mut self, role: PoolRole, key: Pubkey) {
+        match role {
+            PoolRole::Funding => self.funder = key,
+            PoolRole::Posting => self.poster = key,
+            PoolRole::Distribution => self.distributor = key,
+            PoolRole::Closing => self.closer = key,
+        }
+    }
+}
+
+pub const DELEGATE_SUBMIT_SCORE: u64 = 1 << 0;
+pub const DELEGATE_FINALIZE_ENTRY: u64 = 1 << 1;
+pub const DELEGATE_FINALIZE_LEADERBOARD: u64 = 1 << 2;
+pub const DELEGATE_DISTRIBUTE_PRIZES: u64 = 1 << 3;
+pub const DELEGATE_AWARD_JACKPOT: u64 = 1 << 4;
+pub const DELEGATE_ARCHIVE_WEEK: u64 = 1 << 5;
+pub const DELEGATE_CLOSE_REGISTRATIONS: u64 = 1 << 6;
+pub const DELEGATE_CLOSE_PRIZE_POOL: u64 = 1 << 7;
+pub const DELEGATE_SET_FUNDER_ALLOWED: u64 = 1 << 8;
+pub const DELEGATE_SET_FUNDING_RULES: u64 = 1 << 9;
+pub const DELEGATE_SWEEP_MINT_VAULT: u64 = 1 << 10;
+pub const DELEGATE_POST_SCORING_TRANSCRIPT: u64 = 1 << 11;
+
+/// Every role-gated instruction delegate_authority can hand out
+pub const DELEGABLE_IXS: u64 = (1 << 12) - 1;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct Delegation {
+    pub key: Pubkey,
+    /// The delegate is refused from this unix timestamp on
+    pub expires_at: i64,
+    /// DELEGATE_* bits of the instructions the delegate may run
+    pub allowed_ixs: u64,
+}
+
+/// Key changes a Config or PrizePool keeps, the oldest dropped first
+pub const MAX_AUTHORITY_HISTORY: usize = 8;
+
+/// Privileged key an AuthorityChange is about
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AuthorityKind {
+    Guardian,
+    Treasury,
+    Creator,
+    Funder,
+    Poster,
+    Distributor,
+    Closer,
+    /// The pool's delegate; the default key once it is revoked
+    Delegate,
+}
+
+impl From<PoolRole> for AuthorityKind {
+    fn from(role: PoolRole) -> Self {
+        match role {
+            PoolRole::Funding => AuthorityKind::Funder,
+            PoolRole::Posting => AuthorityKind::Poster,
+            PoolRole::Distribution => AuthorityKind::Distributor,
+            PoolRole::Closing => AuthorityKind::Closer,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct AuthorityChange {
+    pub kind: AuthorityKind,
+    pub previous: Pubkey,
+    pub key: Pubkey,
+    pub changed_at: i64,
+}
+
+impl AuthorityChange {
+    /// Appends `change` to `history`, dropping the oldest entry once it is full
+    pub fn record(history: &mut Vec<AuthorityChange>, change: AuthorityChange) {
+        if history.len() >= MAX_AUTHORITY_HISTORY {
+            history.remove(0);
+        }
+        history.push(change);
+    }
+}
+
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct WalletAgeRequirement {
+    /// Key whose attest_wallet_age signatures the pool trusts
+    pub registrar: Pubkey,
+    pub min_age_days: u16,
+}
+
+impl WalletAgeRequirement {
+    /// `profile` carries this registrar's attestation, at least min_age_days old at `now`
+    pub fn is_met_by(&self, profile: &PlayerProfile, now: i64) -> bool {
+        profile.age_registrar == self.registrar
+            && profile.first_seen_at > 0
+            && now.saturating_sub(profile.first_seen_at)
+                >= self.min_age_days as i64 * SECONDS_PER_DAY
+    }
+}
+
+/// Prizes advertised in USD, converted to the pool mint at distribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct UsdPrizes {
+    /// Pyth feed pricing the pool mint in USD
+    pub feed_id: [u8; 32],
+    /// What every rank's share adds up to, in US cents
+    pub usd_cents: u64,
+    /// Decimals of the pool mint, read when the USD prizes were set
+    pub mint_decimals: u8,
+    /// Oldest price update distribution accepts
+    pub max_price_age_secs: u32,
+    /// Widest confidence interval distribution accepts, relative to the price
+    pub max_conf_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ScoreCurve {
+    Linear,
+    SquareRoot,
+    Square,
+}
+
+/// Most judges a pool can list
+pub const MAX_JUDGES: usize = 7;
+
+/// Fewest scores a quorum takes: the highest and lowest are dropped, leaving at least one
+pub const MIN_JUDGE_QUORUM: u8 = 3;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct JudgeScore {
+    pub judge: Pubkey,
+    /// In 0..=prize_pool.raw_score_max
+    pub raw_score: u16,
+}
+
+/**
+ * Scores a pool's judges gave one player, combined by aggregate_scores
+ * Seeds: ["judge_scores", prize_pool, player]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct JudgeScores {
+    pub prize_pool: Pubkey,
+    pub player: Pubkey,
+    /// One per judge, scores of judges no longer listed are dropped on the next submission
+    #[max_len(MAX_JUDGES)]
+    pub scores: Vec<JudgeScore>,
+    /// Happy hour multiplier at the first submission, applied to the aggregate
+    pub multiplier_bps: u16,
+    /// The entry holds the aggregate; no further submissions
+    pub aggregated: bool,
+    pub bump: u8,
+}
+
+/**
+ * A player's judged entry in one pool
+ * Seeds: ["entry", prize_pool, player]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Entry {
+    pub prize_pool: Pubkey,
+    pub player: Pubkey,
+    /// Score as given by the judge, or the judges' trimmed mean on quorum
+    /// pools, in 0..=prize_pool.raw_score_max
+    pub raw_score: u16,
+    /// raw_score normalized onto 0..=MAX_SCORE with the pool's curve, then
+    /// boosted by the happy hour it was submitted in
+    pub score: u8,
+    /// Happy hour multiplier applied to score, 10000 = none
+    pub multiplier_bps: u16,
+    /// Community vote weight cast for this entry
+    pub votes: u64,
+    /// judge_weight * score + vote_weight * votes, set by finalize_entry
+    pub final_score: u64,
+    /// No further scores or votes once set
+    pub finalized: bool,
+    /// Reached the jackpot trigger score when finalized
+    pub jackpot_qualified: bool,
+    pub bump: u8,
+}
+
+/**
+ * A player's sign-up for one pool, checked against the pool's entry rules
+ * Seeds: ["registration", prize_pool, player]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Registration {
+    pub prize_pool: Pubkey,
+    pub player: Pubkey,
+    /// NFT shown at registration on collection-gated pools
+    pub nft_mint: Option<Pubkey>,
+    /// Set when nft_mint was a ticket burned to enter
+    pub ticket_burned: bool,
+    pub registered_at: i64,
+    /// Entry bond still held for the player, 0 once refunded or slashed
+    pub bond: u64,
+    /// Set when the guardian slashed the bond for cheating
+    pub bond_slashed: bool,
+    /// Cheat reports against the player the guardian hasn't resolved yet
+    pub pending_reports: u16,
+    pub bump: u8,
+}
+
+/**
+ * One wallet's vote on one entry
+ * Seeds: ["vote", entry, voter]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub entry: Pubkey,
+    pub voter: Pubkey,
+    /// Tokens locked behind the vote
+    pub weight: u64,
+    /// Votes added to the entry, after quadratic scaling if enabled
+    pub counted: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ReportStatus {
+    Pending,
+    Upheld,
+    Rejected,
+}
+
+/**
+ * A bonded accusation that `wallet` cheated in a pool, kept after the
+ * guardian resolves it as the public record of the ruling
+ * Seeds: ["cheat_report", prize_pool, wallet, reporter]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct CheatReport {
+    pub prize_pool: Pubkey,
+    pub wallet: Pubkey,
+    pub reporter: Pubkey,
+    /// Hash of the evidence the reporter handed to the guardian off-chain
+    pub evidence_hash: [u8; 32],
+    /// Reporter tokens held in the bond vault until the ruling
+    pub bond: u64,
+    pub reported_at: i64,
+    pub status: ReportStatus,
+    /// Paid to the reporter out of the slashed entry bond, 0 unless upheld
+    pub bounty: u64,
+    pub resolved_at: i64,
+    pub bump: u8,
+}
+
+/**
+ * Tokens a voter locked to back their votes in one pool, released by
+ * unlock_votes once the pool has distributed
+ * Seeds: ["vote_escrow", prize_pool, voter]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct VoteEscrow {
+    pub prize_pool: Pubkey,
+    pub voter: Pubkey,
+    pub locked: u64,
+    pub bump: u8,
+}
+
+/**
+ * A wallet's balance of the pool mint, recorded during the week to prove
+ * it held the tokens for holder-exclusive pools
+ * Seeds: ["snapshot", prize_pool, wallet]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct BalanceSnapshot {
+    pub prize_pool: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+    pub bump: u8,
+}
+
+/**
+ * Per-pool deposit tally and whitelist flag for one funder
+ * Seeds: ["funder", prize_pool, funder]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct FunderRecord {
+    pub prize_pool: Pubkey,
+    pub funder: Pubkey,
+    pub deposited: u64,
+    pub whitelisted: bool,
+    pub bump: u8,
+}
+
+/**
+ * Marks the category a wallet won this week, so exclusive pools can
+ * reject it in every other category
+ * Seeds: ["week_winner", week_id, wallet]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct WeekWinner {
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub week_id: String,
+    pub wallet: Pubkey,
+    #[max_len(MAX_CATEGORY_LEN)]
+    pub category: String,
+    pub bump: u8,
+}
+
+/// Most payouts one distribute_prizes pass can leave unpaid before it aborts instead
+pub const MAX_UNPAID_PRIZES: usize = 8;
+
+/**
+ * A payout leg distribute_prizes skipped because its token account was
+ * closed or frozen; the tokens stay in the vault until retry_unpaid
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct UnpaidPrize {
+    /// Entry the prize was won by (the captain for teams)
+    pub winner: Pubkey,
+    /// Wallet whose token account the leg is owed to
+    pub owner: Pubkey,
+    pub rank: u8,
+    pub amount: u64,
+    /// Set once retry_unpaid delivered it
+    pub paid: bool,
+}
+
+/// Where one winner entry's payout stands, kept beside the winner in its DistributionRecord
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PayoutStatus {
+    /// Every leg was delivered, by the pass itself or retry_unpaid
+    Paid,
+    /// Some legs were delivered, the rest wait in the unpaid list for retry_unpaid
+    Partial,
+    /// No leg was delivered; all of them wait in the unpaid list for retry_unpaid
+    Failed,
+}
+
+/**
+ * Receipt for a single distribute_prizes execution
+ * One per (prize_pool, nonce), so auditors can enumerate every pass
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct DistributionRecord {
+    pub prize_pool: Pubkey,
+    pub nonce: u64,
+    /// sha256 of the borsh-encoded winners argument
+    pub winners_hash: [u8; 32],
+    pub winner_count: u16,
+    /// Delivered during the pass, unpaid legs excluded
+    pub total_paid: u64,
+    pub executor: Pubkey,
+    /// Enclave key that signed the winners, None when the executor was the distributor
+    pub enclave_signer: Option<Pubkey>,
+    pub slot: u64,
+    /// Legs skipped during the pass, at most MAX_UNPAID_PRIZES
+    #[max_len(MAX_UNPAID_PRIZES)]
+    pub unpaid: Vec<UnpaidPrize>,
+    /// Winner wallets (captains for teams) in the order they were paid
+    #[max_len(MAX_RANK)]
+    pub winners: Vec<Pubkey>,
+    /// Payout status of each entry in `winners`, same order
+    #[max_len(MAX_RANK)]
+    pub statuses: Vec<PayoutStatus>,
+    /// The pool's paid_ranks after this pass
+    pub paid_ranks: u16,
+    pub bump: u8,
+}
+
+impl DistributionRecord {
+    /// Status of `wallet`'s entry in this pass, None if it wasn't listed
+    pub fn status_of(&self, wallet: &Pubkey) -> Option<PayoutStatus> {
+        let index = self.winners.iter().position(|winner| winner == wallet)?;
+        self.statuses.get(index).copied()
+    }
+}
+
+/**
+ * Receipt of a winner's claim_prize, so each wallet claims once per pool
+ * Seeds: ["claim", prize_pool, wallet]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimRecord {
+    pub prize_pool: Pubkey,
+    pub wallet: Pubkey,
+    /// 0 for an airdrop share
+    pub rank: u8,
+    pub score: u8,
+    pub amount: u64,
+    /// Wallet whose token account got the prize, the winner's unless redirected
+    pub paid_to: Pubkey,
+    /// Secondary leg routed by the winner's PlayerProfile, included in `amount`
+    pub split_recipient: Option<Pubkey>,
+    pub split_amount: u64,
+    /// Lamports the pool rebated the winner, returned to it when archive_week closes this
+    pub rent_rebate: u64,
+    pub bump: u8,
+}
+
+impl ClaimRecord {
+    /// sha256("claim" || wallet || rank || score || amount (u64 LE)), as folded into a WeekArchive
+    pub fn archive_leaf(&self) -> [u8; 32] {
+        hashv(&[
+            b"claim",
+            self.wallet.as_ref(),
+            &[self.rank, self.score],
+            &self.amount.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+/**
+ * What's left of a pool's claim receipts once sweep_expired or archive_week closed them
+ * Outlives the pool, so past weeks stay auditable without one PDA per winner
+ * Seeds: ["week_archive", prize_pool]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct WeekArchive {
+    pub prize_pool: Pubkey,
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub week_id: String,
+    #[max_len(MAX_CATEGORY_LEN)]
+    pub category: String,
+    /// sha256(receipts_hash || leaf) over every archived receipt, in the order they were closed
+    pub receipts_hash: [u8; 32],
+    pub receipts_archived: u32,
+    /// Prizes recorded by the archived receipts
+    pub amount_archived: u64,
+    /// Rent returned to the winners who paid for the receipts
+    pub lamports_reclaimed: u64,
+    pub bump: u8,
+}
+
+impl WeekArchive {
+    pub fn open(&mut self, key: Pubkey, prize_pool: &PrizePool, bump: u8) {
+        self.prize_pool = key;
+        self.week_id = prize_pool.week_id.clone();
+        self.category = prize_pool.category.clone();
+        self.bump = bump;
+    }
+
+    /// Folds one closed receipt into the summary
+    pub fn record(&mut self, leaf: [u8; 32], amount: u64, lamports: u64) {
+        self.receipts_hash = hashv(&[&self.receipts_hash, &leaf]).to_bytes();
+        self.receipts_archived += 1;
+        self.amount_archived += amount;
+        self.lamports_reclaimed += lamports;
+    }
+}
+
+/**
+ * Protocol-wide counters, so pool and winner totals can be read from chain
+ * Created once by the admin with initialize_global_stats; counts from then on.
+ * Seeds: ["global_stats"]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct GlobalStats {
+    /// Pools opened by initialize_prize_pool, initialize_weekly_pool and rollover_week
+    pub total_pools: u64,
+    /// Wallets whose PlayerProfile recorded their first placing
+    pub unique_winners: u64,
+    /// Latest ISO week a pool was opened for, compared by year and week
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub current_week_id: String,
+    pub bump: u8,
+}
+
+impl GlobalStats {
+    /// Counts a newly opened pool for `week_id`, which becomes the current
+    /// week if it is a later ISO week; free-form week ids never do
+    pub fn record_pool(&mut self, week_id: &str) {
+        self.total_pools += 1;
+        if parse_week_id(week_id) > parse_week_id(&self.current_week_id) {
+            self.current_week_id = week_id.to_string();
+        }
+    }
+}
+
+/**
+ * Prizes delivered in one mint across every pool, so "paid out to date" never
+ * adds up amounts of different mints or decimals
+ * Opened by the first pool created in the mint.
+ * Seeds: ["mint_stats", mint]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct MintStats {
+    pub mint: Pubkey,
+    /// Base units of the mint delivered by distribute_prizes, claim_prize and retry_unpaid
+    pub total_distributed: u64,
+    pub bump: u8,
+}
+
+impl MintStats {
+    pub fn open(&mut self, mint: Pubkey, bump: u8) {
+        self.mint = mint;
+        self.bump = bump;
+    }
+}
+
+/**
+ * Running totals of one pool's week, so stats pages read a single account
+ * Opened by the first instruction that updates it. total_funded and
+ * total_paid start from the pool's own totals and then mirror them, while
+ * participants and claimers count from the opening on.
+ * Seeds: ["week_stats", prize_pool]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct WeekStats {
+    pub prize_pool: Pubkey,
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub week_id: String,
+    #[max_len(MAX_CATEGORY_LEN)]
+    pub category: String,
+    /// Entries locked by finalize_entry
+    pub participants: u32,
+    /// Sum of the finalized entries' scores
+    pub score_total: u64,
+    /// score_total / participants, rounded down
+    pub average_score: u8,
+    pub highest_score: u8,
+    /// Deposits in the pool mint, rollovers included
+    pub total_funded: u64,
+    /// Prizes delivered by distribute_prizes, claim_prize and retry_unpaid
+    pub total_paid: u64,
+    /// Wallets that claimed through claim_prize or claim_many
+    pub unique_claimers: u32,
+    pub bump: u8,
+}
+
+impl WeekStats {
+    /// Fills in a freshly created account; no-op once opened
+    pub fn open(&mut self, key: Pubkey, prize_pool: &PrizePool, bump: u8) {
+        if self.prize_pool != Pubkey::default() {
+            return;
+        }
+        self.prize_pool = key;
+        self.week_id = prize_pool.week_id.clone();
+        self.category = prize_pool.category.clone();
+        self.total_funded = prize_pool.total_funded;
+        self.total_paid = prize_pool.total_distributed;
+        self.bump = bump;
+    }
+
+    /// Folds one finalized entry's score into the averages
+    pub fn record_entry(&mut self, score: u8) {
+        self.participants += 1;
+        self.score_total += score as u64;
+        self.average_score = (self.score_total / self.participants as u64) as u8;
+        self.highest_score = self.highest_score.max(score);
+    }
+}
+
+/// Largest squad a Team can register
+pub const MAX_TEAM_MEMBERS: usize = 5;
+
+/**
+ * Parimutuel market on who wins a pool: bettors who backed rank 1 share
+ * every stake, minus the rake on the losing ones
+ * Seeds: ["bet_market", prize_pool]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct BetMarket {
+    pub prize_pool: Pubkey,
+    /// Escrow of every stake, in the pool mint, seeds ["bet_vault", prize_pool]
+    pub vault: Pubkey,
+    /// No bets at or after this unix timestamp
+    pub closes_at: i64,
+    /// settle_bets is refused from this unix timestamp on and refund_bet opens,
+    /// closes_at + BET_SETTLE_WINDOW_SECONDS
+    pub settle_deadline: i64,
+    /// House cut of the losing stakes, paid to the treasury by settle_bets
+    pub rake_bps: u16,
+    pub total_staked: u64,
+    /// Rank 1 of the finalized leaderboard, set by settle_bets
+    pub winner: Option<Pubkey>,
+    /// Stakes on the winner; 0 once settled means every bet is refunded
+    pub winning_stake: u64,
+    /// Shared pro-rata by the winning bets: every stake minus the rake
+    pub payout_pool: u64,
+    pub bump: u8,
+}
+
+/**
+ * Everything staked on one player in a market
+ * Seeds: ["bet_outcome", bet_market, player]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct BetOutcome {
+    pub bet_market: Pubkey,
+    pub player: Pubkey,
+    pub total_staked: u64,
+    pub bump: u8,
+}
+
+/**
+ * A bettor's stake in a market, all on one player; closed when paid out
+ * Seeds: ["bet", bet_market, bettor]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Bet {
+    pub bet_market: Pubkey,
+    pub bettor: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+/**
+ * Registered squad whose prizes are split across members
+ * Seeds: ["team", captain]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct Team {
+    pub captain: Pubkey,
+    #[max_len(MAX_TEAM_MEMBERS)]
+    pub members: Vec<TeamMember>,
+    pub bump: u8,
+}
+
+impl Team {
+    /// 1..=MAX_TEAM_MEMBERS unique wallets whose shares sum to 100%
+    pub fn validate_members(members: &[TeamMember]) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_TEAM_MEMBERS,
+            ErrorCode::InvalidTeamMembers
+        );
+        let total_bps: u32 = members.iter().map(|m| m.share_bps as u32).sum();
+        require!(
+            total_bps == BPS_DENOMINATOR as u32,
+            ErrorCode::InvalidTeamMembers
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                members[..i].iter().all(|other| other.wallet != member.wallet),
+                ErrorCode::InvalidTeamMembers
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct TeamMember {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+}
+
+/**
+ * Per-wallet player settings
+ * Seeds: ["player", wallet]
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerProfile {
+    pub wallet: Pubkey,
+    /// Secondary wallet (coach, agent, guild) that receives split_bps of each prize
+    pub split_recipient: Option<Pubkey>,
+    pub split_bps: u16,
+    /// Last week the player finished in the top PODIUM_RANK, empty if never
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub last_podium_week: String,
+    /// Wallet whose token account receives solo prizes instead of `wallet`
+    pub payout_wallet: Option<Pubkey>,
+    /// Bumped on every signed payout authorization, so none can be replayed
+    pub payout_nonce: u64,
+    /// Last week the player placed in any paid rank, empty if never
+    #[max_len(MAX_WEEK_ID_LEN)]
+    pub last_placed_week: String,
+    /// Consecutive weeks placed, up to and including last_placed_week
+    pub streak_weeks: u16,
+    /// Wallet's first on-chain activity as attested by age_registrar, 0 if never attested
+    pub first_seen_at: i64,
+    pub age_registrar: Pubkey,
+    pub bump: u8,
+}
+
+impl PlayerProfile {
+    /// Empty profile of `wallet`, for the instructions that create one
+    pub fn init(&mut self, wallet: Pubkey, bump: u8) {
+        self.wallet = wallet;
+        self.split_recipient = None;
+        self.split_bps = 0;
+        self.last_podium_week = String::new();
+        self.payout_wallet = None;
+        self.payout_nonce = 0;
+        self.last_placed_week = String::new();
+        self.streak_weeks = 0;
+        self.first_seen_at = 0;
+        self.age_registrar = Pubkey::default();
+        self.bump = bump;
+    }
+
+    /// Counts a placing in `week_id`: extends the streak if the player placed
+    /// the week before, keeps it for another category the same week, and
+    /// otherwise starts over at 1. Returns whether it was the first ever.
+    pub fn record_placing(&mut self, week_id: &str) -> bool {
+        if self.last_placed_week == week_id {
+            return false;
+        }
+        let first = self.last_placed_week.is_empty();
+        let extends = previous_week_id(week_id).as_deref() == Some(self.last_placed_week.as_str());
+        self.streak_weeks = if extends {
+            self.streak_weeks.saturating_add(1)
+        } else {
+            1
+        };
+        self.last_placed_week = week_id.to_string();
+        first
+    }
+
+    /// The streak earns `config`'s bonus
+    pub fn on_streak(&self, config: &Config) -> bool {
+        config.streak_bonus_bps > 0 && self.streak_weeks >= config.min_streak_weeks
+    }
+}
+
+/// One of the signer's leaderboard leaves, claimed through claim_many
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimRequest {
+    pub rank: u32,
+    pub score: u8,
+    /// Sibling hashes from the leaf up to the pool's leaderboard_root
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// A leaderboard leaf passed to sweep_expired, claimed or not
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SweptLeaf {
+    pub wallet: Pubkey,
+    pub rank: u32,
+    pub score: u8,
+    /// Sibling hashes from the leaf up to leaderboard_root
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WinnerEntry {
+    /// Winning wallet, or the team captain for team entries
+    pub wallet: Pubkey,
+    pub rank: u8,
+    pub score: u8,
+    /// Team PDA whose members share this prize
+    pub team: Option<Pubkey>,
+}
+
+/// Low bits of ListedWinner::rank_flags holding the rank, enough for MAX_RANK
+pub const LISTED_RANK_MASK: u8 = 0x0f;
+
+/// ListedWinner::rank_flags bit marking a team entry led by `wallet`
+pub const LISTED_TEAM_FLAG: u8 = 0x80;
+
+/**
+ * A winner as stored in a WinnerList: 34 bytes with no length prefixes,
+ * about half of a borsh WinnerEntry for a team. Team entries only carry a
+ * flag, since the Team PDA is derived from the captain.
+ */
+#[zero_copy]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct ListedWinner {
+    /// Winning wallet, or the team captain for team entries
+    pub wallet: Pubkey,
+    pub score: u8,
+    /// Rank under LISTED_RANK_MASK, plus LISTED_TEAM_FLAG for team entries
+    pub rank_flags: u8,
+}
+
+impl ListedWinner {
+    pub fn new(wallet: Pubkey, rank: u8, score: u8, team: bool) -> Self {
+        let flags = if team { LISTED_TEAM_FLAG } else { 0 };
+        Self { wallet, score, rank_flags: (rank & LISTED_RANK_MASK) | flags }
+    }
+
+    pub fn rank(&self) -> u8 {
+        self.rank_flags & LISTED_RANK_MASK
+    }
+
+    pub fn is_team(&self) -> bool {
+        self.rank_flags & LISTED_TEAM_FLAG != 0
+    }
+
+    /// No bits set outside the rank and the known flags
+    pub fn is_well_formed(&self) -> bool {
+        self.rank_flags & !(LISTED_RANK_MASK | LISTED_TEAM_FLAG) == 0
+    }
+
+    pub fn to_entry(&self) -> WinnerEntry {
+        let team = self.is_team().then(|| {
+            Pubkey::find_program_address(&[b"team", self.wallet.as_ref()], &crate::ID).0
+        });
+        WinnerEntry { wallet: self.wallet, rank: self.rank(), score: self.score, team }
+    }
+}
+
+/**
+ * Winners staged across several append_winners transactions, so
+ * distribute_prizes can read a list too large for one instruction's data
+ * Seeds: ["winner_list", prize_pool]
+ */
+#[account(zero_copy)]
+#[derive(InitSpace)]
+pub struct WinnerList {
+    pub prize_pool: Pubkey,
+    /// Entries appended so far; distribute_prizes resets it once it paid them
+    pub count: u8,
+    pub bump: u8,
+    pub entries: [ListedWinner; MAX_RANK as usize],
+}
+
+impl WinnerList {
+    /// The appended entries in the form distribute_prizes takes them
+    pub fn winners(&self) -> Vec<WinnerEntry> {
+        self.entries[..self.count as usize].iter().map(ListedWinner::to_entry).collect()
+    }
+}
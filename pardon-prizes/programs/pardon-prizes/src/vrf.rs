@@ -0,0 +1,41 @@
+//! Reads fulfilled randomness from ORAO VRF, so jackpot ties are broken by
+//! a draw neither the pool authority nor the players can steer.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::pubkey;
+
+pub const ORAO_VRF_PROGRAM_ID: Pubkey = pubkey!("VRFzZoJdhFWL8rkvu87LpKM3RbcVezpMEc6X5GVDr7y");
+
+/// Prefix of ORAO's randomness request PDAs
+const RANDOMNESS_ACCOUNT_SEED: &[u8] = b"orao-vrf-randomness-request";
+
+/// Byte offset of `randomness: [u8; 64]` in a randomness account:
+/// discriminator (8), seed (32)
+const RANDOMNESS_OFFSET: usize = 8 + 32;
+
+/// Seed the jackpot draw for `prize_pool` must be requested with, so the
+/// authority can't shop around between several requests
+pub fn jackpot_draw_seed(prize_pool: &Pubkey) -> [u8; 32] {
+    hashv(&[b"jackpot", prize_pool.as_ref()]).to_bytes()
+}
+
+/// ORAO's randomness account for a request made with `seed`
+pub fn randomness_address(seed: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[RANDOMNESS_ACCOUNT_SEED, seed], &ORAO_VRF_PROGRAM_ID).0
+}
+
+/// The request's randomness, None while it's still all zeroes (unfulfilled)
+pub fn fulfilled_randomness(data: &[u8]) -> Option<[u8; 64]> {
+    let randomness: [u8; 64] = data
+        .get(RANDOMNESS_OFFSET..RANDOMNESS_OFFSET + 64)?
+        .try_into()
+        .ok()?;
+    randomness.iter().any(|&b| b != 0).then_some(randomness)
+}
+
+/// Uniform-enough pick in 0..len from the first 8 bytes of `randomness`
+pub fn pick_index(randomness: &[u8; 64], len: usize) -> usize {
+    let value = u64::from_le_bytes(randomness[..8].try_into().unwrap());
+    (value % len as u64) as usize
+}
@@ -0,0 +1,135 @@
+//! Minimal client for the Wormhole core bridge's post_message, so weekly
+//! results can be verified on other chains without trusting our API.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::pubkey;
+
+use crate::errors::ErrorCode;
+use crate::state::PrizePool;
+
+/// Core bridge on mainnet-beta
+#[cfg(not(feature = "devnet"))]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+
+/// Core bridge on devnet
+#[cfg(feature = "devnet")]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("3u8hJUVTA4jH1wYAyUur7FFZVQ8H635K3tSHHF4ssjQ5");
+
+/// Core bridge instruction tag for PostMessage
+const POST_MESSAGE: u8 = 1;
+
+/// Guardians only sign once the posting slot is finalized
+const CONSISTENCY_FINALIZED: u8 = 1;
+
+/// Byte offset of `fee: u64` in the bridge's BridgeData account:
+/// guardian_set_index (u32), last_lamports (u64), guardian_set_expiration_time (u32)
+const BRIDGE_FEE_OFFSET: usize = 4 + 8 + 4;
+
+/// First byte of every payload we post, so receivers can tell message kinds apart
+pub const RESULTS_PAYLOAD_ID: u8 = 1;
+
+/// Lamports the bridge charges per message
+pub fn message_fee(bridge: &AccountInfo) -> Result<u64> {
+    let data = bridge.try_borrow_data()?;
+    let fee = data
+        .get(BRIDGE_FEE_OFFSET..BRIDGE_FEE_OFFSET + 8)
+        .ok_or(ErrorCode::InvalidBridgeConfig)?;
+    Ok(u64::from_le_bytes(fee.try_into().unwrap()))
+}
+
+/// Next sequence the bridge will assign to `emitter`, 0 before its first message
+pub fn next_sequence(sequence: &AccountInfo) -> Result<u64> {
+    let data = sequence.try_borrow_data()?;
+    Ok(data
+        .get(..8)
+        .map(|seq| u64::from_le_bytes(seq.try_into().unwrap()))
+        .unwrap_or(0))
+}
+
+/**
+ * Results payload, big-endian for EVM receivers:
+ * id (u8), week_id (u8 len + bytes), category (u8 len + bytes), mint (32),
+ * leaderboard_root (32), leaderboard_entries (u32), total_distributed (u64),
+ * total_fees (u64), winners_hash (32)
+ */
+pub fn results_payload(prize_pool: &PrizePool, leaderboard_root: &[u8; 32]) -> Vec<u8> {
+    let mut payload = vec![RESULTS_PAYLOAD_ID];
+    payload.push(prize_pool.week_id.len() as u8);
+    payload.extend_from_slice(prize_pool.week_id.as_bytes());
+    payload.push(prize_pool.category.len() as u8);
+    payload.extend_from_slice(prize_pool.category.as_bytes());
+    payload.extend_from_slice(prize_pool.mint.as_ref());
+    payload.extend_from_slice(leaderboard_root);
+    payload.extend_from_slice(&prize_pool.leaderboard_entries.to_be_bytes());
+    payload.extend_from_slice(&prize_pool.total_distributed.to_be_bytes());
+    payload.extend_from_slice(&prize_pool.total_fees.to_be_bytes());
+    payload.extend_from_slice(&prize_pool.winners_hash);
+    payload
+}
+
+/// Accounts of the core bridge's PostMessage, in the order it expects them
+pub struct PostMessage<'a, 'info> {
+    pub bridge: &'a AccountInfo<'info>,
+    pub message: &'a AccountInfo<'info>,
+    pub emitter: &'a AccountInfo<'info>,
+    pub sequence: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub wormhole_program: &'a AccountInfo<'info>,
+}
+
+/**
+ * Posts `payload` as a finalized message; `message` and `emitter` are
+ * PDAs of this program signed for with `signer_seeds`
+ * The bridge fee must already be in the fee collector.
+ */
+pub fn post_message(
+    accounts: PostMessage,
+    payload: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = vec![POST_MESSAGE];
+    // nonce (u32), payload (borsh Vec<u8>), consistency level
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(&payload);
+    data.push(CONSISTENCY_FINALIZED);
+
+    let ix = Instruction {
+        program_id: WORMHOLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.bridge.key(), false),
+            AccountMeta::new(accounts.message.key(), true),
+            AccountMeta::new_readonly(accounts.emitter.key(), true),
+            AccountMeta::new(accounts.sequence.key(), false),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new(accounts.fee_collector.key(), false),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.bridge.clone(),
+            accounts.message.clone(),
+            accounts.emitter.clone(),
+            accounts.sequence.clone(),
+            accounts.payer.clone(),
+            accounts.fee_collector.clone(),
+            accounts.clock.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+            accounts.wormhole_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
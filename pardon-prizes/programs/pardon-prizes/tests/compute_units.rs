@@ -0,0 +1,168 @@
+//! Compute-unit budgets per instruction on LiteSVM.
+//!
+//! Each benchmark runs the instruction with the limit raised to the 1.4M CU
+//! transaction ceiling, then checks what it used against the instruction's
+//! budget below. Budgets sit well under the limits the runtime enforces, so an
+//! instruction that grows noticeably fails here long before a transaction
+//! would. Every run prints what each instruction used; when a change is meant
+//! to move one, set its budget from that figure plus 25%. Needs the SBF build
+//! from `anchor build`; CI runs these with it and `PARDON_REQUIRE_PROGRAM=1`.
+
+mod support;
+
+use anchor_spl::associated_token::get_associated_token_address;
+use pardon_prizes::math::MAX_RANK;
+use pardon_prizes::merkle::{hash_pair, leaderboard_leaf};
+use pardon_prizes::{ClaimRequest, FEATURE_CLAIMS};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use support::{distribute_ix, solo, solo_accounts, TestContext, CATEGORY, MAX_TX_COMPUTE_UNITS};
+
+const WEEK: &str = "2024-W45";
+const POOL_SIZE: u64 = 10_000_000_000;
+
+const INITIALIZE_PRIZE_POOL_BUDGET: u64 = 80_000;
+const FUND_POOL_BUDGET: u64 = 50_000;
+const REGISTER_PLAYER_BUDGET: u64 = 80_000;
+const SUBMIT_SCORE_BUDGET: u64 = 60_000;
+const RETRY_UNPAID_BUDGET: u64 = 80_000;
+/// The first claim of a pool, which also takes the pool's cuts and opens the
+/// winner's token account
+const CLAIM_PRIZE_BUDGET: u64 = 150_000;
+const CLAIM_MANY_BASE_BUDGET: u64 = 60_000;
+const CLAIM_MANY_PER_CLAIM_BUDGET: u64 = 110_000;
+const DISTRIBUTE_PRIZES_BASE_BUDGET: u64 = 100_000;
+const DISTRIBUTE_PRIZES_PER_WINNER_BUDGET: u64 = 60_000;
+
+/// Winner-list sizes distribute_prizes is measured at, up to a full list.
+/// LiteSVM doesn't hold transactions to 1232 bytes, so a full list runs here
+/// even though on a cluster it needs a lookup table or a staged WinnerList.
+const WINNER_COUNTS: [u8; 3] = [1, MAX_RANK / 2, MAX_RANK];
+
+/// Weeks claimed in one claim_many
+const CLAIM_MANY_WEEKS: [&str; 4] = ["2024-W41", "2024-W42", "2024-W43", "2024-W44"];
+
+fn assert_within(instruction: &str, used: u64, budget: u64) {
+    println!("{instruction}: {used} CU of {budget}");
+    assert!(used <= budget, "{instruction} used {used} CU, over its {budget} CU budget");
+}
+
+#[test]
+fn pool_setup_stays_within_budget() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.initialize_prize_pool_ix(WEEK, CATEGORY, MAX_RANK, &[]);
+    let used = ctx.send_metered(&[ix], &[]);
+    assert_within("initialize_prize_pool", used, INITIALIZE_PRIZE_POOL_BUDGET);
+
+    let pool = ctx.funded_pool("2024-W46", 0);
+    let funder = ctx.authority.pubkey();
+    let funder_token_account = ctx.create_token_account(&funder, POOL_SIZE);
+    let ix = ctx.fund_pool_ix(&pool, &funder, funder_token_account, POOL_SIZE, "benchmark");
+    let used = ctx.send_metered(&[ix], &[]);
+    assert_within("fund_pool", used, FUND_POOL_BUDGET);
+}
+
+#[test]
+fn entries_stay_within_budget() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+    let used = ctx.send_metered(&[ix], &[&player]);
+    assert_within("register_player", used, REGISTER_PLAYER_BUDGET);
+
+    let ix = ctx.submit_score_ix(&pool, &player.pubkey(), 90);
+    let used = ctx.send_metered(&[ix], &[]);
+    assert_within("submit_score", used, SUBMIT_SCORE_BUDGET);
+}
+
+#[test]
+fn distribution_stays_within_budget_up_to_a_full_list() {
+    for count in WINNER_COUNTS {
+        let Some(mut ctx) = TestContext::try_new() else { return };
+        let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+        let mut winners = Vec::new();
+        let mut accounts = Vec::new();
+        for rank in 1..=count {
+            let wallet = Pubkey::new_unique();
+            let token_account = ctx.create_token_account(&wallet, 0);
+            winners.push(solo(wallet, rank, 95 - rank));
+            accounts.extend(solo_accounts(&wallet, token_account));
+        }
+
+        let ix = distribute_ix(&ctx, &pool, winners, 0, &accounts);
+        let used = ctx.send_metered(&[ix], &[]);
+        let budget =
+            DISTRIBUTE_PRIZES_BASE_BUDGET + DISTRIBUTE_PRIZES_PER_WINNER_BUDGET * count as u64;
+        assert!(budget <= MAX_TX_COMPUTE_UNITS as u64);
+        assert_within(&format!("distribute_prizes x{count}"), used, budget);
+    }
+}
+
+#[test]
+fn retry_unpaid_stays_within_budget() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, Pubkey::new_unique()),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    let destination = ctx.create_token_account(&winner, 0);
+    let ix = TestContext::retry_unpaid_ix(&pool, 0, 0, destination);
+    let used = ctx.send_metered(&[ix], &[]);
+    assert_within("retry_unpaid", used, RETRY_UNPAID_BUDGET);
+}
+
+#[test]
+fn claim_prize_stays_within_budget() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_leaf = leaderboard_leaf(&winner.pubkey(), 1, 95);
+    let other_leaf = leaderboard_leaf(&Pubkey::new_unique(), 2, 90);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&winner_leaf, &other_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    // The claim opens the winner's associated token account
+    let winner_ata = get_associated_token_address(&winner.pubkey(), &pool.mint);
+    let ix = ctx.claim_prize_ix(&pool, &winner.pubkey(), winner_ata, 1, 95, vec![other_leaf]);
+    let used = ctx.send_metered(&[ix], &[&winner]);
+    assert_within("claim_prize", used, CLAIM_PRIZE_BUDGET);
+}
+
+#[test]
+fn claim_many_stays_within_budget() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_leaf = leaderboard_leaf(&winner.pubkey(), 1, 95);
+    let other_leaf = leaderboard_leaf(&Pubkey::new_unique(), 2, 90);
+    let mut pools = Vec::new();
+    for week in CLAIM_MANY_WEEKS {
+        let pool = ctx.funded_pool(week, POOL_SIZE);
+        let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&winner_leaf, &other_leaf), 2);
+        ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+        pools.push(pool);
+    }
+
+    let winner_ata = get_associated_token_address(&winner.pubkey(), &pools[0].mint);
+    let claim = ClaimRequest { rank: 1, score: 95, proof: vec![other_leaf] };
+    let claims = vec![claim; pools.len()];
+    let pools: Vec<_> = pools.iter().collect();
+    let ix = ctx.claim_many_ix(&pools, &winner.pubkey(), winner_ata, claims);
+    let used = ctx.send_metered(&[ix], &[&winner]);
+    let weeks = CLAIM_MANY_WEEKS.len() as u64;
+    let budget = CLAIM_MANY_BASE_BUDGET + CLAIM_MANY_PER_CLAIM_BUDGET * weeks;
+    assert_within(&format!("claim_many x{weeks}"), used, budget);
+}
@@ -0,0 +1,38 @@
+//! Groth16 plumbing checked natively against proofs with known discrete logs.
+
+mod support;
+
+use pardon_prizes::groth16::{negate_g1, scoring_inputs, verify};
+use support::{g1_add, g1_mul, known_log_proof, G1_GENERATOR};
+
+#[test]
+fn negation_cancels_and_round_trips() {
+    let point = g1_mul(42);
+    assert_eq!(negate_g1(&negate_g1(&point)), point);
+    assert_eq!(g1_add(&point, &negate_g1(&point)), [0; 64]);
+    assert_eq!(negate_g1(&[0; 64]), [0; 64]);
+    assert_ne!(negate_g1(&G1_GENERATOR), G1_GENERATOR);
+}
+
+#[test]
+fn scoring_inputs_split_hashes_into_field_sized_halves() {
+    let inputs = scoring_inputs(&[0xff; 32], &[0xee; 32]);
+    for (input, fill) in inputs.iter().zip([0xff, 0xff, 0xee, 0xee]) {
+        assert_eq!(input[..16], [0; 16]);
+        assert_eq!(input[16..], [fill; 16]);
+    }
+}
+
+#[test]
+fn proofs_only_verify_for_their_own_inputs() {
+    let inputs = scoring_inputs(&[7; 32], &[3; 32]);
+    let (key, proof) = known_log_proof(&inputs);
+    assert!(verify(&key, &proof, &inputs).unwrap());
+
+    let other_root = scoring_inputs(&[8; 32], &[3; 32]);
+    assert!(!verify(&key, &proof, &other_root).unwrap());
+
+    let mut forged = proof;
+    forged.c = g1_mul(8);
+    assert!(!verify(&key, &forged, &inputs).unwrap());
+}
@@ -0,0 +1,57 @@
+//! Property tests for leaderboard Merkle proofs.
+
+use anchor_lang::prelude::Pubkey;
+use pardon_prizes::merkle::{hash_pair, leaderboard_leaf, verify_proof};
+use proptest::prelude::*;
+
+/// Builds every level bottom-up, carrying an odd last node up unpaired
+fn levels(leaves: Vec<[u8; 32]>) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves];
+    while levels.last().unwrap().len() > 1 {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => hash_pair(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+fn proof(levels: &[Vec<[u8; 32]>], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+    for level in &levels[..levels.len() - 1] {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        index /= 2;
+    }
+    proof
+}
+
+proptest! {
+    #[test]
+    fn every_entry_proves_its_rank(scores in prop::collection::vec(0..=100u8, 1..40)) {
+        let wallets: Vec<Pubkey> = scores.iter().map(|_| Pubkey::new_unique()).collect();
+        let leaves = wallets
+            .iter()
+            .zip(&scores)
+            .enumerate()
+            .map(|(i, (wallet, &score))| leaderboard_leaf(wallet, i as u32 + 1, score))
+            .collect();
+        let levels = levels(leaves);
+        let root = levels.last().unwrap()[0];
+
+        for (i, (wallet, &score)) in wallets.iter().zip(&scores).enumerate() {
+            let rank = i as u32 + 1;
+            let proof = proof(&levels, i);
+            prop_assert!(verify_proof(&proof, &root, leaderboard_leaf(wallet, rank, score)));
+            prop_assert!(!verify_proof(&proof, &root, leaderboard_leaf(wallet, rank + 1, score)));
+        }
+    }
+}
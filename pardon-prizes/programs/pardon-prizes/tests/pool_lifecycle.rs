@@ -0,0 +1,4140 @@
+//! End-to-end pool lifecycle on LiteSVM.
+//!
+//! Needs the SBF build from `anchor build`; without it every test skips.
+
+mod support;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use pardon_prizes::groth16::scoring_inputs;
+use pardon_prizes::math::{MAX_RANK, WEEK_SECONDS};
+use pardon_prizes::merkle::{hash_pair, leaderboard_leaf};
+use pardon_prizes::wormhole::{results_payload, WORMHOLE_PROGRAM_ID};
+use pardon_prizes::{
+    AuthorityKind, BetMarket, CheatReport, ClaimRecord, ClaimRequest, Config, DistributionRecord,
+    EnclaveSigner, Entry, GlobalStats, HappyHour, Jackpot, ListedWinner, MintStats, PayoutStatus,
+    PlayerProfile, PoolRole, PoolStatus, PrizePool, PrizePreview, ProgramState, QualificationTier,
+    Registration, ReportStatus, ScoreCurve, StakeTier, SweptLeaf, TeamMember, WalletAgeRequirement,
+    WeekArchive, WeekInfo, WeekStats, WeekWinner, WinnerEntry, WinnerList,
+    BET_SETTLE_WINDOW_SECONDS, DELEGATE_DISTRIBUTE_PRIZES, DELEGATE_FINALIZE_LEADERBOARD,
+    EMERGENCY_TIMELOCK_SECONDS, FEATURE_CLAIMS, FEATURE_RAFFLES, MAX_AUTHORITY_HISTORY,
+    MAX_METADATA_URI_LEN, SECONDS_PER_DAY, STAKE_LOCK_SECONDS,
+};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::hash::hashv;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+use support::{
+    distribute_ix, enclave_distribute_ixs, g1_mul, known_log_proof, solo, solo_accounts,
+    TestContext, CATEGORY,
+};
+
+const WEEK: &str = "2024-W45";
+const POOL_SIZE: u64 = 10_000_000_000;
+
+#[test]
+fn distributes_first_prize() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE / 2);
+    let record = TestContext::distribution_record_address(&pool.prize_pool, 0);
+    assert!(ctx.svm.get_account(&record).is_some());
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE / 2);
+}
+
+#[test]
+fn rejects_low_scores() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 79)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    assert!(ctx.send(&[ix], &[]).is_err());
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE);
+}
+
+#[test]
+fn rejects_duplicate_winners() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+
+    let accounts = [
+        solo_accounts(&winner, winner_ata),
+        solo_accounts(&winner, winner_ata),
+    ]
+    .concat();
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95), solo(winner, 2, 90)],
+        0,
+        &accounts,
+    );
+    assert!(ctx.send(&[ix], &[]).is_err());
+    assert_eq!(ctx.token_balance(&winner_ata), 0);
+}
+
+#[test]
+fn winners_must_be_sorted_without_gaps() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let first = Pubkey::new_unique();
+    let first_ata = ctx.create_token_account(&first, 0);
+    let third = Pubkey::new_unique();
+    let third_ata = ctx.create_token_account(&third, 0);
+
+    let unsorted = [solo_accounts(&third, third_ata), solo_accounts(&first, first_ata)].concat();
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(third, 2, 90), solo(first, 1, 95)],
+        0,
+        &unsorted,
+    );
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let accounts = [solo_accounts(&first, first_ata), solo_accounts(&third, third_ata)].concat();
+    let gapped = vec![solo(first, 1, 95), solo(third, 3, 90)];
+    let ix = distribute_ix(&ctx, &pool, gapped.clone(), 0, &accounts);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let allow = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetRankGapsAllowed {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetRankGapsAllowed { allowed: true }.data(),
+    };
+    ctx.send(&[allow], &[]).expect("set_rank_gaps_allowed");
+
+    let ix = distribute_ix(&ctx, &pool, gapped, 0, &accounts);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&first_ata), POOL_SIZE / 2);
+    assert!(ctx.token_balance(&third_ata) > 0);
+}
+
+#[test]
+fn closed_winner_accounts_are_left_unpaid_for_retry() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let first = Pubkey::new_unique();
+    let first_ata = ctx.create_token_account(&first, 0);
+    let second = Pubkey::new_unique();
+    let closed = get_associated_token_address(&second, &pool.mint);
+    let winners = vec![solo(first, 1, 95), solo(second, 2, 90)];
+
+    // Only the winner's own associated token address counts as closed
+    let empty = Pubkey::new_unique();
+    let accounts = [solo_accounts(&first, first_ata), solo_accounts(&second, empty)].concat();
+    let ix = distribute_ix(&ctx, &pool, winners.clone(), 0, &accounts);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let accounts = [solo_accounts(&first, first_ata), solo_accounts(&second, closed)].concat();
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &accounts);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&first_ata), POOL_SIZE / 2);
+
+    let record_address = TestContext::distribution_record_address(&pool.prize_pool, 0);
+    let record: DistributionRecord = ctx.account(&record_address);
+    assert_eq!(record.unpaid.len(), 1);
+    let owed = record.unpaid[0].amount;
+    assert!(owed > 0);
+    assert_eq!(record.unpaid[0].owner, second);
+    assert_eq!(record.total_paid, POOL_SIZE / 2);
+    assert_eq!(record.winners, vec![first, second]);
+    assert_eq!(record.statuses, vec![PayoutStatus::Paid, PayoutStatus::Failed]);
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(prize_pool.unpaid_reserve, owed);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE / 2 + owed);
+
+    let retry = |destination| TestContext::retry_unpaid_ix(&pool, 0, 0, destination);
+    assert!(ctx.send(&[retry(first_ata)], &[]).is_err());
+
+    let second_ata = ctx.create_token_account(&second, 0);
+    ctx.send(&[retry(second_ata)], &[]).expect("retry_unpaid");
+    assert_eq!(ctx.token_balance(&second_ata), owed);
+    let record: DistributionRecord = ctx.account(&record_address);
+    assert!(record.unpaid[0].paid);
+    assert_eq!(record.status_of(&second), Some(PayoutStatus::Paid));
+    assert_eq!(record.total_paid, POOL_SIZE / 2 + owed);
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(prize_pool.unpaid_reserve, 0);
+
+    assert!(ctx.send(&[retry(second_ata)], &[]).is_err());
+}
+
+#[test]
+fn later_passes_split_the_first_pass_basis_and_skip_paid_ranks() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let mut wallets = Vec::new();
+    let mut atas = Vec::new();
+    for _ in 0..3 {
+        let wallet = Pubkey::new_unique();
+        atas.push(ctx.create_token_account(&wallet, 0));
+        wallets.push(wallet);
+    }
+    let accounts = |i: usize| solo_accounts(&wallets[i], atas[i]);
+
+    let ix = distribute_ix(&ctx, &pool, vec![solo(wallets[0], 1, 95)], 0, &accounts(0));
+    ctx.send(&[ix], &[]).expect("first pass");
+    assert_eq!(ctx.token_balance(&atas[0]), POOL_SIZE / 2);
+
+    // Rank 1 is paid, and skipping rank 2 would leave a gap
+    let ix = distribute_ix(&ctx, &pool, vec![solo(wallets[1], 1, 95)], 1, &accounts(1));
+    let logs = ctx.send(&[ix], &[]).expect_err("rank paid twice").meta.logs;
+    assert!(logs.iter().any(|log| log.contains("RankAlreadyPaid")));
+    let ix = distribute_ix(&ctx, &pool, vec![solo(wallets[1], 3, 90)], 1, &accounts(1));
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    // Half the vault is left, yet rank 2 still gets 20% of the first pass's basis
+    let ix = distribute_ix(&ctx, &pool, vec![solo(wallets[1], 2, 90)], 1, &accounts(1));
+    ctx.send(&[ix], &[]).expect("second pass");
+    assert_eq!(ctx.token_balance(&atas[1]), POOL_SIZE / 5);
+    let ix = distribute_ix(&ctx, &pool, vec![solo(wallets[2], 3, 85)], 2, &accounts(2));
+    ctx.send(&[ix], &[]).expect("third pass");
+    assert_eq!(ctx.token_balance(&atas[2]), POOL_SIZE / 10);
+
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(prize_pool.distribution_basis, POOL_SIZE);
+    assert_eq!(prize_pool.paid_ranks, 0b111);
+    let record_address = TestContext::distribution_record_address(&pool.prize_pool, 1);
+    let record: DistributionRecord = ctx.account(&record_address);
+    assert_eq!(record.paid_ranks, 0b11);
+}
+
+#[test]
+fn distributes_winners_staged_in_a_winner_list() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let ix = ctx.open_winner_list_ix(&pool);
+    ctx.send(&[ix], &[]).expect("open_winner_list");
+
+    // Bits outside the rank and the team flag are refused
+    let mut malformed = ListedWinner::new(Pubkey::new_unique(), 1, 95, false);
+    malformed.rank_flags |= 0x40;
+    let ix = ctx.append_winners_ix(&pool, &[malformed]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let mut listed = Vec::new();
+    let mut accounts = Vec::new();
+    let mut token_accounts = Vec::new();
+    for rank in 1..=3 {
+        let wallet = Pubkey::new_unique();
+        let token_account = ctx.create_token_account(&wallet, 0);
+        listed.push(ListedWinner::new(wallet, rank, 95 - rank, false));
+        accounts.extend(solo_accounts(&wallet, token_account));
+        token_accounts.push(token_account);
+    }
+    // Staged over two transactions, as a list too long for one would be
+    let ix = ctx.append_winners_ix(&pool, &listed[..2]);
+    ctx.send(&[ix], &[]).expect("append_winners");
+    let ix = ctx.append_winners_ix(&pool, &listed[2..]);
+    ctx.send(&[ix], &[]).expect("append_winners");
+
+    let list_address = TestContext::winner_list_address(&pool.prize_pool);
+    let list: WinnerList = ctx.account(&list_address);
+    assert_eq!(list.count, 3);
+    assert_eq!(&list.entries[..3], &listed[..]);
+
+    // The list replaces the argument, so naming winners in both is refused
+    let ix = distribute_ix(&ctx, &pool, vec![solo(listed[0].wallet, 1, 94)], 0, &accounts);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = distribute_ix(&ctx, &pool, vec![], 0, &accounts);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&token_accounts[0]), POOL_SIZE / 2);
+    assert!(token_accounts[1..].iter().all(|account| ctx.token_balance(account) > 0));
+    let list: WinnerList = ctx.account(&list_address);
+    assert_eq!(list.count, 0);
+}
+
+#[test]
+fn rejects_foreign_authority() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+
+    let intruder = Keypair::new();
+    ctx.svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+    let mut ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    let authority = ctx.authority.pubkey();
+    let signer = ix.accounts.iter_mut().find(|meta| meta.pubkey == authority).unwrap();
+    signer.pubkey = intruder.pubkey();
+    assert!(ctx.send(&[ix], &[&intruder]).is_err());
+}
+
+#[test]
+fn leftovers_need_guardian_timelocked_withdrawal() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let close = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::ClosePrizePool {
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            authority: ctx.authority.pubkey(),
+            closer: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::ClosePrizePool {}.data(),
+    };
+    assert!(ctx.send(std::slice::from_ref(&close), &[]).is_err());
+
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+
+    let treasury = Pubkey::new_unique();
+    let treasury_ata = ctx.create_token_account(&treasury, 0);
+    let announce = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::AnnounceEmergencyWithdraw {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            destination: treasury_ata,
+            guardian: guardian.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::AnnounceEmergencyWithdraw {}.data(),
+    };
+    ctx.send(&[announce], &[&guardian]).expect("announce_emergency_withdraw");
+
+    let withdraw = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::EmergencyWithdraw {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            destination: treasury_ata,
+            guardian: guardian.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::EmergencyWithdraw {}.data(),
+    };
+    assert!(ctx.send(std::slice::from_ref(&withdraw), &[&guardian]).is_err());
+
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += EMERGENCY_TIMELOCK_SECONDS;
+    ctx.svm.set_sysvar(&clock);
+    ctx.send(&[withdraw], &[&guardian]).expect("emergency_withdraw");
+    assert_eq!(ctx.token_balance(&treasury_ata), POOL_SIZE);
+
+    ctx.send(&[close], &[]).expect("close_prize_pool");
+    assert!(ctx.svm.get_account(&pool.prize_pool).is_none());
+}
+
+#[test]
+fn emergency_withdrawal_leaves_the_sponsor_reserve() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let sponsor = Keypair::new();
+    ctx.svm.airdrop(&sponsor.pubkey(), 1_000_000_000).unwrap();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetBonusMultiplier {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetBonusMultiplier {
+            bonus_multiplier_bps: 20_000,
+            sponsor: sponsor.pubkey(),
+        }
+        .data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_bonus_multiplier");
+    let sponsor_ata = ctx.create_token_account(&sponsor.pubkey(), POOL_SIZE);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::FundBonus {
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            sponsor_token_account: sponsor_ata,
+            bonus_sponsor: sponsor.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::FundBonus { amount: POOL_SIZE }.data(),
+    };
+    ctx.send(&[ix], &[&sponsor]).expect("fund_bonus");
+
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+    let treasury_ata = ctx.create_token_account(&Pubkey::new_unique(), 0);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::AnnounceEmergencyWithdraw {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            destination: treasury_ata,
+            guardian: guardian.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::AnnounceEmergencyWithdraw {}.data(),
+    };
+    ctx.send(&[ix], &[&guardian]).expect("announce_emergency_withdraw");
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += EMERGENCY_TIMELOCK_SECONDS;
+    ctx.svm.set_sysvar(&clock);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::EmergencyWithdraw {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            destination: treasury_ata,
+            guardian: guardian.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::EmergencyWithdraw {}.data(),
+    };
+    ctx.send(&[ix], &[&guardian]).expect("emergency_withdraw");
+    assert_eq!(ctx.token_balance(&treasury_ata), POOL_SIZE);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE);
+
+    // The week is cancelled, so the sponsor gets the reserve back without a payout
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::RefundBonus {
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            sponsor_token_account: sponsor_ata,
+            authority: ctx.authority.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::RefundBonus {}.data(),
+    };
+    ctx.send(&[ix], &[]).expect("refund_bonus");
+    assert_eq!(ctx.token_balance(&sponsor_ata), POOL_SIZE);
+    assert_eq!(ctx.token_balance(&pool.token_account), 0);
+}
+
+#[test]
+fn rejects_replayed_distribution() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let winners = vec![solo(winner, 1, 95)];
+
+    let ix = distribute_ix(&ctx, &pool, winners.clone(), 0, &solo_accounts(&winner, winner_ata));
+    ctx.send(std::slice::from_ref(&ix), &[]).expect("first distribution");
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let next = vec![solo(winner, 2, 90)];
+    let ix = distribute_ix(&ctx, &pool, next, 1, &solo_accounts(&winner, winner_ata));
+    ctx.send(&[ix], &[]).expect("next nonce");
+}
+
+#[test]
+fn enforces_winner_count() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool_with(WEEK, 2, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+
+    let empty = distribute_ix(&ctx, &pool, vec![], 0, &solo_accounts(&winner, winner_ata));
+    assert!(ctx.send(&[empty], &[]).is_err());
+
+    let beyond_max = vec![solo(winner, 3, 95)];
+    let ix = distribute_ix(&ctx, &pool, beyond_max, 0, &solo_accounts(&winner, winner_ata));
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let too_many = (1..=3)
+        .map(|rank| solo(winner, rank.min(2), 95))
+        .collect();
+    let ix = distribute_ix(&ctx, &pool, too_many, 0, &solo_accounts(&winner, winner_ata));
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn splits_team_prize_between_members() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let captain = Keypair::new();
+    ctx.svm.airdrop(&captain.pubkey(), 1_000_000_000).unwrap();
+    let mate = Pubkey::new_unique();
+    let captain_ata = ctx.create_token_account(&captain.pubkey(), 0);
+    let mate_ata = ctx.create_token_account(&mate, 0);
+
+    let team = TestContext::team_address(&captain.pubkey());
+    let register = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::RegisterTeam {
+            team,
+            captain: captain.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::RegisterTeam {
+            members: vec![
+                TeamMember { wallet: captain.pubkey(), share_bps: 6_000 },
+                TeamMember { wallet: mate, share_bps: 4_000 },
+            ],
+        }
+        .data(),
+    };
+    ctx.send(&[register], &[&captain]).expect("register_team");
+
+    let entry = WinnerEntry { team: Some(team), ..solo(captain.pubkey(), 1, 90) };
+    let ix = distribute_ix(&ctx, &pool, vec![entry], 0, &[team, captain_ata, mate_ata]);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    let prize = POOL_SIZE / 2;
+    assert_eq!(ctx.token_balance(&captain_ata), prize * 6 / 10);
+    assert_eq!(ctx.token_balance(&mate_ata), prize * 4 / 10);
+}
+
+#[test]
+fn rejects_destination_of_another_wallet() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let someone_else = ctx.create_token_account(&Pubkey::new_unique(), 0);
+
+    let ix = distribute_ix(&ctx, &pool, vec![solo(winner, 1, 95)], 0, &solo_accounts(&winner, someone_else));
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn routes_profile_split_to_secondary_wallet() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let coach = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let coach_ata = ctx.create_token_account(&coach, 0);
+    let profile = TestContext::player_profile_address(&winner.pubkey());
+
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    let set_split = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPrizeSplit {
+            player_profile: profile,
+            player: winner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPrizeSplit {
+            recipient: Some(coach),
+            split_bps: 1_000,
+        }
+        .data(),
+    };
+    ctx.send(&[create, set_split], &[&winner]).expect("configure split");
+
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner.pubkey(), 1, 95)],
+        0,
+        &[profile, winner_ata, coach_ata],
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    let prize = POOL_SIZE / 2;
+    assert_eq!(ctx.token_balance(&coach_ata), prize / 10);
+    assert_eq!(ctx.token_balance(&winner_ata), prize - prize / 10);
+}
+
+#[test]
+fn unpaid_split_leaves_the_entry_partially_paid() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let coach = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let coach_ata = get_associated_token_address(&coach, &pool.mint);
+    let profile = TestContext::player_profile_address(&winner.pubkey());
+
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    let set_split = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPrizeSplit {
+            player_profile: profile,
+            player: winner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPrizeSplit {
+            recipient: Some(coach),
+            split_bps: 1_000,
+        }
+        .data(),
+    };
+    ctx.send(&[create, set_split], &[&winner]).expect("configure split");
+
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner.pubkey(), 1, 95)],
+        0,
+        &[profile, winner_ata, coach_ata],
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    // The winner got their leg, only the coach's waits for retry_unpaid
+    let prize = POOL_SIZE / 2;
+    assert_eq!(ctx.token_balance(&winner_ata), prize - prize / 10);
+    let record_address = TestContext::distribution_record_address(&pool.prize_pool, 0);
+    let record: DistributionRecord = ctx.account(&record_address);
+    assert_eq!(record.statuses, vec![PayoutStatus::Partial]);
+
+    let coach_ata = ctx.create_token_account(&coach, 0);
+    let ix = TestContext::retry_unpaid_ix(&pool, 0, 0, coach_ata);
+    ctx.send(&[ix], &[]).expect("retry_unpaid");
+    let record: DistributionRecord = ctx.account(&record_address);
+    assert_eq!(record.statuses, vec![PayoutStatus::Paid]);
+}
+
+#[test]
+fn takes_creator_royalty_once_per_week() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.update_config_ix(500);
+    ctx.send(&[ix], &[]).expect("update_config");
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let winners = vec![solo(winner, 2, 90)];
+
+    let ix = distribute_ix(&ctx, &pool, winners.clone(), 0, &solo_accounts(&winner, winner_ata));
+    ctx.send(&[ix], &[]).expect("first pass");
+    let royalty = POOL_SIZE * 5 / 100;
+    assert_eq!(ctx.token_balance(&ctx.creator_token_account), royalty);
+    assert_eq!(ctx.token_balance(&winner_ata), (POOL_SIZE - royalty) / 5);
+
+    let winners = vec![solo(winner, 3, 90)];
+    let ix = distribute_ix(&ctx, &pool, winners, 1, &solo_accounts(&winner, winner_ata));
+    ctx.send(&[ix], &[]).expect("second pass");
+    assert_eq!(ctx.token_balance(&ctx.creator_token_account), royalty);
+}
+
+#[test]
+fn fund_pool_tracks_deposits() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let sponsor = Keypair::new();
+    ctx.svm.airdrop(&sponsor.pubkey(), 1_000_000_000).unwrap();
+    let sponsor_ata = ctx.create_token_account(&sponsor.pubkey(), 500);
+
+    let ix = ctx.fund_pool_ix(&pool, &sponsor.pubkey(), sponsor_ata, 500, "sponsor week");
+    ctx.send(&[ix], &[&sponsor]).expect("fund_pool");
+
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.total_funded, POOL_SIZE + 500);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE + 500);
+}
+
+#[test]
+fn enforces_funder_cap_and_whitelist() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let sponsor = Keypair::new();
+    ctx.svm.airdrop(&sponsor.pubkey(), 1_000_000_000).unwrap();
+    let sponsor_ata = ctx.create_token_account(&sponsor.pubkey(), 1_000);
+
+    let rules = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetFundingRules {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetFundingRules {
+            funder_cap: 600,
+            whitelist_only: true,
+        }
+        .data(),
+    };
+    ctx.send(&[rules], &[]).expect("set_funding_rules");
+
+    let deposit = ctx.fund_pool_ix(&pool, &sponsor.pubkey(), sponsor_ata, 500, "");
+    assert!(ctx.send(std::slice::from_ref(&deposit), &[&sponsor]).is_err());
+
+    let allow = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetFunderAllowed {
+            prize_pool: pool.prize_pool,
+            funder_record: TestContext::funder_record_address(&pool.prize_pool, &sponsor.pubkey()),
+            authority: ctx.authority.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetFunderAllowed {
+            funder: sponsor.pubkey(),
+            allowed: true,
+        }
+        .data(),
+    };
+    ctx.send(&[allow], &[]).expect("set_funder_allowed");
+    ctx.send(std::slice::from_ref(&deposit), &[&sponsor]).expect("whitelisted deposit");
+
+    // 500 + 500 is over the 600 cap
+    assert!(ctx.send(&[deposit], &[&sponsor]).is_err());
+    assert_eq!(ctx.token_balance(&pool.token_account), 500);
+}
+
+#[test]
+fn categories_keep_separate_pools_and_splits() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let top = ctx.funded_pool(WEEK, POOL_SIZE);
+    let funniest = ctx.funded_category_pool(WEEK, "funniest-pardon", 2, &[7_000, 3_000], POOL_SIZE);
+    assert_ne!(top.prize_pool, funniest.prize_pool);
+
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &funniest,
+        vec![solo(winner, 1, 90)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE * 7 / 10);
+    assert_eq!(ctx.token_balance(&top.token_account), POOL_SIZE);
+
+    // One share per paid rank, summing to at most 100%
+    let ix = ctx.initialize_prize_pool_ix(WEEK, "community-choice", 2, &[9_000, 2_000]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn exclusive_pools_pay_one_category_per_wallet() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let top = ctx.funded_pool(WEEK, POOL_SIZE);
+    let funniest = ctx.funded_category_pool(WEEK, "funniest-pardon", MAX_RANK, &[], POOL_SIZE);
+    for pool in [&top, &funniest] {
+        let ix = Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetExclusiveWinners {
+                prize_pool: pool.prize_pool,
+                authority: ctx.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetExclusiveWinners { exclusive: true }.data(),
+        };
+        ctx.send(&[ix], &[]).expect("set_exclusive_winners");
+    }
+
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let marker = TestContext::week_winner_address(WEEK, &winner);
+    let [profile, _] = solo_accounts(&winner, winner_ata);
+    let accounts = [marker, profile, winner_ata];
+
+    let ix = distribute_ix(&ctx, &top, vec![solo(winner, 1, 95)], 0, &accounts);
+    ctx.send(&[ix], &[]).expect("first category");
+    let record: WeekWinner = ctx.account(&marker);
+    assert_eq!(record.category, CATEGORY);
+
+    // Same wallet, another category of the same week
+    let ix = distribute_ix(&ctx, &funniest, vec![solo(winner, 1, 95)], 0, &accounts);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    assert_eq!(ctx.token_balance(&funniest.token_account), POOL_SIZE);
+}
+
+#[test]
+fn win_cooldown_blocks_back_to_back_podiums() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let cooldown = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetWinCooldown {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetWinCooldown { cooldown_rank: 3 }.data(),
+    };
+    ctx.send(&[cooldown], &[]).expect("set_win_cooldown");
+
+    let last_week = ctx.funded_pool("2024-W44", POOL_SIZE);
+    let this_week = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    ctx.send(&[create], &[&winner]).expect("create_player_profile");
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let accounts = solo_accounts(&winner.pubkey(), winner_ata);
+
+    let ix = distribute_ix(&ctx, &last_week, vec![solo(winner.pubkey(), 2, 90)], 0, &accounts);
+    ctx.send(&[ix], &[]).expect("last week's podium");
+    let profile: PlayerProfile = ctx.account(&accounts[0]);
+    assert_eq!(profile.last_podium_week, "2024-W44");
+
+    let ix = distribute_ix(&ctx, &this_week, vec![solo(winner.pubkey(), 1, 95)], 0, &accounts);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    // Ranks past the threshold are still open to last week's podium
+    let ix = distribute_ix(&ctx, &this_week, vec![solo(winner.pubkey(), 4, 95)], 0, &accounts);
+    ctx.send(&[ix], &[]).expect("rank outside the cooldown");
+}
+
+#[test]
+fn submit_score_normalizes_with_pool_curve() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let normalization = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetScoreNormalization {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetScoreNormalization {
+            raw_score_max: 1_000,
+            score_curve: ScoreCurve::SquareRoot,
+        }
+        .data(),
+    };
+    ctx.send(&[normalization], &[]).expect("set_score_normalization");
+
+    let player = Pubkey::new_unique();
+    let entry = TestContext::entry_address(&pool.prize_pool, &player);
+
+    // 100 * sqrt(640 / 1000) = 80
+    let ix = ctx.submit_score_ix(&pool, &player, 640);
+    ctx.send(&[ix], &[]).expect("submit_score");
+    let state: Entry = ctx.account(&entry);
+    assert_eq!((state.raw_score, state.score), (640, 80));
+
+    let ix = ctx.submit_score_ix(&pool, &player, 1_001);
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn judge_quorum_drops_the_highest_and_lowest_score() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let judges: Vec<Keypair> = (0..4).map(|_| Keypair::new()).collect();
+    for judge in &judges {
+        ctx.svm.airdrop(&judge.pubkey(), 1_000_000_000).unwrap();
+    }
+    let panel: Vec<Pubkey> = judges.iter().map(|judge| judge.pubkey()).collect();
+    let player = Pubkey::new_unique();
+    let score = |judge: &Keypair, raw_score: u16| {
+        TestContext::submit_judge_score_ix(&pool, &judge.pubkey(), &player, raw_score)
+    };
+    let judging_ends_at = ctx.svm.get_sysvar::<Clock>().unix_timestamp + 3_600;
+
+    // Dropping both outliers takes at least three scores
+    let ix = ctx.set_judges_ix(&pool, panel.clone(), 2, judging_ends_at);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.set_judges_ix(&pool, vec![panel[0]; 3], 3, judging_ends_at);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.set_judges_ix(&pool, panel.clone(), 3, 0);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.set_judges_ix(&pool, panel, 3, judging_ends_at);
+    ctx.send(&[ix], &[]).expect("set_judges");
+
+    let ix = ctx.submit_score_ix(&pool, &player, 90);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let outsider = Keypair::new();
+    ctx.svm.airdrop(&outsider.pubkey(), 1_000_000_000).unwrap();
+    assert!(ctx.send(&[score(&outsider, 90)], &[&outsider]).is_err());
+
+    ctx.send(&[score(&judges[0], 100)], &[&judges[0]]).expect("submit_judge_score");
+    ctx.send(&[score(&judges[1], 10)], &[&judges[1]]).expect("submit_judge_score");
+    assert!(ctx.send(&[score(&judges[1], 20)], &[&judges[1]]).is_err());
+    let ix = ctx.aggregate_scores_ix(&pool, &player);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    // A quorum waits for the rest of the panel while judging is open
+    ctx.send(&[score(&judges[2], 80)], &[&judges[2]]).expect("submit_judge_score");
+    let ix = ctx.aggregate_scores_ix(&pool, &player);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    ctx.send(&[score(&judges[3], 90)], &[&judges[3]]).expect("submit_judge_score");
+    let ix = ctx.aggregate_scores_ix(&pool, &player);
+    ctx.send(&[ix], &[]).expect("aggregate_scores");
+    let state: Entry = ctx.account(&TestContext::entry_address(&pool.prize_pool, &player));
+    assert_eq!((state.raw_score, state.score), (85, 85));
+
+    let ix = ctx.aggregate_scores_ix(&pool, &player);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    assert!(ctx.send(&[score(&judges[3], 95)], &[&judges[3]]).is_err());
+
+    // After the deadline a quorum is enough, and no more scores are taken
+    let late = Pubkey::new_unique();
+    for (judge, raw_score) in judges[..3].iter().zip([70, 60, 50]) {
+        let ix = TestContext::submit_judge_score_ix(&pool, &judge.pubkey(), &late, raw_score);
+        ctx.send(&[ix], &[judge]).expect("submit_judge_score");
+    }
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = judging_ends_at;
+    ctx.svm.set_sysvar(&clock);
+    let ix = TestContext::submit_judge_score_ix(&pool, &judges[3].pubkey(), &late, 40);
+    assert!(ctx.send(&[ix], &[&judges[3]]).is_err());
+    let ix = ctx.aggregate_scores_ix(&pool, &late);
+    ctx.send(&[ix], &[]).expect("aggregate_scores");
+    let state: Entry = ctx.account(&TestContext::entry_address(&pool.prize_pool, &late));
+    assert_eq!(state.raw_score, 60);
+}
+
+#[test]
+fn finalize_entry_weights_judge_score() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let weights = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetScoreWeights {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetScoreWeights {
+            judge_weight: 3,
+            vote_weight: 1,
+        }
+        .data(),
+    };
+    ctx.send(&[weights], &[]).expect("set_score_weights");
+
+    let player = Pubkey::new_unique();
+    let ix = ctx.submit_score_ix(&pool, &player, 85);
+    ctx.send(&[ix], &[]).expect("submit_score");
+    let ix = ctx.finalize_entry_ix(&pool, &player);
+    ctx.send(&[ix], &[]).expect("finalize_entry");
+
+    let entry: Entry = ctx.account(&TestContext::entry_address(&pool.prize_pool, &player));
+    assert!(entry.finalized);
+    assert_eq!(entry.final_score, 255);
+
+    // Finalized entries are locked
+    let ix = ctx.submit_score_ix(&pool, &player, 100);
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn votes_are_capped_by_balance_and_counted_once() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_category_pool(WEEK, "community-choice", MAX_RANK, &[], 0);
+    let player = Pubkey::new_unique();
+    let ix = ctx.submit_score_ix(&pool, &player, 90);
+    ctx.send(&[ix], &[]).expect("submit_score");
+
+    let voter = Keypair::new();
+    ctx.svm.airdrop(&voter.pubkey(), 1_000_000_000).unwrap();
+    let voter_ata = ctx.create_token_account(&voter.pubkey(), 500);
+
+    let ix = ctx.vote_for_entry_ix(&pool, &player, &voter.pubkey(), voter_ata, 501);
+    assert!(ctx.send(&[ix], &[&voter]).is_err());
+
+    let ix = ctx.vote_for_entry_ix(&pool, &player, &voter.pubkey(), voter_ata, 300);
+    ctx.send(&[ix], &[&voter]).expect("vote_for_entry");
+    let ix = ctx.vote_for_entry_ix(&pool, &player, &voter.pubkey(), voter_ata, 200);
+    assert!(ctx.send(&[ix], &[&voter]).is_err());
+
+    let entry: Entry = ctx.account(&TestContext::entry_address(&pool.prize_pool, &player));
+    assert_eq!(entry.votes, 300);
+}
+
+#[test]
+fn quadratic_votes_count_square_root_of_weight() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let quadratic = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetQuadraticVotes {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetQuadraticVotes { enabled: true }.data(),
+    };
+    ctx.send(&[quadratic], &[]).expect("set_quadratic_votes");
+
+    let pool = ctx.funded_category_pool(WEEK, "community-choice", MAX_RANK, &[], 0);
+    let player = Pubkey::new_unique();
+    let ix = ctx.submit_score_ix(&pool, &player, 90);
+    ctx.send(&[ix], &[]).expect("submit_score");
+
+    let whale = Keypair::new();
+    ctx.svm.airdrop(&whale.pubkey(), 1_000_000_000).unwrap();
+    let whale_ata = ctx.create_token_account(&whale.pubkey(), 1_000_000);
+    let ix = ctx.vote_for_entry_ix(&pool, &player, &whale.pubkey(), whale_ata, 1_000_000);
+    ctx.send(&[ix], &[&whale]).expect("vote_for_entry");
+
+    let entry: Entry = ctx.account(&TestContext::entry_address(&pool.prize_pool, &player));
+    assert_eq!(entry.votes, 1_000);
+    // The full weight is still locked
+    assert_eq!(ctx.token_balance(&whale_ata), 0);
+}
+
+#[test]
+fn vote_tokens_stay_escrowed_until_distribution() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_category_pool(WEEK, "community-choice", MAX_RANK, &[], POOL_SIZE);
+    let player = Pubkey::new_unique();
+    let ix = ctx.submit_score_ix(&pool, &player, 90);
+    ctx.send(&[ix], &[]).expect("submit_score");
+
+    let voter = Keypair::new();
+    ctx.svm.airdrop(&voter.pubkey(), 1_000_000_000).unwrap();
+    let voter_ata = ctx.create_token_account(&voter.pubkey(), 500);
+    let ix = ctx.vote_for_entry_ix(&pool, &player, &voter.pubkey(), voter_ata, 300);
+    ctx.send(&[ix], &[&voter]).expect("vote_for_entry");
+    assert_eq!(ctx.token_balance(&voter_ata), 200);
+    assert_eq!(ctx.token_balance(&TestContext::vote_vault_address(&pool.prize_pool)), 300);
+
+    let unlock = TestContext::unlock_votes_ix(&pool, &voter.pubkey(), voter_ata);
+    assert!(ctx.send(std::slice::from_ref(&unlock), &[&voter]).is_err());
+
+    let winner_ata = ctx.create_token_account(&player, 0);
+    let winners = vec![solo(player, 1, 90)];
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &solo_accounts(&player, winner_ata));
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    ctx.send(&[unlock], &[&voter]).expect("unlock_votes");
+    assert_eq!(ctx.token_balance(&voter_ata), 500);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.votes_escrowed, 0);
+}
+
+#[test]
+fn vote_tokens_unlock_on_claimed_and_cancelled_weeks() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let claimed = ctx.funded_category_pool(WEEK, "community-choice", MAX_RANK, &[], POOL_SIZE);
+    let cancelled =
+        ctx.funded_category_pool("2024-W44", "community-choice", MAX_RANK, &[], POOL_SIZE);
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    let voter = Keypair::new();
+    ctx.svm.airdrop(&voter.pubkey(), 1_000_000_000).unwrap();
+    let voter_ata = ctx.create_token_account(&voter.pubkey(), 500);
+    for pool in [&claimed, &cancelled] {
+        let ix = ctx.submit_score_ix(pool, &player.pubkey(), 90);
+        ctx.send(&[ix], &[]).expect("submit_score");
+        let ix = ctx.vote_for_entry_ix(pool, &player.pubkey(), &voter.pubkey(), voter_ata, 100);
+        ctx.send(&[ix], &[&voter]).expect("vote_for_entry");
+    }
+
+    // A claim pays the week out without moving the distribution nonce
+    let unlock = TestContext::unlock_votes_ix(&claimed, &voter.pubkey(), voter_ata);
+    assert!(ctx.send(std::slice::from_ref(&unlock), &[&voter]).is_err());
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let leaf = leaderboard_leaf(&player.pubkey(), 1, 90);
+    let ix = ctx.finalize_leaderboard_ix(&claimed, leaf, 1);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let player_ata = ctx.create_token_account(&player.pubkey(), 0);
+    let ix = ctx.claim_prize_ix(&claimed, &player.pubkey(), player_ata, 1, 90, Vec::new());
+    ctx.send(&[ix], &[&player]).expect("claim_prize");
+    ctx.send(&[unlock], &[&voter]).expect("unlock_votes");
+    assert_eq!(ctx.token_balance(&voter_ata), 400);
+
+    // A cancelled week never pays out, so its votes unlock once it is cancelled
+    let unlock = TestContext::unlock_votes_ix(&cancelled, &voter.pubkey(), voter_ata);
+    assert!(ctx.send(std::slice::from_ref(&unlock), &[&voter]).is_err());
+    ctx.cancel_week(&cancelled);
+    ctx.send(&[unlock], &[&voter]).expect("unlock_votes");
+    assert_eq!(ctx.token_balance(&voter_ata), 500);
+    let state: PrizePool = ctx.account(&cancelled.prize_pool);
+    assert_eq!(state.votes_escrowed, 0);
+}
+
+#[test]
+fn holder_weeks_require_a_balance_snapshot() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let slot = ctx.svm.get_sysvar::<Clock>().slot;
+    let ix = ctx.set_holder_requirement_ix(&pool, 1_000, slot, slot + 100);
+    ctx.send(&[ix], &[]).expect("set_holder_requirement");
+    // The window is open, so neither the bar nor the window can move
+    let ix = ctx.set_holder_requirement_ix(&pool, 1, slot, slot + 100);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 500);
+    let ix = TestContext::record_balance_snapshot_ix(&pool, &winner.pubkey(), winner_ata);
+    ctx.send(&[ix], &[&winner]).expect("record_balance_snapshot");
+
+    // Topping up and snapshotting again doesn't replace the first snapshot
+    ctx.set_token_balance(&winner_ata, 1_500);
+    let ix = TestContext::record_balance_snapshot_ix(&pool, &winner.pubkey(), winner_ata);
+    assert!(ctx.send(&[ix], &[&winner]).is_err());
+
+    let snapshot = TestContext::snapshot_address(&pool.prize_pool, &winner.pubkey());
+    let [profile, _] = solo_accounts(&winner.pubkey(), winner_ata);
+    let accounts = [snapshot, profile, winner_ata];
+    let ix = distribute_ix(&ctx, &pool, vec![solo(winner.pubkey(), 1, 95)], 0, &accounts);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let holder = Keypair::new();
+    ctx.svm.airdrop(&holder.pubkey(), 1_000_000_000).unwrap();
+    let holder_ata = ctx.create_token_account(&holder.pubkey(), 1_500);
+    let ix = TestContext::record_balance_snapshot_ix(&pool, &holder.pubkey(), holder_ata);
+    ctx.send(&[ix], &[&holder]).expect("record_balance_snapshot");
+
+    // Snapshots taken after the window closes are refused
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.slot = slot + 100;
+    ctx.svm.set_sysvar(&clock);
+    let late = Keypair::new();
+    ctx.svm.airdrop(&late.pubkey(), 1_000_000_000).unwrap();
+    let late_ata = ctx.create_token_account(&late.pubkey(), 1_500);
+    let ix = TestContext::record_balance_snapshot_ix(&pool, &late.pubkey(), late_ata);
+    assert!(ctx.send(&[ix], &[&late]).is_err());
+
+    let snapshot = TestContext::snapshot_address(&pool.prize_pool, &holder.pubkey());
+    let [profile, _] = solo_accounts(&holder.pubkey(), holder_ata);
+    let accounts = [snapshot, profile, holder_ata];
+    let ix = distribute_ix(&ctx, &pool, vec![solo(holder.pubkey(), 1, 95)], 0, &accounts);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&holder_ata), 1_500 + POOL_SIZE / 2);
+}
+
+#[test]
+fn claim_many_pays_every_missed_week_at_once() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let earlier = ctx.funded_pool("2024-W44", POOL_SIZE);
+    let later = ctx.funded_pool(WEEK, POOL_SIZE);
+    let (winner, other) = (Keypair::new(), Pubkey::new_unique());
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let first_leaf = leaderboard_leaf(&winner.pubkey(), 1, 95);
+    let second_leaf = leaderboard_leaf(&winner.pubkey(), 2, 90);
+    let other_leaf = leaderboard_leaf(&other, 1, 99);
+    let ix = ctx.finalize_leaderboard_ix(&earlier, hash_pair(&first_leaf, &other_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let ix = ctx.finalize_leaderboard_ix(&later, hash_pair(&other_leaf, &second_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    let first = ClaimRequest { rank: 1, score: 95, proof: vec![other_leaf] };
+    let second = ClaimRequest { rank: 2, score: 90, proof: vec![other_leaf] };
+
+    // One pool twice would need its receipt created twice
+    let ix = ctx.claim_many_ix(
+        &[&earlier, &earlier],
+        &winner.pubkey(),
+        winner_ata,
+        vec![first.clone(), first.clone()],
+    );
+    assert!(ctx.send(&[ix], &[&winner]).is_err());
+
+    let ix = ctx.claim_many_ix(
+        &[&earlier, &later],
+        &winner.pubkey(),
+        winner_ata,
+        vec![first.clone(), second],
+    );
+    ctx.send(std::slice::from_ref(&ix), &[&winner]).expect("claim_many");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE / 2 + POOL_SIZE / 5);
+    for (pool, rank) in [(&earlier, 1), (&later, 2)] {
+        let receipt = TestContext::claim_record_address(&pool.prize_pool, &winner.pubkey());
+        let record: ClaimRecord = ctx.account(&receipt);
+        assert_eq!(record.rank, rank);
+        let stats: WeekStats = ctx.account(&TestContext::week_stats_address(&pool.prize_pool));
+        assert_eq!(stats.unique_claimers, 1);
+    }
+
+    // The receipts stop a second batch, and a single claim, from paying again
+    assert!(ctx.send(&[ix], &[&winner]).is_err());
+    let claim = ctx.claim_prize_ix(&earlier, &winner.pubkey(), winner_ata, 1, 95, first.proof);
+    assert!(ctx.send(&[claim], &[&winner]).is_err());
+}
+
+#[test]
+fn holder_weeks_check_the_snapshot_at_claim_time() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let slot = ctx.svm.get_sysvar::<Clock>().slot;
+    let ix = ctx.set_holder_requirement_ix(&pool, 1_000, slot, slot + 100);
+    ctx.send(&[ix], &[]).expect("set_holder_requirement");
+
+    let (first, second) = (Keypair::new(), Keypair::new());
+    let mut atas = Vec::new();
+    for (player, balance) in [(&first, 1_000), (&second, 999)] {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let ata = ctx.create_token_account(&player.pubkey(), balance);
+        let ix = TestContext::record_balance_snapshot_ix(&pool, &player.pubkey(), ata);
+        ctx.send(&[ix], &[player]).expect("record_balance_snapshot");
+        atas.push(ata);
+    }
+
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let first_leaf = leaderboard_leaf(&first.pubkey(), 1, 95);
+    let second_leaf = leaderboard_leaf(&second.pubkey(), 2, 90);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&first_leaf, &second_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    let claim = ctx.claim_prize_ix(&pool, &second.pubkey(), atas[1], 2, 90, vec![first_leaf]);
+    assert!(ctx.send(&[claim], &[&second]).is_err());
+    let claim = ctx.claim_prize_ix(&pool, &first.pubkey(), atas[0], 1, 95, vec![second_leaf]);
+    ctx.send(&[claim], &[&first]).expect("claim_prize");
+    assert_eq!(ctx.token_balance(&atas[0]), 1_000 + POOL_SIZE / 2);
+}
+
+#[test]
+fn stake_tier_boosts_prize() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let tiers = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetStakeTiers {
+            config: TestContext::config_address(),
+            stake_mint: ctx.mint,
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetStakeTiers {
+            stake_tiers: vec![
+                StakeTier { min_stake: 1_000, multiplier_bps: 12_500 },
+                StakeTier { min_stake: 10_000, multiplier_bps: 15_000 },
+            ],
+        }
+        .data(),
+    };
+    ctx.send(&[tiers], &[]).expect("set_stake_tiers");
+
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 10_000);
+    let ix = ctx.stake_ix(&winner.pubkey(), winner_ata, 10_000);
+    ctx.send(&[ix], &[&winner]).expect("stake");
+
+    let unstake = TestContext::unstake_ix(&winner.pubkey(), winner_ata, 10_000);
+    assert!(ctx.send(std::slice::from_ref(&unstake), &[&winner]).is_err());
+
+    // Rank 1 alone leaves half the pool, enough for the full 1.5x boost
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let stake = TestContext::stake_address(&winner.pubkey());
+    let [profile, _] = solo_accounts(&winner.pubkey(), winner_ata);
+    let winners = vec![solo(winner.pubkey(), 1, 95)];
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &[stake, profile, winner_ata]);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE / 2 + POOL_SIZE / 4);
+
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += STAKE_LOCK_SECONDS;
+    ctx.svm.set_sysvar(&clock);
+    ctx.send(&[unstake], &[&winner]).expect("unstake");
+    assert_eq!(ctx.token_balance(&winner_ata), 10_000 + POOL_SIZE / 2 + POOL_SIZE / 4);
+}
+
+#[test]
+fn stakers_share_pool_pro_rata() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let mint = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetStakeTiers {
+            config: TestContext::config_address(),
+            stake_mint: ctx.mint,
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetStakeTiers { stake_tiers: vec![] }.data(),
+    };
+    let share = ctx.set_staker_share_ix(2_000);
+    ctx.send(&[mint, share], &[]).expect("set_staker_share");
+
+    let mut stakers = Vec::new();
+    for amount in [3_000, 1_000] {
+        let staker = Keypair::new();
+        ctx.svm.airdrop(&staker.pubkey(), 1_000_000_000).unwrap();
+        let staker_ata = ctx.create_token_account(&staker.pubkey(), amount);
+        let ix = ctx.stake_ix(&staker.pubkey(), staker_ata, amount);
+        ctx.send(&[ix], &[&staker]).expect("stake");
+        stakers.push((staker, staker_ata));
+    }
+
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let winners = vec![solo(winner, 1, 95)];
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &solo_accounts(&winner, winner_ata));
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    // 20% goes to stakers, rank 1 takes half of the rest
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE * 8 / 10 / 2);
+
+    let earned = [POOL_SIZE * 3 / 20, POOL_SIZE / 20];
+    for ((staker, staker_ata), expected) in stakers.iter().zip(earned) {
+        let ix = TestContext::claim_staking_rewards_ix(&staker.pubkey(), *staker_ata);
+        ctx.send(&[ix], &[staker]).expect("claim_staking_rewards");
+        assert_eq!(ctx.token_balance(staker_ata), expected);
+
+        let ix = TestContext::claim_staking_rewards_ix(&staker.pubkey(), *staker_ata);
+        assert!(ctx.send(&[ix], &[staker]).is_err());
+    }
+}
+
+#[test]
+fn relayer_redirects_prizes_with_winner_signature() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let custodial = Keypair::new();
+    let cold_wallet = Pubkey::new_unique();
+    let cold_ata = ctx.create_token_account(&cold_wallet, 0);
+
+    // Signed by someone other than the winner
+    let mut forged = ctx.authorize_payout_wallet_ixs(&custodial, cold_wallet, 0);
+    let message = forged[0].data[16 + 96..].to_vec();
+    forged[0] = TestContext::ed25519_ix(&Keypair::new(), &message);
+    assert!(ctx.send(&forged, &[]).is_err());
+
+    let ixs = ctx.authorize_payout_wallet_ixs(&custodial, cold_wallet, 0);
+    ctx.send(&ixs, &[]).expect("authorize_payout_wallet");
+    // The same authorization can't be replayed
+    assert!(ctx.send(&ixs, &[]).is_err());
+
+    let profile = TestContext::player_profile_address(&custodial.pubkey());
+    let state: PlayerProfile = ctx.account(&profile);
+    assert_eq!(state.payout_wallet, Some(cold_wallet));
+    assert_eq!(state.payout_nonce, 1);
+
+    let winners = vec![solo(custodial.pubkey(), 1, 95)];
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &[profile, cold_ata]);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&cold_ata), POOL_SIZE / 2);
+}
+
+#[test]
+fn winner_redirects_prizes_to_cold_wallet() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let cold_wallet = Pubkey::new_unique();
+    let cold_ata = ctx.create_token_account(&cold_wallet, 0);
+
+    let profile = TestContext::player_profile_address(&winner.pubkey());
+    let redirect = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPayoutWallet {
+            player_profile: profile,
+            player: winner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPayoutWallet { payout_wallet: Some(cold_wallet) }
+            .data(),
+    };
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    ctx.send(&[create, redirect], &[&winner]).expect("set_payout_wallet");
+
+    // The winner's own token account is no longer accepted
+    let winners = vec![solo(winner.pubkey(), 1, 95)];
+    let ix = distribute_ix(&ctx, &pool, winners.clone(), 0, &[profile, winner_ata]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &[profile, cold_ata]);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&cold_ata), POOL_SIZE / 2);
+    assert_eq!(ctx.token_balance(&winner_ata), 0);
+}
+
+#[test]
+fn admin_toggles_feature_flags() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let program_state = Pubkey::find_program_address(&[b"program_state"], &pardon_prizes::ID).0;
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::InitializeProgramState {
+            config: TestContext::config_address(),
+            program_state,
+            admin: ctx.authority.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::InitializeProgramState {}.data(),
+    };
+    ctx.send(&[ix], &[]).expect("initialize_program_state");
+
+    let admin = ctx.authority.pubkey();
+    let flag_ix = |feature: u64, enabled: bool| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetFeatureFlag {
+            config: TestContext::config_address(),
+            program_state,
+            admin,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetFeatureFlag { feature, enabled }.data(),
+    };
+    let ixs = [flag_ix(FEATURE_CLAIMS, true), flag_ix(FEATURE_RAFFLES, true)];
+    ctx.send(&ixs, &[]).expect("set_feature_flag");
+    let ix = flag_ix(FEATURE_RAFFLES, false);
+    ctx.send(&[ix], &[]).expect("set_feature_flag");
+    let ix = flag_ix(1 << 63, true);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let state: ProgramState = ctx.account(&program_state);
+    assert_eq!(state.version, ProgramState::CURRENT_VERSION);
+    assert!(state.is_enabled(FEATURE_CLAIMS));
+    assert!(!state.is_enabled(FEATURE_RAFFLES));
+}
+
+#[test]
+fn authority_sets_pool_metadata() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let metadata_ix = |authority: Pubkey, name: &str| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPoolMetadata {
+            prize_pool: pool.prize_pool,
+            authority,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPoolMetadata {
+            name: name.to_string(),
+            metadata_uri: "https://example.com/week-45.json".to_string(),
+        }
+        .data(),
+    };
+
+    let intruder = Keypair::new();
+    ctx.svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+    let ix = metadata_ix(intruder.pubkey(), "Hijacked");
+    assert!(ctx.send(&[ix], &[&intruder]).is_err());
+
+    let ix = metadata_ix(ctx.authority.pubkey(), &"x".repeat(65));
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = metadata_ix(ctx.authority.pubkey(), "Week 45: Election Special");
+    ctx.send(&[ix], &[]).expect("set_pool_metadata");
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.name, "Week 45: Election Special");
+    assert_eq!(state.metadata_uri, "https://example.com/week-45.json");
+}
+
+#[test]
+fn leaderboard_root_is_set_once() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+
+    let ix = ctx.finalize_leaderboard_ix(&pool, [7; 32], 0);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = ctx.finalize_leaderboard_ix(&pool, [7; 32], 250);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let ix = ctx.finalize_leaderboard_ix(&pool, [8; 32], 250);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.leaderboard_root, Some([7; 32]));
+    assert_eq!(state.leaderboard_entries, 250);
+}
+
+#[test]
+fn attest_results_waits_for_payouts_to_settle() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let fails_with = |ctx: &mut TestContext, code: &str| {
+        let ix = ctx.attest_results_ix(&pool);
+        let failed = ctx.send(&[ix], &[]).expect_err("attest_results");
+        failed.meta.logs.iter().any(|log| log.contains(code))
+    };
+
+    let ix = ctx.finalize_leaderboard_ix(&pool, [7; 32], 1);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    // Posted now, the message would carry totals of 0 forever
+    assert!(fails_with(&mut ctx, "ResultsNotSettled"));
+
+    let destinations = solo_accounts(&winner, winner_ata);
+    let ix = distribute_ix(&ctx, &pool, vec![solo(winner, 1, 95)], 0, &destinations);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    let record: DistributionRecord =
+        ctx.account(&TestContext::distribution_record_address(&pool.prize_pool, 0));
+    assert_eq!(state.winners_hash, hashv(&[&[0; 32], &record.winners_hash]).to_bytes());
+    assert!(results_payload(&state, &[7; 32]).ends_with(&state.winners_hash));
+
+    // Settled, but a bridge without a readable fee is refused rather than charged 0
+    assert!(fails_with(&mut ctx, "InvalidBridgeConfig"));
+    let bridge = Pubkey::find_program_address(&[b"Bridge"], &WORMHOLE_PROGRAM_ID).0;
+    let account = Account {
+        lamports: 1_000_000_000,
+        data: vec![0; 24],
+        owner: WORMHOLE_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    ctx.svm.set_account(bridge, account).unwrap();
+    // Past both checks; the core bridge isn't loaded here, so only its CPI fails
+    assert!(!fails_with(&mut ctx, "ResultsNotSettled"));
+    assert!(!fails_with(&mut ctx, "InvalidBridgeConfig"));
+}
+
+#[test]
+fn required_scoring_transcripts_gate_payouts() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let post = |ctx: &TestContext, transcript_hash: [u8; 32], uri: &str| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::PostScoringTranscript {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::PostScoringTranscript {
+            transcript_hash,
+            uri: uri.to_string(),
+        }
+        .data(),
+    };
+    let require = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetScoringTranscriptsRequired {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetScoringTranscriptsRequired { required: true }.data(),
+    };
+    ctx.send(&[require], &[]).expect("set_scoring_transcripts_required");
+
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let distribute = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    assert!(ctx.send(std::slice::from_ref(&distribute), &[]).is_err());
+
+    let uri = "https://pardon.example/transcripts/2024-W45.json";
+    let ix = post(&ctx, [0; 32], uri);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = post(&ctx, [3; 32], &"x".repeat(MAX_METADATA_URI_LEN + 1));
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = post(&ctx, [3; 32], uri);
+    ctx.send(&[ix], &[]).expect("post_scoring_transcript");
+    let ix = post(&ctx, [4; 32], uri);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    let transcript = state.scoring_transcript.expect("transcript");
+    assert_eq!((transcript.hash, transcript.uri.as_str()), ([3; 32], uri));
+
+    ctx.send(&[distribute], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE / 2);
+}
+
+#[test]
+fn scoring_proofs_tie_the_leaderboard_to_the_transcript() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let inputs = scoring_inputs(&[7; 32], &[3; 32]);
+    let (key, proof) = known_log_proof(&inputs);
+    let mut forged = proof;
+    forged.c = g1_mul(8);
+
+    let ix = TestContext::verify_scoring_proof_ix(&pool, proof);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.set_scoring_verifier_ix(key);
+    ctx.send(&[ix], &[]).expect("set_scoring_verifier");
+
+    // The proof's public inputs only exist once both are on chain
+    let ix = TestContext::verify_scoring_proof_ix(&pool, proof);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.finalize_leaderboard_ix(&pool, [7; 32], 10);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let ix = ctx.post_scoring_transcript_ix(&pool, [3; 32], "https://pardon.example/t.json");
+    ctx.send(&[ix], &[]).expect("post_scoring_transcript");
+
+    let ix = TestContext::verify_scoring_proof_ix(&pool, forged);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert!(!state.scoring_proof_verified);
+
+    let ix = TestContext::verify_scoring_proof_ix(&pool, proof);
+    ctx.send(&[ix], &[]).expect("verify_scoring_proof");
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert!(state.scoring_proof_verified);
+}
+
+#[test]
+fn relayer_distributes_winners_signed_by_the_judge_enclave() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let enclave = Keypair::new();
+    let relayer = Keypair::new();
+    ctx.svm.airdrop(&relayer.pubkey(), 1_000_000_000).unwrap();
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let accounts = solo_accounts(&winner, winner_ata);
+    let relayed = |ctx: &TestContext, signer: &Keypair, winners: Vec<WinnerEntry>| {
+        enclave_distribute_ixs(ctx, &pool, signer, &relayer.pubkey(), winners, 0, &accounts)
+    };
+
+    let ixs = relayed(&ctx, &enclave, vec![solo(winner, 1, 95)]);
+    assert!(ctx.send(&ixs, &[&relayer]).is_err());
+    let mut attested = EnclaveSigner {
+        key: enclave.pubkey(),
+        measurement: [0; 48],
+        report_hash: [9; 32],
+    };
+    let ix = ctx.set_enclave_signer_ix(Some(attested));
+    assert!(ctx.send(&[ix], &[]).is_err());
+    attested.measurement = [5; 48];
+    let ix = ctx.set_enclave_signer_ix(Some(attested));
+    ctx.send(&[ix], &[]).expect("set_enclave_signer");
+
+    // Signed by another key, or over different winners than the relayer submits
+    let ixs = relayed(&ctx, &Keypair::new(), vec![solo(winner, 1, 95)]);
+    assert!(ctx.send(&ixs, &[&relayer]).is_err());
+    let mut swapped = relayed(&ctx, &enclave, vec![solo(winner, 2, 95)]);
+    swapped[0] = relayed(&ctx, &enclave, vec![solo(winner, 1, 95)])[0].clone();
+    assert!(ctx.send(&swapped, &[&relayer]).is_err());
+
+    let ixs = relayed(&ctx, &enclave, vec![solo(winner, 1, 95)]);
+    ctx.send(&ixs, &[&relayer]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE / 2);
+
+    let record: DistributionRecord =
+        ctx.account(&TestContext::distribution_record_address(&pool.prize_pool, 0));
+    assert_eq!(record.executor, relayer.pubkey());
+    assert_eq!(record.enclave_signer, Some(enclave.pubkey()));
+}
+
+#[test]
+fn week_stats_follow_entries_funding_and_claims() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let stats_address = TestContext::week_stats_address(&pool.prize_pool);
+    let stats: WeekStats = ctx.account(&stats_address);
+    assert_eq!((stats.total_funded, stats.participants), (POOL_SIZE, 0));
+
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let runner_up = Pubkey::new_unique();
+    for (player, score) in [(winner.pubkey(), 95), (runner_up, 80)] {
+        let ix = ctx.submit_score_ix(&pool, &player, score);
+        ctx.send(&[ix], &[]).expect("submit_score");
+        let ix = ctx.finalize_entry_ix(&pool, &player);
+        ctx.send(&[ix], &[]).expect("finalize_entry");
+    }
+    let stats: WeekStats = ctx.account(&stats_address);
+    assert_eq!(stats.participants, 2);
+    assert_eq!((stats.average_score, stats.highest_score), (87, 95));
+
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let winner_leaf = leaderboard_leaf(&winner.pubkey(), 1, 95);
+    let runner_up_leaf = leaderboard_leaf(&runner_up, 2, 80);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&winner_leaf, &runner_up_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let proof = vec![runner_up_leaf];
+    let claim = ctx.claim_prize_ix(&pool, &winner.pubkey(), winner_ata, 1, 95, proof);
+    ctx.send(&[claim], &[&winner]).expect("claim_prize");
+
+    let stats: WeekStats = ctx.account(&stats_address);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(stats.total_paid, POOL_SIZE / 2);
+    assert_eq!(stats.total_paid, state.total_distributed);
+    assert_eq!(stats.unique_claimers, 1);
+}
+
+#[test]
+fn global_stats_count_pools_payouts_and_first_time_winners() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let earlier = ctx.funded_pool("2024-W44", POOL_SIZE);
+    let stats: GlobalStats = ctx.account(&TestContext::global_stats_address());
+    assert_eq!(stats.total_pools, 2);
+    // Opening an earlier week's pool doesn't move the current week back
+    assert_eq!(stats.current_week_id, WEEK);
+
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    ctx.send(&[create], &[&winner]).expect("create_player_profile");
+    let accounts = solo_accounts(&winner.pubkey(), winner_ata);
+    for pool in [&pool, &earlier] {
+        let ix = distribute_ix(&ctx, pool, vec![solo(winner.pubkey(), 1, 95)], 0, &accounts);
+        ctx.send(&[ix], &[]).expect("distribute_prizes");
+    }
+
+    // The same wallet winning twice counts once
+    let stats: GlobalStats = ctx.account(&TestContext::global_stats_address());
+    assert_eq!(stats.unique_winners, 1);
+    let stats: MintStats = ctx.account(&TestContext::mint_stats_address(&ctx.mint));
+    assert_eq!(stats.total_distributed, POOL_SIZE);
+
+    // The singleton is created only once
+    let ix = ctx.initialize_global_stats_ix();
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn winners_claim_with_leaderboard_proof() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let first = Keypair::new();
+    let second = Keypair::new();
+    ctx.svm.airdrop(&first.pubkey(), 1_000_000_000).unwrap();
+    ctx.svm.airdrop(&second.pubkey(), 1_000_000_000).unwrap();
+    let first_ata = ctx.create_token_account(&first.pubkey(), 0);
+    let second_ata = ctx.create_token_account(&second.pubkey(), 0);
+
+    let first_leaf = leaderboard_leaf(&first.pubkey(), 1, 95);
+    let second_leaf = leaderboard_leaf(&second.pubkey(), 2, 90);
+    let root = hash_pair(&first_leaf, &second_leaf);
+
+    // Claims stay off until the admin enables them and the root is published
+    let claim = ctx.claim_prize_ix(&pool, &first.pubkey(), first_ata, 1, 95, vec![second_leaf]);
+    assert!(ctx.send(std::slice::from_ref(&claim), &[&first]).is_err());
+    ctx.enable_feature(FEATURE_CLAIMS);
+    assert!(ctx.send(std::slice::from_ref(&claim), &[&first]).is_err());
+    let ix = ctx.finalize_leaderboard_ix(&pool, root, 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    ctx.send(std::slice::from_ref(&claim), &[&first]).expect("claim_prize");
+    assert_eq!(ctx.token_balance(&first_ata), POOL_SIZE / 2);
+    assert!(ctx.send(&[claim], &[&first]).is_err());
+
+    let inflated =
+        ctx.claim_prize_ix(&pool, &second.pubkey(), second_ata, 1, 90, vec![first_leaf]);
+    assert!(ctx.send(&[inflated], &[&second]).is_err());
+    let ix = ctx.claim_prize_ix(&pool, &second.pubkey(), second_ata, 2, 90, vec![first_leaf]);
+    ctx.send(&[ix], &[&second]).expect("claim_prize");
+    assert_eq!(ctx.token_balance(&second_ata), POOL_SIZE / 5);
+
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(first.pubkey(), 1, 95)],
+        0,
+        &solo_accounts(&first.pubkey(), first_ata),
+    );
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn claims_follow_the_payout_wallet_and_split() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Keypair::new();
+    let captain = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    ctx.svm.airdrop(&captain.pubkey(), 1_000_000_000).unwrap();
+    let cold_wallet = Pubkey::new_unique();
+    let coach = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+    let cold_ata = ctx.create_token_account(&cold_wallet, 0);
+    let coach_ata = ctx.create_token_account(&coach, 0);
+    let captain_ata = ctx.create_token_account(&captain.pubkey(), 0);
+
+    let profile = TestContext::player_profile_address(&winner.pubkey());
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    let redirect = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPayoutWallet {
+            player_profile: profile,
+            player: winner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPayoutWallet { payout_wallet: Some(cold_wallet) }
+            .data(),
+    };
+    let set_split = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPrizeSplit {
+            player_profile: profile,
+            player: winner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPrizeSplit {
+            recipient: Some(coach),
+            split_bps: 1_000,
+        }
+        .data(),
+    };
+    ctx.send(&[create, redirect, set_split], &[&winner]).expect("configure profile");
+
+    let register = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::RegisterTeam {
+            team: TestContext::team_address(&captain.pubkey()),
+            captain: captain.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::RegisterTeam {
+            members: vec![
+                TeamMember { wallet: captain.pubkey(), share_bps: 5_000 },
+                TeamMember { wallet: Pubkey::new_unique(), share_bps: 5_000 },
+            ],
+        }
+        .data(),
+    };
+    ctx.send(&[register], &[&captain]).expect("register_team");
+
+    let winner_leaf = leaderboard_leaf(&winner.pubkey(), 1, 95);
+    let captain_leaf = leaderboard_leaf(&captain.pubkey(), 2, 90);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&winner_leaf, &captain_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    let set_stake_tiers = |ctx: &TestContext, stake_tiers: Vec<StakeTier>| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetStakeTiers {
+            config: TestContext::config_address(),
+            stake_mint: ctx.mint,
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetStakeTiers { stake_tiers }.data(),
+    };
+    let tiers = vec![StakeTier { min_stake: 1_000, multiplier_bps: 12_500 }];
+    let ix = set_stake_tiers(&ctx, tiers);
+    ctx.send(&[ix], &[]).expect("set_stake_tiers");
+
+    // Stake boosts need the winners list distribute_prizes is given
+    let claim = ctx.claim_prize_ix(&pool, &winner.pubkey(), cold_ata, 1, 95, vec![captain_leaf]);
+    assert!(ctx.send(std::slice::from_ref(&claim), &[&winner]).is_err());
+    let ix = set_stake_tiers(&ctx, vec![]);
+    ctx.send(&[ix], &[]).expect("set_stake_tiers");
+
+    // The winner's own account is no longer where their prize goes
+    let ix = ctx.claim_prize_ix(&pool, &winner.pubkey(), winner_ata, 1, 95, vec![captain_leaf]);
+    assert!(ctx.send(&[ix], &[&winner]).is_err());
+    ctx.send(&[claim], &[&winner]).expect("claim_prize");
+
+    let prize = POOL_SIZE / 2;
+    assert_eq!(ctx.token_balance(&coach_ata), prize / 10);
+    assert_eq!(ctx.token_balance(&cold_ata), prize - prize / 10);
+    assert_eq!(ctx.token_balance(&winner_ata), 0);
+    let record: ClaimRecord =
+        ctx.account(&TestContext::claim_record_address(&pool.prize_pool, &winner.pubkey()));
+    assert_eq!(record.paid_to, cold_wallet);
+    assert_eq!(record.split_recipient, Some(coach));
+    assert_eq!(record.split_amount, prize / 10);
+
+    // A team's prize is split between its members by distribute_prizes only
+    let ix = ctx.claim_prize_ix(&pool, &captain.pubkey(), captain_ata, 2, 90, vec![winner_leaf]);
+    assert!(ctx.send(&[ix], &[&captain]).is_err());
+    assert_eq!(ctx.token_balance(&captain_ata), 0);
+}
+
+#[test]
+fn airdrop_pools_pay_every_qualifier_the_same_share() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let set_airdrop_mode = |ctx: &TestContext, threshold: Option<u8>| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetAirdropMode {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetAirdropMode { threshold }.data(),
+    };
+    let ix = set_airdrop_mode(&ctx, Some(101));
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = set_airdrop_mode(&ctx, Some(90));
+    ctx.send(&[ix], &[]).expect("set_airdrop_mode");
+
+    let players: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    let scores = [95, 92, 85];
+    let leaves: Vec<[u8; 32]> = players
+        .iter()
+        .zip(scores)
+        .enumerate()
+        .map(|(i, (player, score))| leaderboard_leaf(&player.pubkey(), i as u32 + 1, score))
+        .collect();
+    let pair = hash_pair(&leaves[0], &leaves[1]);
+    let root = hash_pair(&pair, &leaves[2]);
+    let proofs = [vec![leaves[1], leaves[2]], vec![leaves[0], leaves[2]], vec![pair]];
+    let ix = ctx.finalize_leaderboard_ix(&pool, root, 3);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    // The leaderboard decides how many shares there are, so the mode is now fixed
+    let ix = set_airdrop_mode(&ctx, None);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(players[0].pubkey(), 1, 95)],
+        0,
+        &solo_accounts(&players[0].pubkey(), pool.token_account),
+    );
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let mut claimed = Vec::new();
+    for (i, player) in players.iter().enumerate() {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let ata = ctx.create_token_account(&player.pubkey(), 0);
+        let proof = proofs[i].clone();
+        let ix = ctx.claim_prize_ix(&pool, &player.pubkey(), ata, i as u32 + 1, scores[i], proof);
+        claimed.push(ctx.send(&[ix], &[player]).is_ok().then(|| ctx.token_balance(&ata)));
+    }
+    // Ranks don't matter, and the entry below the threshold gets nothing
+    assert_eq!(claimed, vec![Some(POOL_SIZE / 3), Some(POOL_SIZE / 3), None]);
+
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.airdrop_claims, 2);
+    let record: ClaimRecord =
+        ctx.account(&TestContext::claim_record_address(&pool.prize_pool, &players[1].pubkey()));
+    assert_eq!(record.rank, 0);
+}
+
+#[test]
+fn tiered_airdrops_weight_shares_by_score() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let set_tiers = |ctx: &TestContext, tiers: Vec<QualificationTier>| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetQualificationTiers {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetQualificationTiers { tiers }.data(),
+    };
+    let set_weight = |ctx: &TestContext, total_weight: u64| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetAirdropWeight {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetAirdropWeight { total_weight }.data(),
+    };
+    let tier = |threshold, multiplier_bps| QualificationTier { threshold, multiplier_bps };
+
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetAirdropMode {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetAirdropMode { threshold: Some(80) }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_airdrop_mode");
+    let ix = set_tiers(&ctx, vec![tier(95, 20_000), tier(90, 15_000)]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = set_tiers(&ctx, vec![tier(90, 15_000), tier(95, 40_000)]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = set_tiers(&ctx, vec![tier(90, 15_000), tier(95, 20_000)]);
+    ctx.send(&[ix], &[]).expect("set_qualification_tiers");
+
+    let players: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+    let scores = [95, 91, 85];
+    let leaves: Vec<[u8; 32]> = players
+        .iter()
+        .zip(scores)
+        .enumerate()
+        .map(|(i, (player, score))| leaderboard_leaf(&player.pubkey(), i as u32 + 1, score))
+        .collect();
+    let pair = hash_pair(&leaves[0], &leaves[1]);
+    let root = hash_pair(&pair, &leaves[2]);
+    let proofs = [vec![leaves[1], leaves[2]], vec![leaves[0], leaves[2]], vec![pair]];
+    let ix = ctx.finalize_leaderboard_ix(&pool, root, 3);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    let atas: Vec<Pubkey> = players
+        .iter()
+        .map(|player| {
+            ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+            ctx.create_token_account(&player.pubkey(), 0)
+        })
+        .collect();
+    let claim = |ctx: &TestContext, i: usize| {
+        let proof = proofs[i].clone();
+        ctx.claim_prize_ix(&pool, &players[i].pubkey(), atas[i], i as u32 + 1, scores[i], proof)
+    };
+
+    // Claims wait for the poster's total, which must fit the tiers
+    let ix = claim(&ctx, 0);
+    assert!(ctx.send(&[ix], &[&players[0]]).is_err());
+    let ix = set_weight(&ctx, 3 * 20_000 + 1);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = set_weight(&ctx, 20_000 + 15_000 + 10_000);
+    ctx.send(&[ix], &[]).expect("set_airdrop_weight");
+
+    for (i, player) in players.iter().enumerate() {
+        let ix = claim(&ctx, i);
+        ctx.send(&[ix], &[player]).expect("claim_prize");
+    }
+    let balances: Vec<u64> = atas.iter().map(|ata| ctx.token_balance(ata)).collect();
+    let share = |weight: u64| (POOL_SIZE as u128 * weight as u128 / 45_000) as u64;
+    assert_eq!(balances, vec![share(20_000), share(15_000), share(10_000)]);
+
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.airdrop_weight_claimed, 45_000);
+}
+
+#[test]
+fn allowlisted_mints_fund_per_mint_sub_vaults() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let bridged = Pubkey::new_unique();
+    ctx.create_mint(bridged);
+    let admin = ctx.authority.pubkey();
+    let allow_ix = |funding_mints: Vec<Pubkey>| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetFundingMints {
+            config: TestContext::config_address(),
+            admin,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetFundingMints { funding_mints }.data(),
+    };
+    let ix = allow_ix(vec![ctx.mint, bridged]);
+    ctx.send(&[ix], &[]).expect("set_funding_mints");
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+
+    let mint_vault = TestContext::mint_vault_address(&pool.prize_pool, &bridged);
+    let open = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::OpenMintVault {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            mint: bridged,
+            mint_vault,
+            payer: admin,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::OpenMintVault {}.data(),
+    };
+    ctx.send(&[open], &[]).expect("open_mint_vault");
+
+    let sponsor = Keypair::new();
+    ctx.svm.airdrop(&sponsor.pubkey(), 1_000_000_000).unwrap();
+    let funder = sponsor.pubkey();
+    let sponsor_ata = ctx.create_token_account_in(&bridged, &funder, 500);
+    let wrong_vault =
+        TestContext::fund_vault_ix(&pool, pool.token_account, &funder, sponsor_ata, 500, "");
+    assert!(ctx.send(&[wrong_vault], &[&sponsor]).is_err());
+    let deposit = TestContext::fund_vault_ix(&pool, mint_vault, &funder, sponsor_ata, 500, "bridged");
+    ctx.send(&[deposit], &[&sponsor]).expect("fund_pool");
+    assert_eq!(ctx.token_balance(&mint_vault), 500);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.total_funded, POOL_SIZE);
+
+    let treasury = ctx.create_token_account_in(&bridged, &admin, 0);
+    let sweep = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SweepMintVault {
+            prize_pool: pool.prize_pool,
+            mint_vault,
+            destination: treasury,
+            authority: admin,
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SweepMintVault {}.data(),
+    };
+    ctx.send(&[sweep], &[]).expect("sweep_mint_vault");
+    assert_eq!(ctx.token_balance(&treasury), 500);
+
+    // Dropping the pool mint from the allowlist stops new deposits in it
+    let ix = allow_ix(vec![bridged]);
+    ctx.send(&[ix], &[]).expect("set_funding_mints");
+    let funder_ata = ctx.create_token_account(&funder, 100);
+    let ix = ctx.fund_pool_ix(&pool, &funder, funder_ata, 100, "");
+    assert!(ctx.send(&[ix], &[&sponsor]).is_err());
+}
+
+#[test]
+fn collection_gated_pools_require_an_nft_registration() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let collection = Pubkey::new_unique();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetEntryCollection {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetEntryCollection {
+            collection: Some(collection),
+            burn_ticket: false,
+        }
+        .data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_entry_collection");
+
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    let ix = ctx.submit_score_ix(&pool, &player.pubkey(), 90);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+    assert!(ctx.send(&[ix], &[&player]).is_err());
+    let unverified = ctx.create_collection_nft(&player.pubkey(), &collection, false);
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), Some(&unverified), None);
+    assert!(ctx.send(&[ix], &[&player]).is_err());
+
+    let pass = ctx.create_collection_nft(&player.pubkey(), &collection, true);
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), Some(&pass), None);
+    ctx.send(&[ix], &[&player]).expect("register_player");
+    assert_eq!(ctx.token_balance(&pass.token_account), 1);
+
+    let ix = ctx.submit_score_ix(&pool, &player.pubkey(), 90);
+    ctx.send(&[ix], &[]).expect("submit_score");
+}
+
+#[test]
+fn gatekeeper_pools_require_a_live_civic_pass() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let network = Pubkey::new_unique();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGatekeeperNetwork {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGatekeeperNetwork { network: Some(network) }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_gatekeeper_network");
+
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    // The gateway token sits before the player profile, the bond vault and the
+    // two event CPI accounts
+    let register_ix = |gateway_token: Option<Pubkey>| {
+        let mut ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+        if let Some(gateway_token) = gateway_token {
+            let at = ix.accounts.len() - 5;
+            ix.accounts[at] = AccountMeta::new_readonly(gateway_token, false);
+        }
+        ix
+    };
+
+    assert!(ctx.send(&[register_ix(None)], &[&player]).is_err());
+    let other_network = ctx.set_gateway_token(&player.pubkey(), &Pubkey::new_unique(), 0, None);
+    assert!(ctx.send(&[register_ix(Some(other_network))], &[&player]).is_err());
+    let revoked = ctx.set_gateway_token(&player.pubkey(), &network, 1, None);
+    assert!(ctx.send(&[register_ix(Some(revoked))], &[&player]).is_err());
+    let someone_elses = ctx.set_gateway_token(&Pubkey::new_unique(), &network, 0, None);
+    assert!(ctx.send(&[register_ix(Some(someone_elses))], &[&player]).is_err());
+
+    let ix = ctx.submit_score_ix(&pool, &player.pubkey(), 90);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let pass = ctx.set_gateway_token(&player.pubkey(), &network, 0, Some(i64::MAX));
+    ctx.send(&[register_ix(Some(pass))], &[&player]).expect("register_player");
+    let ix = ctx.submit_score_ix(&pool, &player.pubkey(), 90);
+    ctx.send(&[ix], &[]).expect("submit_score");
+}
+
+#[test]
+fn wallet_age_pools_turn_away_fresh_wallets() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let registrar = Keypair::new();
+    let requirement = WalletAgeRequirement { registrar: registrar.pubkey(), min_age_days: 30 };
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetWalletAgeRequirement {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetWalletAgeRequirement {
+            requirement: Some(requirement),
+        }
+        .data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_wallet_age_requirement");
+
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let veteran = Keypair::new();
+    let newcomer = Keypair::new();
+    for player in [&veteran, &newcomer] {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    }
+    // The player profile sits before the bond vault and the two event CPI accounts
+    let register_ix = |player: &Pubkey| {
+        let mut ix = TestContext::register_player_ix(&pool, player, None, None);
+        let at = ix.accounts.len() - 4;
+        ix.accounts[at] =
+            AccountMeta::new_readonly(TestContext::player_profile_address(player), false);
+        ix
+    };
+
+    // No attestation yet, then one signed by someone other than the registrar
+    assert!(ctx.send(&[register_ix(&veteran.pubkey())], &[&veteran]).is_err());
+    let forged = ctx.attest_wallet_age_ixs(&Keypair::new(), veteran.pubkey(), 1);
+    ctx.send(&forged, &[]).expect("attest_wallet_age");
+    assert!(ctx.send(&[register_ix(&veteran.pubkey())], &[&veteran]).is_err());
+
+    let ixs = ctx.attest_wallet_age_ixs(&registrar, veteran.pubkey(), now - 90 * SECONDS_PER_DAY);
+    ctx.send(&ixs, &[]).expect("attest_wallet_age");
+    let profile: PlayerProfile = ctx.account(&TestContext::player_profile_address(&veteran.pubkey()));
+    assert_eq!(profile.age_registrar, registrar.pubkey());
+    ctx.send(&[register_ix(&veteran.pubkey())], &[&veteran]).expect("register_player");
+
+    let ixs = ctx.attest_wallet_age_ixs(&registrar, newcomer.pubkey(), now - SECONDS_PER_DAY);
+    ctx.send(&ixs, &[]).expect("attest_wallet_age");
+    assert!(ctx.send(&[register_ix(&newcomer.pubkey())], &[&newcomer]).is_err());
+    let ix = ctx.submit_score_ix(&pool, &newcomer.pubkey(), 90);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    // Attestations from the future are refused outright
+    let ixs = ctx.attest_wallet_age_ixs(&registrar, newcomer.pubkey(), now + SECONDS_PER_DAY);
+    assert!(ctx.send(&ixs, &[]).is_err());
+}
+
+#[test]
+fn ticket_pools_burn_the_ticket_on_registration() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let tickets = Pubkey::new_unique();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetEntryCollection {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetEntryCollection {
+            collection: Some(tickets),
+            burn_ticket: true,
+        }
+        .data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_entry_collection");
+
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    let ticket = ctx.create_collection_nft(&player.pubkey(), &tickets, true);
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), Some(&ticket), None);
+    ctx.send(&[ix], &[&player]).expect("register_player");
+
+    assert!(ctx.svm.get_account(&ticket.token_account).is_none());
+    let registration = TestContext::registration_address(&pool.prize_pool, &player.pubkey());
+    let registration: Registration = ctx.account(&registration);
+    assert_eq!(registration.nft_mint, Some(ticket.mint));
+    assert!(registration.ticket_burned);
+}
+
+#[test]
+fn entry_bonds_are_slashed_for_cheaters_and_refunded_to_the_rest() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+
+    let bond = 500;
+    let set_entry_bond_ix = |ctx: &TestContext, entry_bond| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetEntryBond {
+            prize_pool: pool.prize_pool,
+            bond_vault: TestContext::bond_vault_address(&pool.prize_pool),
+            mint: ctx.mint,
+            authority: ctx.authority.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetEntryBond { entry_bond }.data(),
+    };
+    let ix = set_entry_bond_ix(&ctx, bond);
+    ctx.send(&[ix], &[]).expect("set_entry_bond");
+
+    let honest = Keypair::new();
+    let cheater = Keypair::new();
+    let mut atas = vec![];
+    for player in [&honest, &cheater] {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let player_ata = ctx.create_token_account(&player.pubkey(), bond);
+        let mut ix =
+            TestContext::register_player_ix(&pool, &player.pubkey(), None, Some(player_ata));
+        assert!(ctx.send(std::slice::from_ref(&ix), &[player]).is_err());
+        // The bond vault sits just before the two event CPI accounts
+        let at = ix.accounts.len() - 3;
+        ix.accounts[at] =
+            AccountMeta::new(TestContext::bond_vault_address(&pool.prize_pool), false);
+        ctx.send(&[ix], &[player]).expect("register_player");
+        assert_eq!(ctx.token_balance(&player_ata), 0);
+        atas.push(player_ata);
+    }
+    let (honest_ata, cheater_ata) = (atas[0], atas[1]);
+    assert_eq!(ctx.token_balance(&TestContext::bond_vault_address(&pool.prize_pool)), 2 * bond);
+    // Bonds are locked in once players have posted them
+    let ix = set_entry_bond_ix(&ctx, 0);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    // Nobody gets their bond back before the week pays out
+    let ix = TestContext::refund_entry_bond_ix(&pool, &honest.pubkey(), honest_ata);
+    assert!(ctx.send(&[ix], &[&honest]).is_err());
+
+    let slash_ix = |signer: &Pubkey| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SlashEntryBond {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            registration: TestContext::registration_address(&pool.prize_pool, &cheater.pubkey()),
+            bond_vault: TestContext::bond_vault_address(&pool.prize_pool),
+            prize_pool_token_account: pool.token_account,
+            guardian: *signer,
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SlashEntryBond {}.data(),
+    };
+    let authority = ctx.authority.pubkey();
+    assert!(ctx.send(&[slash_ix(&authority)], &[]).is_err());
+    ctx.send(&[slash_ix(&guardian.pubkey())], &[&guardian]).expect("slash_entry_bond");
+    assert!(ctx.send(&[slash_ix(&guardian.pubkey())], &[&guardian]).is_err());
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE + bond);
+
+    let winners = vec![solo(honest.pubkey(), 1, 95)];
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &solo_accounts(&honest.pubkey(), honest_ata));
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    // The slashed bond was paid out as prize money
+    assert_eq!(ctx.token_balance(&honest_ata), (POOL_SIZE + bond) / 2);
+
+    let ix = TestContext::refund_entry_bond_ix(&pool, &honest.pubkey(), honest_ata);
+    ctx.send(&[ix], &[&honest]).expect("refund_entry_bond");
+    assert_eq!(ctx.token_balance(&honest_ata), (POOL_SIZE + bond) / 2 + bond);
+    let ix = TestContext::refund_entry_bond_ix(&pool, &cheater.pubkey(), cheater_ata);
+    assert!(ctx.send(&[ix], &[&cheater]).is_err());
+
+    let registration: Registration = ctx.account(&TestContext::registration_address(
+        &pool.prize_pool,
+        &cheater.pubkey(),
+    ));
+    assert!(registration.bond_slashed);
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!((prize_pool.bonds_held, prize_pool.bonds_slashed), (0, bond));
+    assert_eq!(
+        prize_pool.expected_vault_balance(),
+        Some(ctx.token_balance(&pool.token_account))
+    );
+}
+
+#[test]
+fn upheld_cheat_reports_pay_a_bounty_from_the_slashed_bond() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+
+    let (entry_bond, report_bond) = (500, 100);
+    let authority = ctx.authority.pubkey();
+    let prize_pool = pool.prize_pool;
+    let set_report_bounty_ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetReportBounty { prize_pool, authority }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetReportBounty { report_bond, bounty_bps: 5_000 }.data(),
+    };
+    // Bounties come out of entry bonds, so the pool needs those first
+    assert!(ctx.send(std::slice::from_ref(&set_report_bounty_ix), &[]).is_err());
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetEntryBond {
+            prize_pool,
+            bond_vault: TestContext::bond_vault_address(&prize_pool),
+            mint: ctx.mint,
+            authority,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetEntryBond { entry_bond }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_entry_bond");
+    ctx.send(&[set_report_bounty_ix], &[]).expect("set_report_bounty");
+
+    let cheater = Keypair::new();
+    let honest = Keypair::new();
+    for player in [&cheater, &honest] {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let player_ata = ctx.create_token_account(&player.pubkey(), entry_bond);
+        let mut ix =
+            TestContext::register_player_ix(&pool, &player.pubkey(), None, Some(player_ata));
+        let at = ix.accounts.len() - 3;
+        ix.accounts[at] = AccountMeta::new(TestContext::bond_vault_address(&prize_pool), false);
+        ctx.send(&[ix], &[player]).expect("register_player");
+    }
+
+    let report_address = |wallet: &Pubkey, reporter: &Pubkey| {
+        Pubkey::find_program_address(
+            &[b"cheat_report", prize_pool.as_ref(), wallet.as_ref(), reporter.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    };
+    let report_ix = |wallet: Pubkey, reporter: &Pubkey, reporter_token_account| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::ReportCheater {
+            prize_pool,
+            registration: TestContext::registration_address(&prize_pool, &wallet),
+            cheat_report: report_address(&wallet, reporter),
+            bond_vault: TestContext::bond_vault_address(&prize_pool),
+            reporter_token_account,
+            reporter: *reporter,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::ReportCheater { wallet, evidence_hash: [7; 32] }.data(),
+    };
+    let resolve_ix = |wallet: &Pubkey, report, reporter_token_account, upheld| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::ResolveReport {
+            config: TestContext::config_address(),
+            prize_pool,
+            cheat_report: report,
+            registration: TestContext::registration_address(&prize_pool, wallet),
+            bond_vault: TestContext::bond_vault_address(&prize_pool),
+            prize_pool_token_account: pool.token_account,
+            reporter_token_account,
+            guardian: guardian.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::ResolveReport { upheld }.data(),
+    };
+
+    let (reporter, troll) = (Keypair::new(), Keypair::new());
+    let mut atas = vec![];
+    for wallet in [&reporter, &troll] {
+        ctx.svm.airdrop(&wallet.pubkey(), 1_000_000_000).unwrap();
+        atas.push(ctx.create_token_account(&wallet.pubkey(), report_bond));
+    }
+    let (reporter_ata, troll_ata) = (atas[0], atas[1]);
+    // Nobody can report themselves
+    let cheater_ata = ctx.create_token_account(&cheater.pubkey(), report_bond);
+    let ix = report_ix(cheater.pubkey(), &cheater.pubkey(), cheater_ata);
+    assert!(ctx.send(&[ix], &[&cheater]).is_err());
+    let ix = report_ix(cheater.pubkey(), &reporter.pubkey(), reporter_ata);
+    ctx.send(&[ix], &[&reporter]).expect("report_cheater");
+    let ix = report_ix(honest.pubkey(), &troll.pubkey(), troll_ata);
+    ctx.send(&[ix], &[&troll]).expect("report_cheater");
+    assert_eq!(ctx.token_balance(&reporter_ata), 0);
+    let cheater_registration = TestContext::registration_address(&prize_pool, &cheater.pubkey());
+    let registration: Registration = ctx.account(&cheater_registration);
+    assert_eq!(registration.pending_reports, 1);
+
+    // The troll's report is rejected and their bond joins the prizes
+    let troll_report = report_address(&honest.pubkey(), &troll.pubkey());
+    let ix = resolve_ix(&honest.pubkey(), troll_report, troll_ata, false);
+    ctx.send(&[ix], &[&guardian]).expect("resolve_report");
+    assert_eq!(ctx.token_balance(&troll_ata), 0);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE + report_bond);
+
+    let report = report_address(&cheater.pubkey(), &reporter.pubkey());
+    let ix = resolve_ix(&cheater.pubkey(), report, reporter_ata, true);
+    ctx.send(std::slice::from_ref(&ix), &[&guardian]).expect("resolve_report");
+    // The report bond back plus half the cheater's bond; the other half joins the prizes
+    assert_eq!(ctx.token_balance(&reporter_ata), report_bond + entry_bond / 2);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE + report_bond + entry_bond / 2);
+    assert!(ctx.send(&[ix], &[&guardian]).is_err());
+
+    let state: CheatReport = ctx.account(&report);
+    assert_eq!(state.status, ReportStatus::Upheld);
+    assert_eq!(state.bounty, entry_bond / 2);
+    assert_eq!(state.evidence_hash, [7; 32]);
+    let registration: Registration = ctx.account(&cheater_registration);
+    assert!(registration.bond_slashed);
+    assert_eq!(registration.pending_reports, 0);
+    let state: PrizePool = ctx.account(&prize_pool);
+    assert_eq!(state.bonds_held, entry_bond);
+    assert_eq!(state.expected_vault_balance(), Some(ctx.token_balance(&pool.token_account)));
+}
+
+#[test]
+fn entry_fees_grow_the_pool_minus_the_rake() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 1_000);
+    let treasury = Pubkey::new_unique();
+    let treasury_ata = ctx.create_token_account(&treasury, 0);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetTreasury {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetTreasury { treasury }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_treasury");
+
+    let authority = ctx.authority.pubkey();
+    let prize_pool = pool.prize_pool;
+    let set_entry_fee_ix = move |entry_fee, rake_bps| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetEntryFee { prize_pool, authority }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetEntryFee { entry_fee, rake_bps }.data(),
+    };
+    let ix = set_entry_fee_ix(100, 10_001);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = set_entry_fee_ix(100, 1_000);
+    ctx.send(&[ix], &[]).expect("set_entry_fee");
+
+    for _ in 0..2 {
+        let player = Keypair::new();
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let player_ata = ctx.create_token_account(&player.pubkey(), 100);
+        let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+        assert!(ctx.send(&[ix], &[&player]).is_err());
+        let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, Some(player_ata));
+        ctx.send(&[ix], &[&player]).expect("register_player");
+        assert_eq!(ctx.token_balance(&player_ata), 0);
+    }
+    assert_eq!(ctx.token_balance(&pool.token_account), 1_200);
+
+    // Fees are locked in once players have paid them
+    let ix = set_entry_fee_ix(50, 0);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::CloseEntries {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            treasury_token_account: treasury_ata,
+            authority: ctx.authority.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::CloseEntries {}.data(),
+    };
+    ctx.send(&[ix], &[]).expect("close_entries");
+
+    // 10% of the 200 in fees; the sponsor's 1_000 is never raked
+    assert_eq!(ctx.token_balance(&treasury_ata), 20);
+    assert_eq!(ctx.token_balance(&pool.token_account), 1_180);
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert!(prize_pool.entries_closed);
+    assert_eq!(prize_pool.entry_fees_collected, 200);
+    assert_eq!(prize_pool.total_fees, 20);
+
+    let late = Keypair::new();
+    ctx.svm.airdrop(&late.pubkey(), 1_000_000_000).unwrap();
+    let late_ata = ctx.create_token_account(&late.pubkey(), 100);
+    let ix = TestContext::register_player_ix(&pool, &late.pubkey(), None, Some(late_ata));
+    assert!(ctx.send(&[ix], &[&late]).is_err());
+}
+
+#[test]
+fn insurance_fund_takes_a_cut_and_pays_approved_claims() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.configure_insurance_ix(10_001);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.configure_insurance_ix(500);
+    ctx.send(&[ix], &[]).expect("configure_insurance");
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+
+    // 5% goes to the insurance vault before winner math
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let winners = vec![solo(winner, 1, 95)];
+    let ix = distribute_ix(&ctx, &pool, winners, 0, &solo_accounts(&winner, winner_ata));
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE * 95 / 100 / 2);
+    let insurance_vault = TestContext::insurance_vault_address();
+    let insured = POOL_SIZE / 20;
+    assert_eq!(ctx.token_balance(&insurance_vault), insured);
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(prize_pool.expected_vault_balance(), Some(ctx.token_balance(&pool.token_account)));
+
+    // The second-placed winner the judge dropped is made whole
+    let claimant = Pubkey::new_unique();
+    let claimant_ata = ctx.create_token_account(&claimant, 0);
+    let claim_ix = |guardian: &Pubkey, amount| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::PayInsuranceClaim {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            insurance_vault,
+            claimant_token_account: claimant_ata,
+            guardian: *guardian,
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::PayInsuranceClaim { amount, incident_hash: [3; 32] }
+            .data(),
+    };
+    let authority = ctx.authority.pubkey();
+    assert!(ctx.send(&[claim_ix(&authority, insured / 2)], &[]).is_err());
+    let ix = claim_ix(&guardian.pubkey(), insured + 1);
+    assert!(ctx.send(&[ix], &[&guardian]).is_err());
+    let ix = claim_ix(&guardian.pubkey(), insured / 2);
+    ctx.send(&[ix], &[&guardian]).expect("pay_insurance_claim");
+
+    assert_eq!(ctx.token_balance(&claimant_ata), insured / 2);
+    assert_eq!(ctx.token_balance(&insurance_vault), insured - insured / 2);
+    let config: Config = ctx.account(&TestContext::config_address());
+    assert_eq!(config.insurance_paid, insured / 2);
+}
+
+#[test]
+fn jackpot_grows_across_weeks_and_pays_a_perfect_score() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.configure_jackpot_ix(1_000, 100);
+    ctx.send(&[ix], &[]).expect("configure_jackpot");
+
+    // Each week's pool feeds 10% to the jackpot before winner math
+    for week in ["2025-W01", "2025-W02"] {
+        let pool = ctx.funded_pool(week, POOL_SIZE);
+        let winner = Pubkey::new_unique();
+        let winner_ata = ctx.create_token_account(&winner, 0);
+        let ix = distribute_ix(
+            &ctx,
+            &pool,
+            vec![solo(winner, 1, 95)],
+            0,
+            &solo_accounts(&winner, winner_ata),
+        );
+        ctx.send(&[ix], &[]).expect("distribute_prizes");
+        assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE * 9 / 10 / 2);
+    }
+    let jackpot_vault = TestContext::jackpot_vault_address();
+    assert_eq!(ctx.token_balance(&jackpot_vault), POOL_SIZE / 5);
+
+    let pool = ctx.funded_pool("2025-W03", 0);
+    let (near_miss, perfect) = (Pubkey::new_unique(), Pubkey::new_unique());
+    for (player, score) in [(near_miss, 99), (perfect, 100)] {
+        let ix = ctx.submit_score_ix(&pool, &player, score);
+        ctx.send(&[ix], &[]).expect("submit_score");
+        let ix = ctx.finalize_entry_ix(&pool, &player);
+        ctx.send(&[ix], &[]).expect("finalize_entry");
+    }
+    let perfect_ata = ctx.create_token_account(&perfect, 0);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.jackpot_qualifiers, 1);
+
+    let authority = ctx.authority.pubkey();
+    let award_ix = move |prize_pool: Pubkey, entries: &[Pubkey]| {
+        let mut accounts = pardon_prizes::accounts::AwardJackpot {
+            jackpot: TestContext::jackpot_address(),
+            jackpot_vault,
+            prize_pool,
+            winner_token_account: perfect_ata,
+            authority,
+            token_program: spl_token::ID,
+            randomness: None,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(entries.iter().map(|&entry| AccountMeta::new_readonly(entry, false)));
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts,
+            data: pardon_prizes::instruction::AwardJackpot {}.data(),
+        }
+    };
+    let near_miss_entry = TestContext::entry_address(&pool.prize_pool, &near_miss);
+    let perfect_entry = TestContext::entry_address(&pool.prize_pool, &perfect);
+    // No draw until the results are out
+    let ix = award_ix(pool.prize_pool, &[perfect_entry]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.finalize_leaderboard_ix(&pool, leaderboard_leaf(&perfect, 1, 100), 1);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let ix = ctx.finalize_entry_ix(&pool, &Pubkey::new_unique());
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = award_ix(pool.prize_pool, &[near_miss_entry]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    // With two perfect scores, passing only one of them is refused
+    let tied = ctx.funded_pool("2025-W04", 0);
+    let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+    for player in [first, second] {
+        let ix = ctx.submit_score_ix(&tied, &player, 100);
+        ctx.send(&[ix], &[]).expect("submit_score");
+        let ix = ctx.finalize_entry_ix(&tied, &player);
+        ctx.send(&[ix], &[]).expect("finalize_entry");
+    }
+    let ix = ctx.finalize_leaderboard_ix(&tied, leaderboard_leaf(&first, 1, 100), 1);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let ix = award_ix(tied.prize_pool, &[TestContext::entry_address(&tied.prize_pool, &first)]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let ix = award_ix(pool.prize_pool, &[perfect_entry]);
+    ctx.send(&[ix], &[]).expect("award_jackpot");
+    assert_eq!(ctx.token_balance(&perfect_ata), POOL_SIZE / 5);
+    assert_eq!(ctx.token_balance(&jackpot_vault), 0);
+    let jackpot: Jackpot = ctx.account(&TestContext::jackpot_address());
+    assert_eq!(jackpot.total_awarded, POOL_SIZE / 5);
+}
+
+#[test]
+fn sponsored_bonus_multiplier_doubles_prizes() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let sponsor = Keypair::new();
+    ctx.svm.airdrop(&sponsor.pubkey(), 1_000_000_000).unwrap();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetBonusMultiplier {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetBonusMultiplier {
+            bonus_multiplier_bps: 20_000,
+            sponsor: sponsor.pubkey(),
+        }
+        .data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_bonus_multiplier");
+
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let distribute = |ctx: &TestContext| {
+        distribute_ix(
+            ctx,
+            &pool,
+            vec![solo(winner, 1, 95)],
+            0,
+            &solo_accounts(&winner, winner_ata),
+        )
+    };
+    // The multiplier isn't paid until the sponsor's deposit covers it
+    let ix = distribute(&ctx);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let sponsor_ata = ctx.create_token_account(&sponsor.pubkey(), POOL_SIZE);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::FundBonus {
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            sponsor_token_account: sponsor_ata,
+            bonus_sponsor: sponsor.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::FundBonus { amount: POOL_SIZE }.data(),
+    };
+    ctx.send(&[ix], &[&sponsor]).expect("fund_bonus");
+
+    let ix = distribute(&ctx);
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE);
+    let prize_pool: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(prize_pool.bonus_reserve, POOL_SIZE / 2);
+
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::RefundBonus {
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            sponsor_token_account: sponsor_ata,
+            authority: ctx.authority.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::RefundBonus {}.data(),
+    };
+    ctx.send(&[ix], &[]).expect("refund_bonus");
+    assert_eq!(ctx.token_balance(&sponsor_ata), POOL_SIZE / 2);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE / 2);
+}
+
+#[test]
+fn happy_hours_boost_scores_submitted_inside_the_window() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let set_happy_hours_ix = |ctx: &TestContext, happy_hours: Vec<HappyHour>| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetHappyHours {
+            prize_pool: pool.prize_pool,
+            authority: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetHappyHours { happy_hours }.data(),
+    };
+    let overlapping = vec![
+        HappyHour { start: now + 100, end: now + 200, multiplier_bps: 15_000 },
+        HappyHour { start: now + 150, end: now + 250, multiplier_bps: 15_000 },
+    ];
+    let ix = set_happy_hours_ix(&ctx, overlapping);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let window = HappyHour { start: now + 100, end: now + 200, multiplier_bps: 15_000 };
+    let ix = set_happy_hours_ix(&ctx, vec![window]);
+    ctx.send(&[ix], &[]).expect("set_happy_hours");
+
+    let player = Pubkey::new_unique();
+    let entry = TestContext::entry_address(&pool.prize_pool, &player);
+    let ix = ctx.submit_score_ix(&pool, &player, 60);
+    ctx.send(&[ix], &[]).expect("submit_score");
+    let before: Entry = ctx.account(&entry);
+    assert_eq!((before.score, before.multiplier_bps), (60, 10_000));
+
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = now + 150;
+    ctx.svm.set_sysvar(&clock);
+    let ix = ctx.submit_score_ix(&pool, &player, 60);
+    ctx.send(&[ix], &[]).expect("submit_score");
+    let boosted: Entry = ctx.account(&entry);
+    assert_eq!((boosted.raw_score, boosted.score, boosted.multiplier_bps), (60, 90, 15_000));
+}
+
+#[test]
+fn streak_bonus_pays_consecutive_placings_only() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.configure_streak_bonus_ix(2_000, 2);
+    ctx.send(&[ix], &[]).expect("configure_streak_bonus");
+
+    // The treasury tops the vault up with a plain token transfer
+    let treasury = ctx.authority.pubkey();
+    let treasury_ata = ctx.create_token_account(&treasury, POOL_SIZE);
+    let streak_vault = TestContext::streak_vault_address();
+    let top_up = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &treasury_ata,
+        &streak_vault,
+        &treasury,
+        &[],
+        POOL_SIZE,
+    )
+    .unwrap();
+    ctx.send(&[top_up], &[]).expect("top up streak vault");
+
+    let winner = Keypair::new();
+    ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    let create = TestContext::create_player_profile_ix(&winner.pubkey());
+    ctx.send(&[create], &[&winner]).expect("create_player_profile");
+    let winner_ata = ctx.create_token_account(&winner.pubkey(), 0);
+
+    // W03 is skipped, so W04 starts a new streak
+    let prize = POOL_SIZE / 2;
+    let mut expected = 0;
+    for (week, streak_bonus) in [("2025-W01", 0), ("2025-W02", prize / 5), ("2025-W04", 0)] {
+        let pool = ctx.funded_pool(week, POOL_SIZE);
+        let ix = distribute_ix(
+            &ctx,
+            &pool,
+            vec![solo(winner.pubkey(), 1, 95)],
+            0,
+            &solo_accounts(&winner.pubkey(), winner_ata),
+        );
+        ctx.send(&[ix], &[]).expect("distribute_prizes");
+        expected += prize + streak_bonus;
+        assert_eq!(ctx.token_balance(&winner_ata), expected, "{week}");
+    }
+    assert_eq!(ctx.token_balance(&streak_vault), POOL_SIZE - prize / 5);
+    let profile: PlayerProfile =
+        ctx.account(&TestContext::player_profile_address(&winner.pubkey()));
+    assert_eq!((profile.last_placed_week.as_str(), profile.streak_weeks), ("2025-W04", 1));
+}
+
+#[test]
+fn archive_week_closes_claim_receipts_into_a_summary() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let first = Keypair::new();
+    let second = Keypair::new();
+    ctx.svm.airdrop(&first.pubkey(), 1_000_000_000).unwrap();
+    ctx.svm.airdrop(&second.pubkey(), 1_000_000_000).unwrap();
+    let first_ata = ctx.create_token_account(&first.pubkey(), 0);
+    let second_ata = ctx.create_token_account(&second.pubkey(), 0);
+
+    let first_leaf = leaderboard_leaf(&first.pubkey(), 1, 95);
+    let second_leaf = leaderboard_leaf(&second.pubkey(), 2, 90);
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&first_leaf, &second_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    // Nothing to archive before the first payout
+    let ix = ctx.archive_week_ix(&pool, &[]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let claim = ctx.claim_prize_ix(&pool, &first.pubkey(), first_ata, 1, 95, vec![second_leaf]);
+    ctx.send(std::slice::from_ref(&claim), &[&first]).expect("claim_prize");
+    let receipt = TestContext::claim_record_address(&pool.prize_pool, &first.pubkey());
+    let rent = ctx.svm.get_account(&receipt).unwrap().lamports;
+    let before = ctx.svm.get_balance(&first.pubkey()).unwrap();
+
+    // The second winner still has until the claim deadline
+    let ix = ctx.archive_week_ix(&pool, &[first.pubkey()]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = state.claim_deadline;
+    ctx.svm.set_sysvar(&clock);
+
+    let ix = ctx.archive_week_ix(&pool, &[first.pubkey()]);
+    ctx.send(&[ix], &[]).expect("archive_week");
+    assert_eq!(ctx.svm.get_account(&receipt).map_or(0, |account| account.lamports), 0);
+    assert_eq!(ctx.svm.get_balance(&first.pubkey()).unwrap(), before + rent);
+
+    let archive: WeekArchive = ctx.account(&TestContext::week_archive_address(&pool.prize_pool));
+    assert_eq!(archive.receipts_archived, 1);
+    assert_eq!(archive.amount_archived, POOL_SIZE / 2);
+    assert_eq!(archive.lamports_reclaimed, rent);
+
+    // Past the deadline and with the receipts gone, claims are closed for good
+    assert!(ctx.send(&[claim], &[&first]).is_err());
+    let ix = ctx.claim_prize_ix(&pool, &second.pubkey(), second_ata, 2, 90, vec![first_leaf]);
+    assert!(ctx.send(&[ix], &[&second]).is_err());
+}
+
+#[test]
+fn rent_rebates_pay_back_what_claims_cost() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let third = Keypair::new();
+    for winner in [&first, &second, &third] {
+        ctx.svm.airdrop(&winner.pubkey(), 1_000_000_000).unwrap();
+    }
+    let first_ata = ctx.create_token_account(&first.pubkey(), 0);
+    let second_ata = ctx.create_token_account(&second.pubkey(), 0);
+    let third_ata = get_associated_token_address(&third.pubkey(), &pool.mint);
+    let ata_rent = ctx.svm.get_account(&second_ata).unwrap().lamports;
+
+    let first_leaf = leaderboard_leaf(&first.pubkey(), 1, 95);
+    let second_leaf = leaderboard_leaf(&second.pubkey(), 2, 90);
+    let third_leaf = leaderboard_leaf(&third.pubkey(), 3, 85);
+    let top = hash_pair(&first_leaf, &second_leaf);
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&top, &third_leaf), 3);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    // Without a reserve the winner pays the receipt's rent
+    let before = ctx.svm.get_balance(&first.pubkey()).unwrap();
+    let proof = vec![second_leaf, third_leaf];
+    let ix = ctx.claim_prize_ix(&pool, &first.pubkey(), first_ata, 1, 95, proof);
+    ctx.send(&[ix], &[&first]).expect("claim_prize");
+    let receipt = TestContext::claim_record_address(&pool.prize_pool, &first.pubkey());
+    let receipt_rent = ctx.svm.get_account(&receipt).unwrap().lamports;
+    assert_eq!(ctx.svm.get_balance(&first.pubkey()).unwrap(), before - receipt_rent);
+
+    // Only the authority funds rebates
+    let mut ix = ctx.fund_rent_rebates_ix(&pool, 1_000_000_000, 100_000_000);
+    ix.accounts[1] = AccountMeta::new(first.pubkey(), true);
+    assert!(ctx.send(&[ix], &[&first]).is_err());
+    let ix = ctx.fund_rent_rebates_ix(&pool, 1_000_000_000, 100_000_000);
+    ctx.send(&[ix], &[]).expect("fund_rent_rebates");
+
+    // A token account opened before the claim is empty, but the claim didn't pay for it
+    let before = ctx.svm.get_balance(&second.pubkey()).unwrap();
+    let proof = vec![first_leaf, third_leaf];
+    let ix = ctx.claim_prize_ix(&pool, &second.pubkey(), second_ata, 2, 90, proof);
+    ctx.send(&[ix], &[&second]).expect("claim_prize");
+    assert_eq!(ctx.svm.get_balance(&second.pubkey()).unwrap(), before);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.rent_rebate_reserve, 100_000_000 - receipt_rent);
+
+    // The receipt and the token account the claim opened are paid back
+    let before = ctx.svm.get_balance(&third.pubkey()).unwrap();
+    let ix = ctx.claim_prize_ix(&pool, &third.pubkey(), third_ata, 3, 85, vec![top]);
+    ctx.send(&[ix], &[&third]).expect("claim_prize");
+    assert!(ctx.token_balance(&third_ata) > 0);
+    assert_eq!(ctx.svm.get_balance(&third.pubkey()).unwrap(), before);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    let rebate = receipt_rent + ata_rent;
+    assert_eq!(state.rent_rebate_reserve, 100_000_000 - receipt_rent - rebate);
+    let receipt = TestContext::claim_record_address(&pool.prize_pool, &third.pubkey());
+    let record: ClaimRecord = ctx.account(&receipt);
+    assert_eq!(record.rent_rebate, rebate);
+
+    // Archiving hands the rebated rent back to the pool instead of the winner
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = state.claim_deadline;
+    ctx.svm.set_sysvar(&clock);
+    let ix = ctx.archive_week_ix(&pool, &[third.pubkey()]);
+    ctx.send(&[ix], &[]).expect("archive_week");
+    assert_eq!(ctx.svm.get_balance(&third.pubkey()).unwrap(), before);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.rent_rebate_reserve, 100_000_000 - receipt_rent - ata_rent);
+}
+
+#[test]
+fn sweep_expired_sends_unclaimed_prizes_to_the_treasury() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let treasury = Pubkey::new_unique();
+    let treasury_ata = ctx.create_token_account(&treasury, 0);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetTreasury {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetTreasury { treasury }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_treasury");
+
+    let first = Keypair::new();
+    ctx.svm.airdrop(&first.pubkey(), 1_000_000_000).unwrap();
+    let first_ata = ctx.create_token_account(&first.pubkey(), 0);
+    let second = Pubkey::new_unique();
+    let first_leaf = leaderboard_leaf(&first.pubkey(), 1, 95);
+    let second_leaf = leaderboard_leaf(&second, 2, 90);
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&first_leaf, &second_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let claim = ctx.claim_prize_ix(&pool, &first.pubkey(), first_ata, 1, 95, vec![second_leaf]);
+    ctx.send(&[claim], &[&first]).expect("claim_prize");
+
+    let leaf = |wallet: Pubkey, rank, score, sibling| SweptLeaf {
+        wallet,
+        rank,
+        score,
+        proof: vec![sibling],
+    };
+    let sweep = |ctx: &TestContext, leaves: Vec<SweptLeaf>| {
+        ctx.sweep_expired_ix(&pool, treasury_ata, leaves)
+    };
+
+    // Winners keep their prizes until the claim deadline
+    let ix = sweep(&ctx, vec![leaf(second, 2, 90, first_leaf)]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = state.claim_deadline;
+    ctx.svm.set_sysvar(&clock);
+
+    // The claimed leaf's receipt is archived and closed, the other prize expires
+    let receipt = TestContext::claim_record_address(&pool.prize_pool, &first.pubkey());
+    let receipt_rent = ctx.svm.get_account(&receipt).unwrap().lamports;
+    let before = ctx.svm.get_balance(&first.pubkey()).unwrap();
+    let leaves = vec![leaf(first.pubkey(), 1, 95, second_leaf), leaf(second, 2, 90, first_leaf)];
+    let ix = sweep(&ctx, leaves);
+    ctx.send(&[ix], &[]).expect("sweep_expired");
+    assert_eq!(ctx.token_balance(&treasury_ata), POOL_SIZE / 2);
+    assert_eq!(ctx.token_balance(&pool.token_account), 0);
+    assert!(ctx.svm.get_account(&receipt).is_none());
+    assert_eq!(ctx.svm.get_balance(&first.pubkey()).unwrap(), before + receipt_rent);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert!(state.expired_swept);
+    assert_eq!(state.swept, POOL_SIZE / 2);
+    assert_eq!(state.expected_vault_balance(), Some(0));
+    let archive: WeekArchive = ctx.account(&TestContext::week_archive_address(&pool.prize_pool));
+    assert_eq!(archive.receipts_archived, 1);
+
+    // Each leaf is gone through once, and the vault is swept once
+    let ix = sweep(&ctx, vec![leaf(second, 2, 90, first_leaf)]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.archive_week_ix(&pool, &[]);
+    ctx.send(&[ix], &[]).expect("archive_week");
+    let ix = sweep(&ctx, Vec::new());
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
+
+#[test]
+fn sweep_expired_takes_the_pool_cuts_when_nobody_claimed() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.update_config_ix(500);
+    ctx.send(&[ix], &[]).expect("update_config");
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let treasury = Pubkey::new_unique();
+    let treasury_ata = ctx.create_token_account(&treasury, 0);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetTreasury {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetTreasury { treasury }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_treasury");
+
+    let winner = Pubkey::new_unique();
+    let winner_leaf = leaderboard_leaf(&winner, 1, 95);
+    ctx.enable_feature(FEATURE_CLAIMS);
+    let ix = ctx.finalize_leaderboard_ix(&pool, winner_leaf, 1);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = state.claim_deadline;
+    ctx.svm.set_sysvar(&clock);
+
+    // The creator gets the royalty a claim would have paid, the rest is swept
+    let leaves = vec![SweptLeaf { wallet: winner, rank: 1, score: 95, proof: Vec::new() }];
+    let ix = ctx.sweep_expired_ix(&pool, treasury_ata, leaves);
+    ctx.send(&[ix], &[]).expect("sweep_expired");
+    let royalty = POOL_SIZE * 5 / 100;
+    assert_eq!(ctx.token_balance(&ctx.creator_token_account), royalty);
+    assert_eq!(ctx.token_balance(&treasury_ata), POOL_SIZE - royalty);
+    let state: PrizePool = ctx.account(&pool.prize_pool);
+    assert_eq!(state.claim_basis, POOL_SIZE - royalty);
+    assert_eq!(state.swept, POOL_SIZE - royalty);
+    assert_eq!(state.expected_vault_balance(), Some(0));
+}
+
+#[test]
+fn archived_pools_close_registrations_and_refund_rent() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+    ctx.send(&[ix], &[&player]).expect("register_player");
+    let registration = TestContext::registration_address(&pool.prize_pool, &player.pubkey());
+    let rent = ctx.svm.get_account(&registration).unwrap().lamports;
+
+    let authority = ctx.authority.pubkey();
+    let prize_pool = pool.prize_pool;
+    let close_ix = move |players: &[Pubkey]| {
+        let mut accounts = pardon_prizes::accounts::CloseRegistrations {
+            prize_pool,
+            authority,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None);
+        for player in players {
+            let registration = TestContext::registration_address(&prize_pool, player);
+            accounts.push(AccountMeta::new(registration, false));
+            accounts.push(AccountMeta::new(*player, false));
+        }
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts,
+            data: pardon_prizes::instruction::CloseRegistrations {}.data(),
+        }
+    };
+    let ix = close_ix(&[player.pubkey()]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let player_ata = ctx.create_token_account(&player.pubkey(), 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(player.pubkey(), 1, 95)],
+        0,
+        &solo_accounts(&player.pubkey(), player_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    let ix = ctx.archive_week_ix(&pool, &[]);
+    ctx.send(&[ix], &[]).expect("archive_week");
+
+    let before = ctx.svm.get_balance(&player.pubkey()).unwrap();
+    let ix = close_ix(&[player.pubkey()]);
+    ctx.send(&[ix], &[]).expect("close_registrations");
+    assert_eq!(ctx.svm.get_account(&registration).map_or(0, |account| account.lamports), 0);
+    assert_eq!(ctx.svm.get_balance(&player.pubkey()).unwrap(), before + rent);
+
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+    assert!(ctx.send(&[ix], &[&player]).is_err());
+}
+
+#[test]
+fn anyone_closes_archived_pools_after_the_retention_period() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    ctx.set_token_balance(&pool.token_account, 0);
+
+    let retention = 30 * 24 * 60 * 60;
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetAutoClose {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetAutoClose { auto_close_after_secs: retention }
+            .data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_auto_close");
+
+    let cranker = Keypair::new();
+    ctx.svm.airdrop(&cranker.pubkey(), 1_000_000_000).unwrap();
+    let close = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::AutoClosePrizePool {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            authority: ctx.authority.pubkey(),
+            cranker: cranker.pubkey(),
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::AutoClosePrizePool {}.data(),
+    };
+    // Not archived yet, then archived but still inside the retention period
+    assert!(ctx.send(std::slice::from_ref(&close), &[&cranker]).is_err());
+    let ix = ctx.archive_week_ix(&pool, &[]);
+    ctx.send(&[ix], &[]).expect("archive_week");
+    assert!(ctx.send(std::slice::from_ref(&close), &[&cranker]).is_err());
+
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += retention;
+    ctx.svm.set_sysvar(&clock);
+    ctx.send(&[close], &[&cranker]).expect("auto_close_prize_pool");
+    assert_eq!(ctx.svm.get_account(&pool.prize_pool).map_or(0, |account| account.lamports), 0);
+}
+
+#[test]
+fn cpi_caller_allowlist_leaves_direct_calls_alone() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let admin = ctx.authority.pubkey();
+    let set_cpi_callers_ix = move |cpi_callers: Vec<Pubkey>| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetCpiCallers {
+            config: TestContext::config_address(),
+            admin,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetCpiCallers { cpi_callers }.data(),
+    };
+
+    let game = Pubkey::new_unique();
+    for invalid in [vec![game, game], vec![pardon_prizes::ID], vec![game; 5]] {
+        assert!(ctx.send(&[set_cpi_callers_ix(invalid)], &[]).is_err());
+    }
+    ctx.send(&[set_cpi_callers_ix(vec![game])], &[]).expect("set_cpi_callers");
+    let config: Config = ctx.account(&TestContext::config_address());
+    assert_eq!(config.cpi_callers, vec![game]);
+
+    // Top-level instructions are never a CPI, so they pass whatever the allowlist says
+    let funder = ctx.authority.pubkey();
+    let funder_ata = ctx.create_token_account(&funder, 100);
+    let ix = ctx.fund_pool_ix(&pool, &funder, funder_ata, 100, "");
+    ctx.send(&[ix], &[]).expect("fund_pool");
+    let ix = ctx.submit_score_ix(&pool, &Pubkey::new_unique(), 90);
+    ctx.send(&[ix], &[]).expect("submit_score");
+}
+
+#[test]
+fn bets_on_the_winner_share_the_losing_stakes() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let treasury = Pubkey::new_unique();
+    let treasury_ata = ctx.create_token_account(&treasury, 0);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetTreasury {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetTreasury { treasury }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_treasury");
+
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    for player in [&alice, &bob] {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+        ctx.send(&[ix], &[player]).expect("register_player");
+    }
+
+    let authority = ctx.authority.pubkey();
+    let prize_pool = pool.prize_pool;
+    let bet_market = TestContext::bet_market_address(&prize_pool);
+    let bet_vault = TestContext::bet_vault_address(&prize_pool);
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let ix = ctx.open_bet_market_ix(&pool, now, 1_000);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.open_bet_market_ix(&pool, now + 100, 10_001);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = ctx.open_bet_market_ix(&pool, now + 100, 1_000);
+    ctx.send(&[ix], &[]).expect("open_bet_market");
+
+    // Two bettors back alice, one backs bob
+    let bettors: Vec<(Keypair, Pubkey, Pubkey, u64)> = [
+        (alice.pubkey(), 300),
+        (alice.pubkey(), 100),
+        (bob.pubkey(), 600),
+    ]
+    .into_iter()
+    .map(|(player, amount)| {
+        let bettor = Keypair::new();
+        ctx.svm.airdrop(&bettor.pubkey(), 1_000_000_000).unwrap();
+        let ata = ctx.create_token_account(&bettor.pubkey(), amount);
+        (bettor, ata, player, amount)
+    })
+    .collect();
+    for (bettor, ata, player, amount) in &bettors {
+        let ix = TestContext::place_bet_ix(&pool, &bettor.pubkey(), *ata, *player, *amount);
+        ctx.send(&[ix], &[bettor]).expect("place_bet");
+    }
+    let (first, first_ata, ..) = &bettors[0];
+    ctx.set_token_balance(first_ata, 1);
+    let ix = TestContext::place_bet_ix(&pool, &first.pubkey(), *first_ata, bob.pubkey(), 1);
+    assert!(ctx.send(&[ix], &[first]).is_err());
+    // Only registered players can be backed
+    let ix = TestContext::place_bet_ix(&pool, &first.pubkey(), *first_ata, Pubkey::new_unique(), 1);
+    assert!(ctx.send(&[ix], &[first]).is_err());
+    ctx.set_token_balance(first_ata, 0);
+    assert_eq!(ctx.token_balance(&bet_vault), 1_000);
+
+    let alice_leaf = leaderboard_leaf(&alice.pubkey(), 1, 95);
+    let bob_leaf = leaderboard_leaf(&bob.pubkey(), 2, 90);
+    let ix = ctx.finalize_leaderboard_ix(&pool, hash_pair(&alice_leaf, &bob_leaf), 2);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+
+    let settle_ix = move |winner: Pubkey, score, proof| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SettleBets {
+            config: TestContext::config_address(),
+            prize_pool,
+            bet_market,
+            bet_vault,
+            winner_outcome: TestContext::bet_outcome_address(&bet_market, &winner),
+            authority,
+            token_program: spl_token::ID,
+            treasury_token_account: Some(treasury_ata),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SettleBets { winner, score, proof }.data(),
+    };
+    let ix = settle_ix(alice.pubkey(), 95, vec![bob_leaf]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = now + 100;
+    ctx.svm.set_sysvar(&clock);
+    // Rank 2 can't be settled as the winner
+    let ix = settle_ix(bob.pubkey(), 90, vec![alice_leaf]);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let ix = settle_ix(alice.pubkey(), 95, vec![bob_leaf]);
+    ctx.send(&[ix], &[]).expect("settle_bets");
+
+    // 10% of bob's 600 goes to the treasury, alice's backers split the other 940 3:1
+    assert_eq!(ctx.token_balance(&treasury_ata), 60);
+    let market: BetMarket = ctx.account(&bet_market);
+    assert_eq!(market.winner, Some(alice.pubkey()));
+    assert_eq!(market.payout_pool, 940);
+
+    let (loser, loser_ata, ..) = &bettors[2];
+    let ix = TestContext::claim_bet_winnings_ix(&pool, &loser.pubkey(), *loser_ata);
+    assert!(ctx.send(&[ix], &[loser]).is_err());
+    for ((bettor, ata, ..), expected) in bettors.iter().zip([705, 235]) {
+        let ix = TestContext::claim_bet_winnings_ix(&pool, &bettor.pubkey(), *ata);
+        ctx.send(std::slice::from_ref(&ix), &[bettor]).expect("claim_bet_winnings");
+        assert_eq!(ctx.token_balance(ata), expected);
+        assert!(ctx.send(&[ix], &[bettor]).is_err());
+    }
+    assert_eq!(ctx.token_balance(&bet_vault), 0);
+}
+
+#[test]
+fn cancelled_weeks_refund_every_bet() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let player = Keypair::new();
+    ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+    let ix = TestContext::register_player_ix(&pool, &player.pubkey(), None, None);
+    ctx.send(&[ix], &[&player]).expect("register_player");
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let ix = ctx.open_bet_market_ix(&pool, now + 100, 1_000);
+    ctx.send(&[ix], &[]).expect("open_bet_market");
+
+    let bettor = Keypair::new();
+    ctx.svm.airdrop(&bettor.pubkey(), 1_000_000_000).unwrap();
+    let bettor_ata = ctx.create_token_account(&bettor.pubkey(), 500);
+    let ix = TestContext::place_bet_ix(&pool, &bettor.pubkey(), bettor_ata, player.pubkey(), 400);
+    ctx.send(&[ix], &[&bettor]).expect("place_bet");
+
+    let bet_market = TestContext::bet_market_address(&pool.prize_pool);
+    let bettor_key = bettor.pubkey();
+    let player_key = player.pubkey();
+    let refund_ix = || TestContext::refund_bet_ix(&pool, &bettor_key, bettor_ata, &player_key);
+    assert!(ctx.send(&[refund_ix()], &[&bettor]).is_err());
+
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+    let destination = ctx.create_token_account(&Pubkey::new_unique(), 0);
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::AnnounceEmergencyWithdraw {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            destination,
+            guardian: guardian.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::AnnounceEmergencyWithdraw {}.data(),
+    };
+    ctx.send(&[ix], &[&guardian]).expect("announce_emergency_withdraw");
+
+    // The cancelled week takes no more bets
+    let ix = TestContext::place_bet_ix(&pool, &bettor.pubkey(), bettor_ata, player.pubkey(), 100);
+    assert!(ctx.send(&[ix], &[&bettor]).is_err());
+
+    ctx.send(&[refund_ix()], &[&bettor]).expect("refund_bet");
+    assert_eq!(ctx.token_balance(&bettor_ata), 500);
+    let bet = TestContext::bet_address(&bet_market, &bettor_key);
+    assert_eq!(ctx.svm.get_account(&bet).map_or(0, |account| account.lamports), 0);
+    assert!(ctx.send(&[refund_ix()], &[&bettor]).is_err());
+}
+
+#[test]
+fn disqualified_players_and_overdue_markets_refund_bets() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let guardian = Keypair::new();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetGuardian {
+            config: TestContext::config_address(),
+            admin: ctx.authority.pubkey(),
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_guardian");
+    let bond = 500;
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetEntryBond {
+            prize_pool: pool.prize_pool,
+            bond_vault: TestContext::bond_vault_address(&pool.prize_pool),
+            mint: ctx.mint,
+            authority: ctx.authority.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetEntryBond { entry_bond: bond }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_entry_bond");
+
+    let honest = Keypair::new();
+    let cheater = Keypair::new();
+    for player in [&honest, &cheater] {
+        ctx.svm.airdrop(&player.pubkey(), 1_000_000_000).unwrap();
+        let player_ata = ctx.create_token_account(&player.pubkey(), bond);
+        let mut ix =
+            TestContext::register_player_ix(&pool, &player.pubkey(), None, Some(player_ata));
+        // The bond vault sits just before the two event CPI accounts
+        let at = ix.accounts.len() - 3;
+        ix.accounts[at] =
+            AccountMeta::new(TestContext::bond_vault_address(&pool.prize_pool), false);
+        ctx.send(&[ix], &[player]).expect("register_player");
+    }
+
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let ix = ctx.open_bet_market_ix(&pool, now + 100, 1_000);
+    ctx.send(&[ix], &[]).expect("open_bet_market");
+    let bet_market = TestContext::bet_market_address(&pool.prize_pool);
+    let mut bettors = Vec::new();
+    for (player, amount) in [(cheater.pubkey(), 300), (honest.pubkey(), 200)] {
+        let bettor = Keypair::new();
+        ctx.svm.airdrop(&bettor.pubkey(), 1_000_000_000).unwrap();
+        let ata = ctx.create_token_account(&bettor.pubkey(), amount);
+        let ix = TestContext::place_bet_ix(&pool, &bettor.pubkey(), ata, player, amount);
+        ctx.send(&[ix], &[&bettor]).expect("place_bet");
+        bettors.push((bettor, ata, player));
+    }
+    let refund_ix = |(bettor, ata, player): &(Keypair, Pubkey, Pubkey)| {
+        TestContext::refund_bet_ix(&pool, &bettor.pubkey(), *ata, player)
+    };
+    for bettor in &bettors {
+        assert!(ctx.send(&[refund_ix(bettor)], &[&bettor.0]).is_err());
+    }
+
+    // Backers of a player caught cheating get their stake back right away
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SlashEntryBond {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            registration: TestContext::registration_address(&pool.prize_pool, &cheater.pubkey()),
+            bond_vault: TestContext::bond_vault_address(&pool.prize_pool),
+            prize_pool_token_account: pool.token_account,
+            guardian: guardian.pubkey(),
+            token_program: spl_token::ID,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SlashEntryBond {}.data(),
+    };
+    ctx.send(&[ix], &[&guardian]).expect("slash_entry_bond");
+    ctx.send(&[refund_ix(&bettors[0])], &[&bettors[0].0]).expect("refund_bet");
+    assert_eq!(ctx.token_balance(&bettors[0].1), 300);
+    assert!(ctx.send(&[refund_ix(&bettors[1])], &[&bettors[1].0]).is_err());
+    let market: BetMarket = ctx.account(&bet_market);
+    assert_eq!(market.total_staked, 200);
+    assert_eq!(market.settle_deadline, now + 100 + BET_SETTLE_WINDOW_SECONDS);
+
+    // Nobody settled the market in time: it can't be settled any more, only refunded
+    let honest_leaf = leaderboard_leaf(&honest.pubkey(), 1, 95);
+    let ix = ctx.finalize_leaderboard_ix(&pool, honest_leaf, 1);
+    ctx.send(&[ix], &[]).expect("finalize_leaderboard");
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = market.settle_deadline;
+    ctx.svm.set_sysvar(&clock);
+    let winner = honest.pubkey();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SettleBets {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            bet_market,
+            bet_vault: TestContext::bet_vault_address(&pool.prize_pool),
+            winner_outcome: TestContext::bet_outcome_address(&bet_market, &winner),
+            authority: ctx.authority.pubkey(),
+            token_program: spl_token::ID,
+            treasury_token_account: None,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SettleBets { winner, score: 95, proof: vec![] }.data(),
+    };
+    assert!(ctx.send(&[ix], &[]).is_err());
+    ctx.send(&[refund_ix(&bettors[1])], &[&bettors[1].0]).expect("refund_bet");
+    assert_eq!(ctx.token_balance(&bettors[1].1), 200);
+    assert_eq!(ctx.token_balance(&TestContext::bet_vault_address(&pool.prize_pool)), 0);
+}
+
+#[test]
+fn usd_prizes_convert_at_a_fresh_confident_price() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+
+    let feed_id = [3; 32];
+    let authority = ctx.authority.pubkey();
+    let prize_pool = pool.prize_pool;
+    let mint = ctx.mint;
+    let set_usd_prizes_ix = move |usd_cents, max_conf_bps| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetUsdPrizes { prize_pool, mint, authority }
+            .to_account_metas(None),
+        data: pardon_prizes::instruction::SetUsdPrizes {
+            usd_cents,
+            feed_id,
+            max_price_age_secs: 60,
+            max_conf_bps,
+        }
+        .data(),
+    };
+    assert!(ctx.send(&[set_usd_prizes_ix(100_000, 0)], &[]).is_err());
+    // $1,000 across the ranks, so a $500 first prize
+    ctx.send(&[set_usd_prizes_ix(100_000, 100)], &[]).expect("set_usd_prizes");
+
+    let distribute = |ctx: &TestContext| {
+        distribute_ix(
+            ctx,
+            &pool,
+            vec![solo(winner, 1, 95)],
+            0,
+            &solo_accounts(&winner, winner_ata),
+        )
+    };
+    assert!(ctx.send(&[distribute(&ctx)], &[]).is_err());
+
+    // $2.50 per token, so 400 tokens back the $1,000
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let price = 250_000_000;
+    for (feed, conf, publish_time) in [
+        ([4; 32], 0, now),
+        (feed_id, 0, now - 61),
+        (feed_id, price as u64 / 50, now),
+    ] {
+        ctx.set_price_update(feed, price, conf, -8, publish_time);
+        assert!(ctx.send(&[distribute(&ctx)], &[]).is_err());
+    }
+
+    ctx.set_price_update(feed_id, price, price as u64 / 200, -8, now - 60);
+    ctx.send(&[distribute(&ctx)], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), 200_000_000);
+    assert_eq!(ctx.token_balance(&pool.token_account), POOL_SIZE - 200_000_000);
+
+    // Priced amounts are locked in once paid
+    assert!(ctx.send(&[set_usd_prizes_ix(0, 100)], &[]).is_err());
+}
+
+#[test]
+fn pool_roles_move_operations_to_their_own_keys() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let poster = Keypair::new();
+    let closer = Keypair::new();
+    let prize_pool = pool.prize_pool;
+    let set_pool_role_ix = move |authority: Pubkey, role, key| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPoolRole {
+            prize_pool,
+            authority,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPoolRole { role, key }.data(),
+    };
+
+    let authority = ctx.authority.pubkey();
+    let ix = set_pool_role_ix(poster.pubkey(), PoolRole::Posting, poster.pubkey());
+    assert!(ctx.send(&[ix], &[&poster]).is_err());
+    let ix = set_pool_role_ix(authority, PoolRole::Posting, poster.pubkey());
+    ctx.send(&[ix], &[]).expect("set_pool_role");
+    let ix = set_pool_role_ix(authority, PoolRole::Closing, closer.pubkey());
+    ctx.send(&[ix], &[]).expect("set_pool_role");
+    let roles = ctx.account::<PrizePool>(&prize_pool).roles;
+    assert_eq!(roles.poster, poster.pubkey());
+    assert_eq!(roles.closer, closer.pubkey());
+    assert_eq!(roles.distributor, authority);
+
+    // The authority gave posting away, so only the poster can publish the leaderboard
+    let ix = ctx.finalize_leaderboard_ix(&pool, [1; 32], 1);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    let mut ix = ctx.finalize_leaderboard_ix(&pool, [1; 32], 1);
+    ix.accounts[1] = AccountMeta::new_readonly(poster.pubkey(), true);
+    ctx.send(&[ix], &[&poster]).expect("finalize_leaderboard");
+
+    let close_ix = move |closer: Pubkey| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::ClosePrizePool {
+            prize_pool,
+            prize_pool_token_account: TestContext::vault_address(&prize_pool),
+            authority,
+            closer,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::ClosePrizePool {}.data(),
+    };
+    assert!(ctx.send(&[close_ix(authority)], &[]).is_err());
+    let rent = ctx.svm.get_account(&prize_pool).unwrap().lamports;
+    let before = ctx.svm.get_balance(&authority).unwrap();
+    ctx.send(&[close_ix(closer.pubkey())], &[&closer]).expect("close_prize_pool");
+    // The authority pays the fee but collects the rent
+    assert_eq!(ctx.svm.get_balance(&authority).unwrap(), before + rent - 10_000);
+}
+
+#[test]
+fn delegates_run_allowed_instructions_until_they_expire() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let operator = Keypair::new();
+    ctx.svm.airdrop(&operator.pubkey(), 1_000_000_000).unwrap();
+    let authority = ctx.authority.pubkey();
+    let prize_pool = pool.prize_pool;
+    let delegate_ix = move |key, expires_at, allowed_ixs| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::DelegateAuthority {
+            prize_pool,
+            authority,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::DelegateAuthority { key, expires_at, allowed_ixs }.data(),
+    };
+
+    let now = ctx.svm.get_sysvar::<Clock>().unix_timestamp;
+    let key = operator.pubkey();
+    assert!(ctx.send(&[delegate_ix(key, now + 100, 1 << 40)], &[]).is_err());
+    assert!(ctx.send(&[delegate_ix(key, now, DELEGATE_DISTRIBUTE_PRIZES)], &[]).is_err());
+    let ix = delegate_ix(key, now + 100, DELEGATE_DISTRIBUTE_PRIZES);
+    ctx.send(&[ix], &[]).expect("delegate_authority");
+
+    // Signing as the operator in place of the distributor
+    let as_operator = |mut ix: Instruction| {
+        let signer = ix.accounts.iter_mut().find(|meta| meta.pubkey == authority).unwrap();
+        *signer = AccountMeta::new(operator.pubkey(), true);
+        ix
+    };
+    let ix = as_operator(ctx.finalize_leaderboard_ix(&pool, [1; 32], 1));
+    assert!(ctx.send(&[ix], &[&operator]).is_err());
+
+    let first = Pubkey::new_unique();
+    let first_ata = ctx.create_token_account(&first, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(first, 1, 95)],
+        0,
+        &solo_accounts(&first, first_ata),
+    );
+    ctx.send(&[as_operator(ix)], &[&operator]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&first_ata), POOL_SIZE / 2);
+
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = now + 100;
+    ctx.svm.set_sysvar(&clock);
+    let second = Pubkey::new_unique();
+    let second_ata = ctx.create_token_account(&second, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(second, 2, 90)],
+        1,
+        &solo_accounts(&second, second_ata),
+    );
+    assert!(ctx.send(&[as_operator(ix.clone())], &[&operator]).is_err());
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    // No instructions revokes the delegate outright
+    let ix = delegate_ix(key, 0, DELEGATE_FINALIZE_LEADERBOARD);
+    assert!(ctx.send(&[ix], &[]).is_err());
+    ctx.send(&[delegate_ix(key, 0, 0)], &[]).expect("delegate_authority");
+    assert_eq!(ctx.account::<PrizePool>(&prize_pool).delegation, None);
+}
+
+#[test]
+fn key_changes_keep_a_bounded_history() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, 0);
+    let prize_pool = pool.prize_pool;
+    let authority = ctx.authority.pubkey();
+    let set_pool_role_ix = move |key| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetPoolRole {
+            prize_pool,
+            authority,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetPoolRole { role: PoolRole::Posting, key }.data(),
+    };
+
+    let posters: Vec<Pubkey> = (0..MAX_AUTHORITY_HISTORY + 2)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    for poster in &posters {
+        ctx.send(&[set_pool_role_ix(*poster)], &[]).expect("set_pool_role");
+    }
+    // Handing the role to its current holder changes nothing
+    ctx.send(&[set_pool_role_ix(*posters.last().unwrap())], &[]).expect("set_pool_role");
+
+    let history = ctx.account::<PrizePool>(&prize_pool).authority_history;
+    assert_eq!(history.len(), MAX_AUTHORITY_HISTORY);
+    assert!(history.iter().all(|change| change.kind == AuthorityKind::Poster));
+    // The two oldest changes were dropped, the rest chain from one poster to the next
+    assert_eq!(history[0].previous, posters[1]);
+    assert_eq!(history[0].key, posters[2]);
+    assert_eq!(history.last().unwrap().key, posters[MAX_AUTHORITY_HISTORY + 1]);
+
+    let treasury = Pubkey::new_unique();
+    let ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::SetTreasury {
+            config: TestContext::config_address(),
+            admin: authority,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::SetTreasury { treasury }.data(),
+    };
+    ctx.send(&[ix], &[]).expect("set_treasury");
+    let history = ctx.account::<Config>(&TestContext::config_address()).authority_history;
+    let change = history.last().unwrap();
+    assert_eq!(change.kind, AuthorityKind::Treasury);
+    assert_eq!(change.previous, Pubkey::default());
+    assert_eq!(change.key, treasury);
+}
+
+#[test]
+fn vault_balance_matches_the_pool_counters() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+
+    let prize_pool = ctx.account::<PrizePool>(&pool.prize_pool);
+    assert_eq!(
+        prize_pool.expected_vault_balance(),
+        Some(ctx.token_balance(&pool.token_account))
+    );
+    let verify_ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::VerifyAccounting {
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            event_authority: TestContext::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::VerifyAccounting {}.data(),
+    };
+    ctx.send(std::slice::from_ref(&verify_ix), &[]).expect("verify_accounting");
+
+    // A raw transfer skips the counters; the check reports it instead of failing
+    let authority = ctx.authority.pubkey();
+    let donor_ata = ctx.create_token_account(&authority, 1_000);
+    let donation = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &donor_ata,
+        &pool.token_account,
+        &authority,
+        &[],
+        1_000,
+    )
+    .unwrap();
+    ctx.send(&[donation], &[]).expect("transfer");
+    let prize_pool = ctx.account::<PrizePool>(&pool.prize_pool);
+    assert_eq!(
+        prize_pool.expected_vault_balance(),
+        Some(ctx.token_balance(&pool.token_account) - 1_000)
+    );
+    ctx.send(&[verify_ix], &[]).expect("verify_accounting");
+}
+
+#[test]
+fn views_report_what_the_pool_would_pay() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let pool = ctx.funded_pool(WEEK, POOL_SIZE);
+    let preview_ix = |rank| Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::PreviewPrize {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::PreviewPrize { rank }.data(),
+    };
+    let status_ix = || Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::GetPoolStatus {
+            config: TestContext::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+        }
+        .to_account_metas(None),
+        data: pardon_prizes::instruction::PoolStatus {}.data(),
+    };
+
+    let preview: PrizePreview = ctx.simulate(preview_ix(1));
+    assert_eq!(preview.basis, POOL_SIZE);
+    assert_eq!(preview.sponsor_bonus, 0);
+    let status: PoolStatus = ctx.simulate(status_ix());
+    assert_eq!(status.balance, POOL_SIZE);
+    assert_eq!(status.expected_balance, Some(POOL_SIZE));
+    assert!(status.payouts_pending && !status.leaderboard_finalized);
+
+    // The preview is exactly what distribution then pays
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), preview.base_prize);
+
+    let status: PoolStatus = ctx.simulate(status_ix());
+    assert_eq!(status.balance, POOL_SIZE - preview.base_prize);
+    assert!(!status.payouts_pending);
+}
+
+#[test]
+fn numbered_weeks_come_from_the_clock() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let ix = ctx.initialize_weekly_pool_ix(0, CATEGORY, MAX_RANK, &[]);
+    assert!(ctx.send(&[ix], &[]).is_err(), "no numbered weeks before the schedule is set");
+
+    // Week 0 starts Monday 2024-11-04 00:00 UTC, i.e. ISO week 2024-W45
+    let epoch_start = 1_730_678_400;
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = epoch_start + 2 * WEEK_SECONDS + 3_600;
+    ctx.svm.set_sysvar(&clock);
+    let ix = ctx.set_week_schedule_ix(epoch_start);
+    ctx.send(&[ix], &[]).expect("set_week_schedule");
+    let ix = ctx.set_week_schedule_ix(epoch_start + 1);
+    assert!(ctx.send(&[ix], &[]).is_err(), "moving the epoch would renumber pools");
+
+    let current_week_ix = Instruction {
+        program_id: pardon_prizes::ID,
+        accounts: pardon_prizes::accounts::CurrentWeek { config: TestContext::config_address() }
+            .to_account_metas(None),
+        data: pardon_prizes::instruction::CurrentWeek {}.data(),
+    };
+    let week: WeekInfo = ctx.simulate(current_week_ix);
+    assert_eq!(
+        week,
+        WeekInfo {
+            week_index: 2,
+            week_id: "2024-W47".to_string(),
+            starts_at: epoch_start + 2 * WEEK_SECONDS,
+            ends_at: epoch_start + 3 * WEEK_SECONDS,
+        }
+    );
+
+    // Only the current week and the next one are open
+    for week_index in [1, 4] {
+        let ix = ctx.initialize_weekly_pool_ix(week_index, CATEGORY, MAX_RANK, &[]);
+        assert!(ctx.send(&[ix], &[]).is_err(), "week {week_index} is not open");
+    }
+    for week_index in [2, 3] {
+        let ix = ctx.initialize_weekly_pool_ix(week_index, CATEGORY, MAX_RANK, &[]);
+        ctx.send(&[ix], &[]).expect("initialize_weekly_pool");
+    }
+    let prize_pool = TestContext::weekly_pool_address(2, CATEGORY);
+    let state = ctx.account::<PrizePool>(&prize_pool);
+    assert_eq!(state.week_index, Some(2));
+    assert_eq!(state.week_id, "2024-W47");
+    let next = ctx.account::<PrizePool>(&TestContext::weekly_pool_address(3, CATEGORY));
+    assert_eq!(next.week_id, "2024-W48");
+
+    // The pool signs for its vault under its numbered seeds
+    let pool = support::FundedPool {
+        week_id: state.week_id,
+        category: CATEGORY.to_string(),
+        prize_pool,
+        token_account: TestContext::vault_address(&prize_pool),
+        mint: state.mint,
+    };
+    let funder = ctx.authority.pubkey();
+    let funder_token_account = ctx.create_token_account(&funder, POOL_SIZE);
+    let ix = ctx.fund_pool_ix(&pool, &funder, funder_token_account, POOL_SIZE, "week 2");
+    ctx.send(&[ix], &[]).expect("fund_pool");
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &pool,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    assert_eq!(ctx.token_balance(&winner_ata), POOL_SIZE / 2);
+}
+
+#[test]
+fn rollover_opens_the_next_week_with_last_weeks_surplus() {
+    let Some(mut ctx) = TestContext::try_new() else { return };
+    let epoch_start = 1_730_678_400; // 2024-W45
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = epoch_start + 60;
+    ctx.svm.set_sysvar(&clock);
+    let ix = ctx.set_week_schedule_ix(epoch_start);
+    ctx.send(&[ix], &[]).expect("set_week_schedule");
+    let ix = ctx.initialize_weekly_pool_ix(0, CATEGORY, MAX_RANK, &[]);
+    ctx.send(&[ix], &[]).expect("initialize_weekly_pool");
+    let week_0 = ctx.numbered_pool(0, CATEGORY);
+
+    // Week 0 pays its only winner half the pool, then gets archived
+    let funder = ctx.authority.pubkey();
+    let funder_token_account = ctx.create_token_account(&funder, POOL_SIZE);
+    let ix = ctx.fund_pool_ix(&week_0, &funder, funder_token_account, POOL_SIZE, "week 0");
+    ctx.send(&[ix], &[]).expect("fund_pool");
+    let winner = Pubkey::new_unique();
+    let winner_ata = ctx.create_token_account(&winner, 0);
+    let ix = distribute_ix(
+        &ctx,
+        &week_0,
+        vec![solo(winner, 1, 95)],
+        0,
+        &solo_accounts(&winner, winner_ata),
+    );
+    ctx.send(&[ix], &[]).expect("distribute_prizes");
+    let ix = ctx.archive_week_ix(&week_0, &[]);
+    ctx.send(&[ix], &[]).expect("archive_week");
+
+    let ix = ctx.rollover_week_ix(&week_0, 1, Some(&week_0));
+    assert!(ctx.send(&[ix], &[]).is_err(), "rollover needs pool defaults");
+    let ix = ctx.set_pool_defaults_ix(3, &[6_000, 3_000, 1_000]);
+    ctx.send(&[ix], &[]).expect("set_pool_defaults");
+    let ix = ctx.rollover_week_ix(&week_0, 1, Some(&week_0));
+    assert!(ctx.send(&[ix], &[]).is_err(), "week 0 hasn't ended");
+
+    // Anyone can crank it once week 1 has begun
+    let mut clock = ctx.svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = epoch_start + WEEK_SECONDS;
+    ctx.svm.set_sysvar(&clock);
+    let cranker = Keypair::new();
+    ctx.svm.airdrop(&cranker.pubkey(), 1_000_000_000).unwrap();
+    let mut ix = ctx.rollover_week_ix(&week_0, 1, Some(&week_0));
+    let payer = ix.accounts.iter_mut().find(|meta| meta.is_signer).unwrap();
+    payer.pubkey = cranker.pubkey();
+    ctx.send(&[ix], &[&cranker]).expect("rollover_week");
+
+    let week_1 = ctx.numbered_pool(1, CATEGORY);
+    let state: PrizePool = ctx.account(&week_1.prize_pool);
+    assert_eq!(state.week_id, "2024-W46");
+    assert_eq!(state.week_index, Some(1));
+    assert_eq!(state.authority, ctx.authority.pubkey());
+    assert_eq!((state.max_winners, state.rank_shares_bps), (3, vec![6_000, 3_000, 1_000]));
+    assert_eq!(state.total_funded, POOL_SIZE / 2);
+    assert_eq!(ctx.token_balance(&week_1.token_account), POOL_SIZE / 2);
+
+    let source: PrizePool = ctx.account(&week_0.prize_pool);
+    assert_eq!(source.rolled_over, POOL_SIZE / 2);
+    assert_eq!(source.expected_vault_balance(), Some(0));
+    assert_eq!(ctx.token_balance(&week_0.token_account), 0);
+
+    // Each week opens once
+    let ix = ctx.rollover_week_ix(&week_0, 1, None);
+    assert!(ctx.send(&[ix], &[]).is_err());
+}
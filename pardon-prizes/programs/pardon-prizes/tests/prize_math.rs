@@ -0,0 +1,315 @@
+//! Property tests for the rank-based prize split.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Space;
+use pardon_prizes::math::{
+    calculate_prize, calculate_prize_with, isqrt, normalize_score, parse_week_id, previous_week_id, split_by_bps,
+    accrued_rewards, reward_per_share_increase, stake_bonus, stake_multiplier_bps,
+    boosted_score, happy_hour_multiplier_bps, BPS_DENOMINATOR, MAX_HAPPY_HOUR_MULTIPLIER_BPS,
+    MAX_RANK, MAX_SCORE, MAX_STAKE_MULTIPLIER_BPS, bps_of, parimutuel_payout, price_within_confidence,
+    usd_to_tokens, iso_week_id, week_index_at, week_start, WEEK_SECONDS, tier_multiplier_bps,
+    weighted_share, trimmed_mean, highest_paid_rank, rank_bit,
+};
+use pardon_prizes::{HappyHour, ListedWinner, QualificationTier, ScoreCurve, StakeTier};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn prizes_never_exceed_total(total in any::<u64>()) {
+        let paid: u128 = (1..=MAX_RANK)
+            .map(|rank| calculate_prize(rank, total) as u128)
+            .sum();
+        prop_assert!(paid <= total as u128);
+    }
+
+    #[test]
+    fn prizes_are_monotonic_by_rank(total in any::<u64>(), rank in 1..MAX_RANK) {
+        prop_assert!(calculate_prize(rank, total) >= calculate_prize(rank + 1, total));
+    }
+
+    #[test]
+    fn invalid_ranks_pay_nothing(total in any::<u64>(), rank in (MAX_RANK + 1)..=u8::MAX) {
+        prop_assert_eq!(calculate_prize(0, total), 0);
+        prop_assert_eq!(calculate_prize(rank, total), 0);
+    }
+
+    #[test]
+    fn prizes_grow_with_total(a in any::<u64>(), b in any::<u64>(), rank in 1..=MAX_RANK) {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        prop_assert!(calculate_prize(rank, low) <= calculate_prize(rank, high));
+    }
+
+    #[test]
+    fn bps_split_is_exact(amount in any::<u64>(), cuts in prop::collection::vec(0..=BPS_DENOMINATOR, 0..4)) {
+        // Turn arbitrary cut points into shares that sum to 100%
+        let mut points = cuts;
+        points.push(0);
+        points.push(BPS_DENOMINATOR);
+        points.sort_unstable();
+        let shares: Vec<u16> = points.windows(2).map(|w| w[1] - w[0]).collect();
+
+        let parts = split_by_bps(amount, &shares);
+        prop_assert_eq!(parts.len(), shares.len());
+        prop_assert_eq!(parts.iter().map(|&p| p as u128).sum::<u128>(), amount as u128);
+    }
+
+    #[test]
+    fn custom_split_never_exceeds_total(
+        total in any::<u64>(),
+        shares in prop::collection::vec(0..=BPS_DENOMINATOR / MAX_RANK as u16, 1..=MAX_RANK as usize),
+    ) {
+        let paid: u128 = (0..=u8::MAX)
+            .map(|rank| calculate_prize_with(rank, total, &shares) as u128)
+            .sum();
+        prop_assert!(paid <= total as u128);
+        prop_assert_eq!(calculate_prize_with(0, total, &shares), 0);
+    }
+
+    #[test]
+    fn isqrt_is_floor_sqrt(n in any::<u64>()) {
+        let r = isqrt(n) as u128;
+        prop_assert!(r * r <= n as u128);
+        prop_assert!((r + 1) * (r + 1) > n as u128);
+    }
+
+    #[test]
+    fn normalized_scores_stay_in_range(
+        (raw_max, raw) in (1..=u16::MAX).prop_flat_map(|max| (Just(max), 0..=max)),
+        curve in prop_oneof![
+            Just(ScoreCurve::Linear),
+            Just(ScoreCurve::SquareRoot),
+            Just(ScoreCurve::Square),
+        ],
+    ) {
+        let score = normalize_score(raw, raw_max, curve);
+        prop_assert!(score <= MAX_SCORE);
+        prop_assert_eq!(normalize_score(raw_max, raw_max, curve), MAX_SCORE);
+        if raw < raw_max {
+            prop_assert!(score <= normalize_score(raw + 1, raw_max, curve));
+        }
+    }
+
+    #[test]
+    fn stake_bonus_is_capped(
+        amount in any::<u64>(),
+        staked in any::<u64>(),
+        tiers in prop::collection::vec((any::<u64>(), any::<u16>()), 0..5),
+    ) {
+        let tiers: Vec<StakeTier> = tiers
+            .into_iter()
+            .map(|(min_stake, multiplier_bps)| StakeTier { min_stake, multiplier_bps })
+            .collect();
+        let multiplier = stake_multiplier_bps(staked, &tiers);
+        prop_assert!((BPS_DENOMINATOR..=MAX_STAKE_MULTIPLIER_BPS).contains(&multiplier));
+        prop_assert!(stake_bonus(amount, multiplier) <= amount);
+    }
+
+    #[test]
+    fn staker_rewards_never_exceed_reward(
+        reward in any::<u64>(),
+        stakes in prop::collection::vec(1..=u32::MAX as u64, 1..5),
+    ) {
+        let total_staked: u64 = stakes.iter().sum();
+        let per_share = reward_per_share_increase(reward, total_staked);
+        let paid: u128 = stakes.iter().map(|&stake| accrued_rewards(stake, per_share)).sum();
+        prop_assert!(paid <= reward as u128);
+    }
+
+    #[test]
+    fn weighted_airdrop_shares_never_exceed_basis(
+        basis in any::<u64>(),
+        weights in prop::collection::vec(BPS_DENOMINATOR as u64..=30_000, 1..64),
+    ) {
+        let total_weight: u64 = weights.iter().sum();
+        let paid: u128 = weights
+            .iter()
+            .map(|&weight| weighted_share(basis, weight, total_weight) as u128)
+            .sum();
+        prop_assert!(paid <= basis as u128);
+    }
+
+    #[test]
+    fn happy_hour_boosts_stay_on_the_scale(
+        score in 0..=MAX_SCORE,
+        multiplier in BPS_DENOMINATOR..=MAX_HAPPY_HOUR_MULTIPLIER_BPS,
+    ) {
+        let boosted = boosted_score(score, multiplier);
+        prop_assert!(boosted >= score);
+        prop_assert!(boosted <= MAX_SCORE);
+    }
+
+    #[test]
+    fn parimutuel_payouts_never_exceed_pool(
+        stakes in prop::collection::vec(1..u64::MAX / 64, 1..32),
+        rake_bps in 0..=BPS_DENOMINATOR,
+        losing in 0..u64::MAX / 2,
+    ) {
+        let winning: u64 = stakes.iter().sum();
+        let payout_pool = winning + losing - bps_of(losing, rake_bps);
+        let paid: u128 = stakes
+            .iter()
+            .map(|&stake| parimutuel_payout(stake, payout_pool, winning) as u128)
+            .sum();
+        prop_assert!(paid <= payout_pool as u128);
+        // Backing the winner never returns less than the stake
+        prop_assert!(stakes
+            .iter()
+            .all(|&stake| parimutuel_payout(stake, payout_pool, winning) >= stake));
+    }
+
+    #[test]
+    fn usd_conversion_rounds_down_to_the_largest_affordable_amount(
+        usd_cents in 0..1_000_000_000u64,
+        price in 1..1_000_000_000_000i64,
+        exponent in -12..=0i32,
+        decimals in 0..=9u8,
+    ) {
+        // tokens * price * 10^exponent / 10^decimals USD, compared in cents
+        let budget = usd_cents as u128 * 10u128.pow((decimals as i32 - exponent) as u32);
+        let cost = |tokens: u128| tokens * price as u128 * 100;
+        match usd_to_tokens(usd_cents, price, exponent, decimals) {
+            Some(tokens) => {
+                prop_assert!(cost(tokens as u128) <= budget);
+                prop_assert!(cost(tokens as u128 + 1) > budget);
+            }
+            None => prop_assert!(budget / cost(1) > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn numbered_weeks_are_consecutive_iso_weeks(
+        epoch_start in 0..4_000_000_000i64,
+        week_index in 0..10_000u32,
+        offset in 0..WEEK_SECONDS,
+    ) {
+        let start = week_start(epoch_start, week_index);
+        prop_assert_eq!(week_index_at(epoch_start, start + offset), Some(week_index));
+        prop_assert_eq!(
+            previous_week_id(&iso_week_id(start + WEEK_SECONDS)),
+            Some(iso_week_id(start))
+        );
+    }
+
+    #[test]
+    fn listed_winners_pack_into_fixed_records(
+        wallet in any::<[u8; 32]>(),
+        rank in 1..=MAX_RANK,
+        score in any::<u8>(),
+        team in any::<bool>(),
+    ) {
+        let listed = ListedWinner::new(Pubkey::new_from_array(wallet), rank, score, team);
+        let bytes = bytemuck::bytes_of(&listed);
+        prop_assert_eq!(bytes.len(), ListedWinner::INIT_SPACE);
+        prop_assert_eq!(bytemuck::pod_read_unaligned::<ListedWinner>(bytes), listed);
+        prop_assert!(listed.is_well_formed());
+        prop_assert_eq!((listed.rank(), listed.is_team()), (rank, team));
+    }
+
+    #[test]
+    fn paid_ranks_track_every_rank_set(
+        ranks in prop::collection::vec(1..=MAX_RANK, 1..=MAX_RANK as usize),
+    ) {
+        let paid_ranks = ranks.iter().fold(0, |bits, &rank| bits | rank_bit(rank));
+        prop_assert!(ranks.iter().all(|&rank| paid_ranks & rank_bit(rank) != 0));
+        prop_assert_eq!(highest_paid_rank(paid_ranks), *ranks.iter().max().unwrap());
+        prop_assert_eq!(paid_ranks.count_ones() as usize, {
+            let mut distinct = ranks.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            distinct.len()
+        });
+    }
+}
+
+#[test]
+fn stake_multiplier_uses_highest_tier_reached() {
+    let tiers = [
+        StakeTier { min_stake: 1_000, multiplier_bps: 12_500 },
+        StakeTier { min_stake: 10_000, multiplier_bps: 15_000 },
+    ];
+    assert_eq!(stake_multiplier_bps(999, &tiers), BPS_DENOMINATOR);
+    assert_eq!(stake_multiplier_bps(1_000, &tiers), 12_500);
+    assert_eq!(stake_multiplier_bps(u64::MAX, &tiers), 15_000);
+    assert_eq!(stake_bonus(1_000, 15_000), 500);
+}
+
+#[test]
+fn tier_multiplier_uses_highest_tier_reached() {
+    let tiers = [
+        QualificationTier { threshold: 90, multiplier_bps: 15_000 },
+        QualificationTier { threshold: 95, multiplier_bps: 20_000 },
+    ];
+    assert_eq!(tier_multiplier_bps(89, &tiers), BPS_DENOMINATOR);
+    assert_eq!(tier_multiplier_bps(90, &tiers), 15_000);
+    assert_eq!(tier_multiplier_bps(MAX_SCORE, &tiers), 20_000);
+    assert_eq!(weighted_share(1_000, 20_000, 0), 0);
+}
+
+#[test]
+fn trimmed_mean_drops_one_highest_and_one_lowest() {
+    assert_eq!(trimmed_mean(&[100, 10]), None);
+    assert_eq!(trimmed_mean(&[100, 10, 80, 90]), Some(85));
+    assert_eq!(trimmed_mean(&[50, 50, 50, 0]), Some(50));
+    assert_eq!(trimmed_mean(&[u16::MAX; 7]), Some(u16::MAX));
+}
+
+#[test]
+fn previous_week_crosses_iso_years() {
+    assert_eq!(previous_week_id("2024-W45").as_deref(), Some("2024-W44"));
+    assert_eq!(previous_week_id("2024-W01").as_deref(), Some("2023-W52"));
+    // 2020 started on a Wednesday in a leap year, so it has a week 53
+    assert_eq!(previous_week_id("2021-W01").as_deref(), Some("2020-W53"));
+    assert_eq!(previous_week_id("2024-W53"), None);
+    assert_eq!(previous_week_id("2024-W00"), None);
+    assert_eq!(previous_week_id("2024-W+5"), None);
+    assert_eq!(previous_week_id("week-45"), None);
+}
+
+#[test]
+fn week_ids_order_by_year_then_week() {
+    // A free-form id like "week-45" sorts after every "YYYY-Www" id as a string
+    assert!(parse_week_id("2025-W01") > parse_week_id("2024-W52"));
+    assert!(parse_week_id("2024-W10") > parse_week_id("2024-W09"));
+    assert_eq!(parse_week_id("2024-W45"), Some((2024, 45)));
+    assert_eq!(parse_week_id("week-45"), None);
+}
+
+#[test]
+fn iso_week_ids_follow_the_calendar() {
+    assert_eq!(iso_week_id(0), "1970-W01");
+    assert_eq!(iso_week_id(1_730_678_400), "2024-W45"); // Mon 2024-11-04
+    assert_eq!(iso_week_id(1_609_372_800), "2020-W53"); // Thu 2020-12-31
+    assert_eq!(iso_week_id(1_609_718_340), "2020-W53"); // Sun 2021-01-03 23:59
+    assert_eq!(week_index_at(1_730_678_400, 1_730_678_399), None);
+}
+
+#[test]
+fn max_pool_does_not_overflow() {
+    assert_eq!(calculate_prize(1, u64::MAX), u64::MAX / 2);
+    assert_eq!(calculate_prize(MAX_RANK, u64::MAX), (u64::MAX as u128 * 20 / 700) as u64);
+}
+
+#[test]
+fn happy_hours_cover_start_but_not_end() {
+    let windows = [
+        HappyHour { start: 100, end: 200, multiplier_bps: 15_000 },
+        HappyHour { start: 200, end: 300, multiplier_bps: 20_000 },
+    ];
+    assert_eq!(happy_hour_multiplier_bps(99, &windows), BPS_DENOMINATOR);
+    assert_eq!(happy_hour_multiplier_bps(100, &windows), 15_000);
+    assert_eq!(happy_hour_multiplier_bps(200, &windows), 20_000);
+    assert_eq!(happy_hour_multiplier_bps(300, &windows), BPS_DENOMINATOR);
+    assert_eq!(boosted_score(60, 15_000), 90);
+    assert_eq!(boosted_score(60, 20_000), MAX_SCORE);
+}
+
+#[test]
+fn usd_prices_need_a_positive_price_and_a_tight_interval() {
+    assert_eq!(usd_to_tokens(50_000, 250_000_000, -8, 6), Some(200_000_000));
+    assert_eq!(usd_to_tokens(100, 0, -8, 6), None);
+    assert_eq!(usd_to_tokens(100, -1, -8, 6), None);
+    assert_eq!(usd_to_tokens(u64::MAX, 1, -12, 9), None);
+    assert!(price_within_confidence(10_000, 100, 100));
+    assert!(!price_within_confidence(10_000, 101, 100));
+    assert!(!price_within_confidence(0, 0, 100));
+}
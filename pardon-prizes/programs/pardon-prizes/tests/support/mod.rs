@@ -0,0 +1,2067 @@
+//! LiteSVM fixtures shared by the integration suites.
+//!
+//! The program is loaded from the SBF artifact produced by `anchor build`,
+//! so these suites run in-process without a local validator.
+
+#![allow(dead_code, clippy::result_large_err)]
+
+use anchor_lang::solana_program::alt_bn128::prelude::{alt_bn128_addition, alt_bn128_multiplication};
+use anchor_lang::{
+    AccountDeserialize, AnchorDeserialize, AnchorSerialize, InstructionData, ToAccountMetas,
+};
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token::spl_token;
+use litesvm::types::TransactionResult;
+use litesvm::LiteSVM;
+use pardon_prizes::civic::GATEWAY_PROGRAM_ID;
+use pardon_prizes::groth16::{input_point, ScoringProof};
+use pardon_prizes::math::MAX_RANK;
+use pardon_prizes::metaplex::{metadata_address, METADATA_PROGRAM_ID};
+use pardon_prizes::pyth::{PRICE_UPDATE_V2_DISCRIMINATOR, PYTH_RECEIVER_PROGRAM_ID};
+use pardon_prizes::wormhole::WORMHOLE_PROGRAM_ID;
+use pardon_prizes::{
+    ClaimRequest, EnclaveSigner, ListedWinner, PlayerProfile, ScoringVerifyingKey, SweptLeaf,
+    WinnerEntry, WEEKLY_POOL_SEED,
+};
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::program_option::COption;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+use solana_sdk::transaction::Transaction;
+use std::path::Path;
+
+pub const PROGRAM_SO: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../target/deploy/pardon_prizes.so"
+);
+
+pub const MINT_DECIMALS: u8 = 6;
+
+/// Most compute units a single transaction may request
+pub const MAX_TX_COMPUTE_UNITS: u32 = 1_400_000;
+
+/// Category used by fixtures that only need one pool per week
+pub const CATEGORY: &str = "top-score";
+
+pub struct TestContext {
+    pub svm: LiteSVM,
+    /// Upgrade authority, config admin and pool authority
+    pub authority: Keypair,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub creator_token_account: Pubkey,
+}
+
+pub struct CollectionNft {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+}
+
+pub struct FundedPool {
+    pub week_id: String,
+    pub category: String,
+    pub prize_pool: Pubkey,
+    pub token_account: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Set in jobs that run `anchor build` first, so a missing program fails
+/// the instruction suites instead of skipping them
+pub const REQUIRE_PROGRAM_VAR: &str = "PARDON_REQUIRE_PROGRAM";
+
+impl TestContext {
+    /// new(), or None when `anchor build` hasn't produced the program, so the
+    /// instruction suites skip under a plain `cargo test`
+    pub fn try_new() -> Option<Self> {
+        if !Path::new(PROGRAM_SO).exists() && std::env::var_os(REQUIRE_PROGRAM_VAR).is_none() {
+            eprintln!("skipped: {PROGRAM_SO} not found, run `anchor build`");
+            return None;
+        }
+        Some(Self::new())
+    }
+
+    /// Boots a VM with the program, a funded authority, a prize mint and
+    /// a config whose creator takes no royalty
+    pub fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        let program = std::fs::read(PROGRAM_SO)
+            .unwrap_or_else(|err| panic!("missing {PROGRAM_SO}, run `anchor build`: {err}"));
+        deploy_upgradeable(&mut svm, &program, &authority.pubkey());
+
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let mut ctx = Self {
+            svm,
+            authority,
+            mint,
+            creator,
+            creator_token_account: Pubkey::default(),
+        };
+        ctx.create_mint(mint);
+        ctx.creator_token_account = ctx.create_token_account(&creator, 0);
+
+        let ix = ctx.initialize_config_ix(0);
+        ctx.send(&[ix], &[]).expect("initialize_config");
+        let ix = ctx.initialize_global_stats_ix();
+        ctx.send(&[ix], &[]).expect("initialize_global_stats");
+        ctx
+    }
+
+    pub fn config_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"config"], &pardon_prizes::ID).0
+    }
+
+    pub fn initialize_config_ix(&self, creator_share_bps: u16) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::InitializeConfig {
+                config: Self::config_address(),
+                admin: self.authority.pubkey(),
+                program: pardon_prizes::ID,
+                program_data: program_data_address(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::InitializeConfig {
+                creator: self.creator,
+                creator_share_bps,
+            }
+            .data(),
+        }
+    }
+
+    pub fn initialize_global_stats_ix(&self) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::InitializeGlobalStats {
+                config: Self::config_address(),
+                global_stats: Self::global_stats_address(),
+                admin: self.authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::InitializeGlobalStats {}.data(),
+        }
+    }
+
+    pub fn update_config_ix(&self, creator_share_bps: u16) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::UpdateConfig {
+                config: Self::config_address(),
+                admin: self.authority.pubkey(),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::UpdateConfig {
+                creator: self.creator,
+                creator_share_bps,
+            }
+            .data(),
+        }
+    }
+
+    pub fn create_mint(&mut self, mint: Pubkey) {
+        self.create_mint_with_supply(mint, 0);
+    }
+
+    /// Same as create_mint, for tokens that must be burnable
+    pub fn create_mint_with_supply(&mut self, mint: Pubkey, supply: u64) {
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some(self.authority.pubkey()),
+            supply,
+            decimals: MINT_DECIMALS,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        self.set_token_program_account(mint, data);
+    }
+
+    /// Writes an initialized associated token account for `owner` holding `amount`
+    pub fn create_token_account(&mut self, owner: &Pubkey, amount: u64) -> Pubkey {
+        let mint = self.mint;
+        self.create_token_account_in(&mint, owner, amount)
+    }
+
+    /// Same as create_token_account, for a mint other than the fixture's
+    pub fn create_token_account_in(
+        &mut self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Pubkey {
+        let address = get_associated_token_address(owner, mint);
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint: *mint,
+            owner: *owner,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }
+        .pack_into_slice(&mut data);
+        self.set_token_program_account(address, data);
+        address
+    }
+
+    /// Overwrites the balance of an existing token account, e.g. to empty a vault
+    pub fn set_token_balance(&mut self, address: &Pubkey, amount: u64) {
+        let account = self.svm.get_account(address).expect("token account");
+        let mut state = spl_token::state::Account::unpack(&account.data).unwrap();
+        state.amount = amount;
+        let mut data = account.data;
+        state.pack_into_slice(&mut data);
+        self.set_token_program_account(*address, data);
+    }
+
+    /// Fixed address the fixture's Pyth price update lives at
+    pub fn price_update_address() -> Pubkey {
+        Pubkey::new_from_array([7; 32])
+    }
+
+    /// Writes a fully verified PriceUpdateV2 for `feed_id`, as the Pyth receiver would post it
+    pub fn set_price_update(
+        &mut self,
+        feed_id: [u8; 32],
+        price: i64,
+        conf: u64,
+        exponent: i32,
+        publish_time: i64,
+    ) {
+        let mut data = PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0; 32]);
+        data.push(1);
+        data.extend_from_slice(&feed_id);
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&conf.to_le_bytes());
+        data.extend_from_slice(&exponent.to_le_bytes());
+        data.extend_from_slice(&publish_time.to_le_bytes());
+        // prev_publish_time, ema_price, ema_conf, posted_slot
+        data.extend_from_slice(&[0; 32]);
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm
+            .set_account(
+                Self::price_update_address(),
+                Account {
+                    lamports,
+                    data,
+                    owner: PYTH_RECEIVER_PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    pub fn token_balance(&self, address: &Pubkey) -> u64 {
+        let account = self.svm.get_account(address).expect("token account");
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
+    /// Deserializes an Anchor account owned by the program
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self.svm.get_account(address).expect("account exists");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    pub fn prize_pool_address(week_id: &str, category: &str) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"prize_pool", week_id.as_bytes(), category.as_bytes()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn weekly_pool_address(week_index: u32, category: &str) -> Pubkey {
+        Pubkey::find_program_address(
+            &[WEEKLY_POOL_SEED, &week_index.to_le_bytes(), category.as_bytes()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn distribution_record_address(prize_pool: &Pubkey, nonce: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"distribution", prize_pool.as_ref(), &nonce.to_le_bytes()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn winner_list_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"winner_list", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn open_winner_list_ix(&self, pool: &FundedPool) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::OpenWinnerList {
+                prize_pool: pool.prize_pool,
+                winner_list: Self::winner_list_address(&pool.prize_pool),
+                authority: self.authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::OpenWinnerList {}.data(),
+        }
+    }
+
+    /// append_winners with `winners` laid out as packed ListedWinner records
+    pub fn append_winners_ix(&self, pool: &FundedPool, winners: &[ListedWinner]) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::AppendWinners {
+                prize_pool: pool.prize_pool,
+                winner_list: Self::winner_list_address(&pool.prize_pool),
+                authority: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::AppendWinners {
+                entries: bytemuck::cast_slice(winners).to_vec(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn retry_unpaid_ix(
+        pool: &FundedPool,
+        nonce: u64,
+        index: u8,
+        destination: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::RetryUnpaid {
+                prize_pool: pool.prize_pool,
+                distribution_record: Self::distribution_record_address(&pool.prize_pool, nonce),
+                week_stats: Self::week_stats_address(&pool.prize_pool),
+                mint_stats: Self::mint_stats_address(&pool.mint),
+                prize_pool_token_account: pool.token_account,
+                destination,
+                token_program: spl_token::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::RetryUnpaid { nonce, index }.data(),
+        }
+    }
+
+    pub fn player_profile_address(wallet: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"player", wallet.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn week_winner_address(week_id: &str, wallet: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"week_winner", week_id.as_bytes(), wallet.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn entry_address(prize_pool: &Pubkey, player: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"entry", prize_pool.as_ref(), player.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn submit_score_ix(&self, pool: &FundedPool, player: &Pubkey, raw_score: u16) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SubmitScore {
+                config: Self::config_address(),
+                prize_pool: pool.prize_pool,
+                entry: Self::entry_address(&pool.prize_pool, player),
+                authority: self.authority.pubkey(),
+                instructions: solana_sdk::sysvar::instructions::ID,
+                system_program: system_program::ID,
+                registration: self
+                    .svm
+                    .get_account(&Self::registration_address(&pool.prize_pool, player))
+                    .map(|_| Self::registration_address(&pool.prize_pool, player)),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SubmitScore {
+                player: *player,
+                raw_score,
+            }
+            .data(),
+        }
+    }
+
+    pub fn registration_address(prize_pool: &Pubkey, player: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"registration", prize_pool.as_ref(), player.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    /// `nft` is the player's collection NFT on gated pools, `fee_from` the
+    /// token account paying the entry fee on entry-fee pools
+    pub fn register_player_ix(
+        pool: &FundedPool,
+        player: &Pubkey,
+        nft: Option<&CollectionNft>,
+        fee_from: Option<Pubkey>,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::RegisterPlayer {
+                prize_pool: pool.prize_pool,
+                registration: Self::registration_address(&pool.prize_pool, player),
+                player: *player,
+                system_program: system_program::ID,
+                nft_token_account: nft.map(|nft| nft.token_account),
+                nft_metadata: nft.map(|nft| nft.metadata),
+                nft_mint: nft.map(|nft| nft.mint),
+                token_program: (nft.is_some() || fee_from.is_some()).then_some(spl_token::ID),
+                player_token_account: fee_from,
+                prize_pool_token_account: fee_from.map(|_| pool.token_account),
+                gateway_token: None,
+                player_profile: None,
+                bond_vault: None,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::RegisterPlayer {}.data(),
+        }
+    }
+
+    /// Mints an NFT to `owner` whose metadata names `collection`
+    pub fn create_collection_nft(
+        &mut self,
+        owner: &Pubkey,
+        collection: &Pubkey,
+        verified: bool,
+    ) -> CollectionNft {
+        let mint = Pubkey::new_unique();
+        self.create_mint_with_supply(mint, 1);
+        let token_account = self.create_token_account_in(&mint, owner, 1);
+
+        let mut data = vec![4]; // Key::MetadataV1
+        data.extend_from_slice(self.authority.pubkey().as_ref());
+        data.extend_from_slice(mint.as_ref());
+        for field in ["Pardon Pass", "PASS", "https://example.com/pass.json"] {
+            data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&0u16.to_le_bytes()); // seller_fee_basis_points
+        data.extend_from_slice(&[0, 0, 1, 0, 0]); // no creators, sale, mutable, no nonce/standard
+        data.push(1);
+        data.push(verified as u8);
+        data.extend_from_slice(collection.as_ref());
+
+        let metadata = metadata_address(&mint);
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm
+            .set_account(
+                metadata,
+                Account {
+                    lamports,
+                    data,
+                    owner: METADATA_PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+        CollectionNft { mint, token_account, metadata }
+    }
+
+    pub fn vote_record_address(entry: &Pubkey, voter: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vote", entry.as_ref(), voter.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn vote_for_entry_ix(
+        &self,
+        pool: &FundedPool,
+        player: &Pubkey,
+        voter: &Pubkey,
+        voter_token_account: Pubkey,
+        weight: u64,
+    ) -> Instruction {
+        let entry = Self::entry_address(&pool.prize_pool, player);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::VoteForEntry {
+                config: Self::config_address(),
+                prize_pool: pool.prize_pool,
+                entry,
+                vote_record: Self::vote_record_address(&entry, voter),
+                vote_escrow: Self::vote_escrow_address(&pool.prize_pool, voter),
+                vote_vault: Self::vote_vault_address(&pool.prize_pool),
+                mint: self.mint,
+                voter_token_account,
+                voter: *voter,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::VoteForEntry { weight }.data(),
+        }
+    }
+
+    pub fn vote_escrow_address(prize_pool: &Pubkey, voter: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vote_escrow", prize_pool.as_ref(), voter.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn bond_vault_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"bond_vault", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn refund_entry_bond_ix(
+        pool: &FundedPool,
+        player: &Pubkey,
+        player_token_account: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::RefundEntryBond {
+                prize_pool: pool.prize_pool,
+                registration: Self::registration_address(&pool.prize_pool, player),
+                bond_vault: Self::bond_vault_address(&pool.prize_pool),
+                player_token_account,
+                player: *player,
+                token_program: spl_token::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::RefundEntryBond {}.data(),
+        }
+    }
+
+    /// Makes a guardian and has it announce an emergency withdrawal, cancelling the week
+    pub fn cancel_week(&mut self, pool: &FundedPool) {
+        let guardian = Keypair::new();
+        let ix = Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetGuardian {
+                config: Self::config_address(),
+                admin: self.authority.pubkey(),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetGuardian { guardian: guardian.pubkey() }.data(),
+        };
+        self.send(&[ix], &[]).expect("set_guardian");
+        let destination = self.create_token_account(&Pubkey::new_unique(), 0);
+        let ix = Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::AnnounceEmergencyWithdraw {
+                config: Self::config_address(),
+                prize_pool: pool.prize_pool,
+                prize_pool_token_account: pool.token_account,
+                destination,
+                guardian: guardian.pubkey(),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::AnnounceEmergencyWithdraw {}.data(),
+        };
+        self.send(&[ix], &[&guardian]).expect("announce_emergency_withdraw");
+    }
+
+    pub fn vote_vault_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vote_vault", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn unlock_votes_ix(
+        pool: &FundedPool,
+        voter: &Pubkey,
+        voter_token_account: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::UnlockVotes {
+                prize_pool: pool.prize_pool,
+                vote_escrow: Self::vote_escrow_address(&pool.prize_pool, voter),
+                vote_vault: Self::vote_vault_address(&pool.prize_pool),
+                voter_token_account,
+                voter: *voter,
+                token_program: spl_token::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::UnlockVotes {}.data(),
+        }
+    }
+
+    pub fn global_stats_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"global_stats"], &pardon_prizes::ID).0
+    }
+
+    pub fn mint_stats_address(mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"mint_stats", mint.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn week_stats_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"week_stats", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn judge_scores_address(prize_pool: &Pubkey, player: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"judge_scores", prize_pool.as_ref(), player.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn set_judges_ix(
+        &self,
+        pool: &FundedPool,
+        judges: Vec<Pubkey>,
+        quorum: u8,
+        judging_ends_at: i64,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetJudges {
+                prize_pool: pool.prize_pool,
+                authority: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetJudges {
+                judges,
+                quorum,
+                judging_ends_at,
+            }
+            .data(),
+        }
+    }
+
+    pub fn submit_judge_score_ix(
+        pool: &FundedPool,
+        judge: &Pubkey,
+        player: &Pubkey,
+        raw_score: u16,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SubmitJudgeScore {
+                prize_pool: pool.prize_pool,
+                judge_scores: Self::judge_scores_address(&pool.prize_pool, player),
+                judge: *judge,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SubmitJudgeScore {
+                player: *player,
+                raw_score,
+            }
+            .data(),
+        }
+    }
+
+    pub fn aggregate_scores_ix(&self, pool: &FundedPool, player: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::AggregateScores {
+                prize_pool: pool.prize_pool,
+                judge_scores: Self::judge_scores_address(&pool.prize_pool, player),
+                entry: Self::entry_address(&pool.prize_pool, player),
+                payer: self.authority.pubkey(),
+                system_program: system_program::ID,
+                registration: self
+                    .svm
+                    .get_account(&Self::registration_address(&pool.prize_pool, player))
+                    .map(|_| Self::registration_address(&pool.prize_pool, player)),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::AggregateScores {}.data(),
+        }
+    }
+
+    pub fn finalize_entry_ix(&self, pool: &FundedPool, player: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::FinalizeEntry {
+                config: Self::config_address(),
+                prize_pool: pool.prize_pool,
+                entry: Self::entry_address(&pool.prize_pool, player),
+                week_stats: Self::week_stats_address(&pool.prize_pool),
+                authority: self.authority.pubkey(),
+                system_program: system_program::ID,
+                jackpot: self
+                    .svm
+                    .get_account(&Self::jackpot_address())
+                    .map(|_| Self::jackpot_address()),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::FinalizeEntry {}.data(),
+        }
+    }
+
+    pub fn finalize_leaderboard_ix(
+        &self,
+        pool: &FundedPool,
+        root: [u8; 32],
+        entry_count: u32,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::FinalizeLeaderboard {
+                prize_pool: pool.prize_pool,
+                authority: self.authority.pubkey(),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::FinalizeLeaderboard { root, entry_count }.data(),
+        }
+    }
+
+    /// attest_results with the core bridge PDAs; the bridge itself isn't loaded
+    pub fn attest_results_ix(&self, pool: &FundedPool) -> Instruction {
+        let wormhole =
+            |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &WORMHOLE_PROGRAM_ID).0;
+        let emitter = Pubkey::find_program_address(&[b"emitter"], &pardon_prizes::ID).0;
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::AttestResults {
+                prize_pool: pool.prize_pool,
+                wormhole_bridge: wormhole(&[b"Bridge"]),
+                wormhole_message: Pubkey::find_program_address(
+                    &[b"attestation", pool.prize_pool.as_ref()],
+                    &pardon_prizes::ID,
+                )
+                .0,
+                wormhole_emitter: emitter,
+                wormhole_sequence: wormhole(&[b"Sequence", emitter.as_ref()]),
+                wormhole_fee_collector: wormhole(&[b"fee_collector"]),
+                payer: self.authority.pubkey(),
+                wormhole_program: WORMHOLE_PROGRAM_ID,
+                clock: solana_sdk::sysvar::clock::ID,
+                rent: solana_sdk::sysvar::rent::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::AttestResults {}.data(),
+        }
+    }
+
+    pub fn post_scoring_transcript_ix(
+        &self,
+        pool: &FundedPool,
+        transcript_hash: [u8; 32],
+        uri: &str,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::PostScoringTranscript {
+                prize_pool: pool.prize_pool,
+                authority: self.authority.pubkey(),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::PostScoringTranscript {
+                transcript_hash,
+                uri: uri.to_string(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn scoring_verifier_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"scoring_verifier"], &pardon_prizes::ID).0
+    }
+
+    pub fn set_scoring_verifier_ix(&self, key: ScoringVerifyingKey) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetScoringVerifier {
+                config: Self::config_address(),
+                scoring_verifier: Self::scoring_verifier_address(),
+                admin: self.authority.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetScoringVerifier { key }.data(),
+        }
+    }
+
+    pub fn set_enclave_signer_ix(&self, enclave_signer: Option<EnclaveSigner>) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetEnclaveSigner {
+                config: Self::config_address(),
+                admin: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetEnclaveSigner { enclave_signer }.data(),
+        }
+    }
+
+    pub fn verify_scoring_proof_ix(pool: &FundedPool, proof: ScoringProof) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::VerifyScoringProof {
+                scoring_verifier: Self::scoring_verifier_address(),
+                prize_pool: pool.prize_pool,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::VerifyScoringProof { proof }.data(),
+        }
+    }
+
+    pub fn program_state_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"program_state"], &pardon_prizes::ID).0
+    }
+
+    /// Creates the program state on first use, then turns `feature` on
+    pub fn enable_feature(&mut self, feature: u64) {
+        let program_state = Self::program_state_address();
+        if self.svm.get_account(&program_state).is_none() {
+            let ix = Instruction {
+                program_id: pardon_prizes::ID,
+                accounts: pardon_prizes::accounts::InitializeProgramState {
+                    config: Self::config_address(),
+                    program_state,
+                    admin: self.authority.pubkey(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: pardon_prizes::instruction::InitializeProgramState {}.data(),
+            };
+            self.send(&[ix], &[]).expect("initialize_program_state");
+        }
+
+        let ix = Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetFeatureFlag {
+                config: Self::config_address(),
+                program_state,
+                admin: self.authority.pubkey(),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetFeatureFlag { feature, enabled: true }.data(),
+        };
+        self.send(&[ix], &[]).expect("set_feature_flag");
+    }
+
+    pub fn claim_record_address(prize_pool: &Pubkey, wallet: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"claim", prize_pool.as_ref(), wallet.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    /// Passes the winner's PlayerProfile, and its split recipient's token account, if it exists
+    pub fn claim_prize_ix(
+        &self,
+        pool: &FundedPool,
+        winner: &Pubkey,
+        winner_token_account: Pubkey,
+        rank: u32,
+        score: u8,
+        proof: Vec<[u8; 32]>,
+    ) -> Instruction {
+        let profile = Self::player_profile_address(winner);
+        let profile_state = self
+            .svm
+            .get_account(&profile)
+            .map(|_| self.account::<PlayerProfile>(&profile));
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::ClaimPrize {
+                config: Self::config_address(),
+                program_state: Self::program_state_address(),
+                prize_pool: pool.prize_pool,
+                claim_record: Self::claim_record_address(&pool.prize_pool, winner),
+                week_stats: Self::week_stats_address(&pool.prize_pool),
+                global_stats: Self::global_stats_address(),
+                mint_stats: Self::mint_stats_address(&pool.mint),
+                prize_pool_token_account: pool.token_account,
+                creator_token_account: self.creator_token_account,
+                winner_token_account,
+                winner: *winner,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                staker_rewards_vault: None,
+                jackpot_vault: self
+                    .svm
+                    .get_account(&Self::jackpot_vault_address())
+                    .map(|_| Self::jackpot_vault_address()),
+                player_profile: profile_state.as_ref().map(|_| profile),
+                streak_vault: None,
+                gateway_token: None,
+                insurance_vault: self
+                    .svm
+                    .get_account(&Self::insurance_vault_address())
+                    .map(|_| Self::insurance_vault_address()),
+                balance_snapshot: self
+                    .svm
+                    .get_account(&Self::snapshot_address(&pool.prize_pool, winner))
+                    .map(|_| Self::snapshot_address(&pool.prize_pool, winner)),
+                mint: Some(pool.mint),
+                associated_token_program: Some(associated_token::ID),
+                split_token_account: profile_state
+                    .and_then(|state| state.split_recipient)
+                    .map(|recipient| get_associated_token_address(&recipient, &pool.mint)),
+                team: Self::team_address(winner),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::ClaimPrize { rank, score, proof }.data(),
+        }
+    }
+
+    /// Claims `claims[i]` from `pools[i]`, all paying into `winner_token_account`
+    pub fn claim_many_ix(
+        &self,
+        pools: &[&FundedPool],
+        winner: &Pubkey,
+        winner_token_account: Pubkey,
+        claims: Vec<ClaimRequest>,
+    ) -> Instruction {
+        let mint = pools[0].mint;
+        let mut accounts = pardon_prizes::accounts::ClaimMany {
+            config: Self::config_address(),
+            program_state: Self::program_state_address(),
+            winner_token_account,
+            creator_token_account: self.creator_token_account,
+            winner: *winner,
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            global_stats: Self::global_stats_address(),
+            mint_stats: Self::mint_stats_address(&mint),
+            staker_rewards_vault: None,
+            jackpot_vault: self
+                .svm
+                .get_account(&Self::jackpot_vault_address())
+                .map(|_| Self::jackpot_vault_address()),
+            player_profile: None,
+            streak_vault: None,
+            gateway_token: None,
+            insurance_vault: self
+                .svm
+                .get_account(&Self::insurance_vault_address())
+                .map(|_| Self::insurance_vault_address()),
+            mint,
+            associated_token_program: Some(associated_token::ID),
+            split_token_account: None,
+            team: Self::team_address(winner),
+            event_authority: Self::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None);
+        for pool in pools {
+            accounts.extend([
+                AccountMeta::new(pool.prize_pool, false),
+                AccountMeta::new(pool.token_account, false),
+                AccountMeta::new(Self::claim_record_address(&pool.prize_pool, winner), false),
+                AccountMeta::new(Self::week_stats_address(&pool.prize_pool), false),
+                AccountMeta::new_readonly(Self::snapshot_address(&pool.prize_pool, winner), false),
+            ]);
+        }
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts,
+            data: pardon_prizes::instruction::ClaimMany { claims }.data(),
+        }
+    }
+
+    pub fn fund_rent_rebates_ix(&self, pool: &FundedPool, cap: u64, amount: u64) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::FundRentRebates {
+                prize_pool: pool.prize_pool,
+                authority: self.authority.pubkey(),
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::FundRentRebates { cap, amount }.data(),
+        }
+    }
+
+    pub fn week_archive_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"week_archive", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    /// Archives the claim receipts of `winners`, each followed by the winner's wallet
+    pub fn archive_week_ix(&self, pool: &FundedPool, winners: &[Pubkey]) -> Instruction {
+        let mut accounts = pardon_prizes::accounts::ArchiveWeek {
+            prize_pool: pool.prize_pool,
+            week_archive: Self::week_archive_address(&pool.prize_pool),
+            authority: self.authority.pubkey(),
+            system_program: system_program::ID,
+            event_authority: Self::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None);
+        for winner in winners {
+            let receipt = Self::claim_record_address(&pool.prize_pool, winner);
+            accounts.push(AccountMeta::new(receipt, false));
+            accounts.push(AccountMeta::new(*winner, false));
+        }
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts,
+            data: pardon_prizes::instruction::ArchiveWeek {}.data(),
+        }
+    }
+
+    /// Sweeps with each leaf's claim receipt followed by its wallet
+    pub fn sweep_expired_ix(
+        &self,
+        pool: &FundedPool,
+        treasury_token_account: Pubkey,
+        leaves: Vec<SweptLeaf>,
+    ) -> Instruction {
+        let mut accounts = pardon_prizes::accounts::SweepExpired {
+            config: Self::config_address(),
+            prize_pool: pool.prize_pool,
+            prize_pool_token_account: pool.token_account,
+            treasury_token_account,
+            creator_token_account: self.creator_token_account,
+            week_archive: Self::week_archive_address(&pool.prize_pool),
+            payer: self.authority.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+            staker_rewards_vault: None,
+            jackpot_vault: self
+                .svm
+                .get_account(&Self::jackpot_vault_address())
+                .map(|_| Self::jackpot_vault_address()),
+            insurance_vault: self
+                .svm
+                .get_account(&Self::insurance_vault_address())
+                .map(|_| Self::insurance_vault_address()),
+            event_authority: Self::event_authority_address(),
+            program: pardon_prizes::ID,
+        }
+        .to_account_metas(None);
+        for leaf in &leaves {
+            let receipt = Self::claim_record_address(&pool.prize_pool, &leaf.wallet);
+            accounts.push(AccountMeta::new(receipt, false));
+            accounts.push(AccountMeta::new(leaf.wallet, false));
+        }
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts,
+            data: pardon_prizes::instruction::SweepExpired { leaves }.data(),
+        }
+    }
+
+    pub fn bet_market_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"bet_market", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn bet_vault_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"bet_vault", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn bet_outcome_address(bet_market: &Pubkey, player: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"bet_outcome", bet_market.as_ref(), player.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn bet_address(bet_market: &Pubkey, bettor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"bet", bet_market.as_ref(), bettor.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn open_bet_market_ix(&self, pool: &FundedPool, closes_at: i64, rake_bps: u16) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::OpenBetMarket {
+                prize_pool: pool.prize_pool,
+                bet_market: Self::bet_market_address(&pool.prize_pool),
+                bet_vault: Self::bet_vault_address(&pool.prize_pool),
+                mint: self.mint,
+                authority: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::OpenBetMarket { closes_at, rake_bps }.data(),
+        }
+    }
+
+    pub fn place_bet_ix(
+        pool: &FundedPool,
+        bettor: &Pubkey,
+        bettor_token_account: Pubkey,
+        player: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let bet_market = Self::bet_market_address(&pool.prize_pool);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::PlaceBet {
+                bet_market,
+                prize_pool: pool.prize_pool,
+                bet_vault: Self::bet_vault_address(&pool.prize_pool),
+                registration: Self::registration_address(&pool.prize_pool, &player),
+                bet_outcome: Self::bet_outcome_address(&bet_market, &player),
+                bet: Self::bet_address(&bet_market, bettor),
+                bettor_token_account,
+                bettor: *bettor,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::PlaceBet { player, amount }.data(),
+        }
+    }
+
+    /// Refunds `bettor`'s bet on `player`
+    pub fn refund_bet_ix(
+        pool: &FundedPool,
+        bettor: &Pubkey,
+        bettor_token_account: Pubkey,
+        player: &Pubkey,
+    ) -> Instruction {
+        let bet_market = Self::bet_market_address(&pool.prize_pool);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::RefundBet {
+                bet_market,
+                prize_pool: pool.prize_pool,
+                bet_vault: Self::bet_vault_address(&pool.prize_pool),
+                bet: Self::bet_address(&bet_market, bettor),
+                bettor_token_account,
+                bettor: *bettor,
+                token_program: spl_token::ID,
+                bet_outcome: Self::bet_outcome_address(&bet_market, player),
+                registration: Self::registration_address(&pool.prize_pool, player),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::RefundBet {}.data(),
+        }
+    }
+
+    pub fn claim_bet_winnings_ix(
+        pool: &FundedPool,
+        bettor: &Pubkey,
+        bettor_token_account: Pubkey,
+    ) -> Instruction {
+        let bet_market = Self::bet_market_address(&pool.prize_pool);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::ClaimBetWinnings {
+                bet_market,
+                bet_vault: Self::bet_vault_address(&pool.prize_pool),
+                bet: Self::bet_address(&bet_market, bettor),
+                bettor_token_account,
+                bettor: *bettor,
+                token_program: spl_token::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::ClaimBetWinnings {}.data(),
+        }
+    }
+
+    pub fn snapshot_address(prize_pool: &Pubkey, wallet: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"snapshot", prize_pool.as_ref(), wallet.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn set_holder_requirement_ix(
+        &self,
+        pool: &FundedPool,
+        min_holding: u64,
+        snapshot_start_slot: u64,
+        snapshot_end_slot: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetHolderRequirement {
+                prize_pool: pool.prize_pool,
+                authority: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetHolderRequirement {
+                min_holding,
+                snapshot_start_slot,
+                snapshot_end_slot,
+            }
+            .data(),
+        }
+    }
+
+    pub fn record_balance_snapshot_ix(
+        pool: &FundedPool,
+        wallet: &Pubkey,
+        wallet_token_account: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::RecordBalanceSnapshot {
+                prize_pool: pool.prize_pool,
+                snapshot: Self::snapshot_address(&pool.prize_pool, wallet),
+                wallet_token_account,
+                wallet: *wallet,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::RecordBalanceSnapshot {}.data(),
+        }
+    }
+
+    pub fn stake_address(wallet: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"stake", wallet.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn stake_vault_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"stake_vault"], &pardon_prizes::ID).0
+    }
+
+    /// Stakes `amount` of the fixture mint, which doubles as the stake mint
+    pub fn stake_ix(
+        &self,
+        wallet: &Pubkey,
+        wallet_token_account: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::Stake {
+                config: Self::config_address(),
+                stake_account: Self::stake_address(wallet),
+                stake_vault: Self::stake_vault_address(),
+                stake_mint: self.mint,
+                wallet_token_account,
+                wallet: *wallet,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::Stake { amount }.data(),
+        }
+    }
+
+    pub fn unstake_ix(wallet: &Pubkey, wallet_token_account: Pubkey, amount: u64) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::Unstake {
+                config: Self::config_address(),
+                stake_account: Self::stake_address(wallet),
+                stake_vault: Self::stake_vault_address(),
+                wallet_token_account,
+                wallet: *wallet,
+                token_program: spl_token::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::Unstake { amount }.data(),
+        }
+    }
+
+    pub fn jackpot_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"jackpot"], &pardon_prizes::ID).0
+    }
+
+    pub fn jackpot_vault_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"jackpot_vault"], &pardon_prizes::ID).0
+    }
+
+    /// Feeds `jackpot_bps` of pools in the fixture mint to the jackpot
+    pub fn configure_jackpot_ix(&self, jackpot_bps: u16, trigger_score: u8) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::ConfigureJackpot {
+                config: Self::config_address(),
+                jackpot: Self::jackpot_address(),
+                jackpot_vault: Self::jackpot_vault_address(),
+                jackpot_mint: self.mint,
+                admin: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::ConfigureJackpot { jackpot_bps, trigger_score }
+                .data(),
+        }
+    }
+
+    pub fn insurance_vault_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"insurance_vault"], &pardon_prizes::ID).0
+    }
+
+    /// Sets aside `insurance_bps` of pools in the fixture mint for insurance claims
+    pub fn configure_insurance_ix(&self, insurance_bps: u16) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::ConfigureInsurance {
+                config: Self::config_address(),
+                insurance_vault: Self::insurance_vault_address(),
+                insurance_mint: self.mint,
+                admin: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::ConfigureInsurance { insurance_bps }.data(),
+        }
+    }
+
+    pub fn streak_vault_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"streak_vault"], &pardon_prizes::ID).0
+    }
+
+    /// Pays streak bonuses on pools in the fixture mint
+    pub fn configure_streak_bonus_ix(
+        &self,
+        streak_bonus_bps: u16,
+        min_streak_weeks: u16,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::ConfigureStreakBonus {
+                config: Self::config_address(),
+                streak_vault: Self::streak_vault_address(),
+                streak_mint: self.mint,
+                admin: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::ConfigureStreakBonus {
+                streak_bonus_bps,
+                min_streak_weeks,
+            }
+            .data(),
+        }
+    }
+
+    pub fn staker_rewards_vault_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"staker_rewards"], &pardon_prizes::ID).0
+    }
+
+    pub fn set_staker_share_ix(&self, staker_share_bps: u16) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetStakerShare {
+                config: Self::config_address(),
+                staker_rewards_vault: Self::staker_rewards_vault_address(),
+                stake_mint: self.mint,
+                admin: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetStakerShare { staker_share_bps }.data(),
+        }
+    }
+
+    pub fn claim_staking_rewards_ix(wallet: &Pubkey, wallet_token_account: Pubkey) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::ClaimStakingRewards {
+                config: Self::config_address(),
+                stake_account: Self::stake_address(wallet),
+                staker_rewards_vault: Self::staker_rewards_vault_address(),
+                wallet_token_account,
+                wallet: *wallet,
+                token_program: spl_token::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::ClaimStakingRewards {}.data(),
+        }
+    }
+
+    /// Native ed25519 sig-verify of `signer` over `message`, all offsets in its own data
+    pub fn ed25519_ix(signer: &Keypair, message: &[u8]) -> Instruction {
+        const DATA_START: u16 = 2 + 14;
+        let pubkey_offset = DATA_START;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+        let mut data = vec![1, 0];
+        for offset in [
+            signature_offset,
+            u16::MAX,
+            pubkey_offset,
+            u16::MAX,
+            message_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&offset.to_le_bytes());
+        }
+        data.extend_from_slice(signer.pubkey().as_ref());
+        data.extend_from_slice(signer.sign_message(message).as_ref());
+        data.extend_from_slice(message);
+        Instruction {
+            program_id: solana_sdk::ed25519_program::ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    /// The winner's signed authorization followed by the relayer's authorize_payout_wallet
+    pub fn authorize_payout_wallet_ixs(
+        &self,
+        winner: &Keypair,
+        payout_wallet: Pubkey,
+        payout_nonce: u64,
+    ) -> [Instruction; 2] {
+        let wallet = winner.pubkey();
+        let message =
+            pardon_prizes::payout_authorization_message(&wallet, &payout_wallet, payout_nonce);
+        let authorize = Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::AuthorizePayoutWallet {
+                player_profile: Self::player_profile_address(&wallet),
+                relayer: self.authority.pubkey(),
+                instructions: solana_sdk::sysvar::instructions::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::AuthorizePayoutWallet { wallet, payout_wallet }.data(),
+        };
+        [Self::ed25519_ix(winner, &message), authorize]
+    }
+
+    /// The registrar's signed attestation followed by the relayer's attest_wallet_age
+    pub fn attest_wallet_age_ixs(
+        &self,
+        registrar: &Keypair,
+        wallet: Pubkey,
+        first_seen_at: i64,
+    ) -> [Instruction; 2] {
+        let message = pardon_prizes::wallet_age_message(&wallet, first_seen_at);
+        let attest = Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::AttestWalletAge {
+                player_profile: Self::player_profile_address(&wallet),
+                relayer: self.authority.pubkey(),
+                instructions: solana_sdk::sysvar::instructions::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::AttestWalletAge {
+                wallet,
+                registrar: registrar.pubkey(),
+                first_seen_at,
+            }
+            .data(),
+        };
+        [Self::ed25519_ix(registrar, &message), attest]
+    }
+
+    pub fn create_player_profile_ix(player: &Pubkey) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::CreatePlayerProfile {
+                player_profile: Self::player_profile_address(player),
+                player: *player,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::CreatePlayerProfile {}.data(),
+        }
+    }
+
+    pub fn team_address(captain: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"team", captain.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn event_authority_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"__event_authority"], &pardon_prizes::ID).0
+    }
+
+    pub fn initialize_prize_pool_ix(
+        &self,
+        week_id: &str,
+        category: &str,
+        max_winners: u8,
+        rank_shares_bps: &[u16],
+    ) -> Instruction {
+        let prize_pool = Self::prize_pool_address(week_id, category);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::InitializePrizePool {
+                config: Self::config_address(),
+                prize_pool,
+                mint: self.mint,
+                vault: Self::vault_address(&prize_pool),
+                global_stats: Self::global_stats_address(),
+                mint_stats: Self::mint_stats_address(&self.mint),
+                authority: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::InitializePrizePool {
+                week_id: week_id.to_string(),
+                category: category.to_string(),
+                max_winners,
+                rank_shares_bps: rank_shares_bps.to_vec(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn set_week_schedule_ix(&self, week_epoch_start: i64) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetWeekSchedule {
+                config: Self::config_address(),
+                admin: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetWeekSchedule { week_epoch_start }.data(),
+        }
+    }
+
+    pub fn initialize_weekly_pool_ix(
+        &self,
+        week_index: u32,
+        category: &str,
+        max_winners: u8,
+        rank_shares_bps: &[u16],
+    ) -> Instruction {
+        let prize_pool = Self::weekly_pool_address(week_index, category);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::InitializeWeeklyPool {
+                config: Self::config_address(),
+                prize_pool,
+                mint: self.mint,
+                vault: Self::vault_address(&prize_pool),
+                global_stats: Self::global_stats_address(),
+                mint_stats: Self::mint_stats_address(&self.mint),
+                authority: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::InitializeWeeklyPool {
+                week_index,
+                category: category.to_string(),
+                max_winners,
+                rank_shares_bps: rank_shares_bps.to_vec(),
+            }
+            .data(),
+        }
+    }
+
+    pub fn set_pool_defaults_ix(&self, max_winners: u8, rank_shares_bps: &[u16]) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::SetPoolDefaults {
+                config: Self::config_address(),
+                admin: self.authority.pubkey(),
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::SetPoolDefaults {
+                max_winners,
+                rank_shares_bps: rank_shares_bps.to_vec(),
+            }
+            .data(),
+        }
+    }
+
+    /// Rolls `previous` into numbered week `week_index`, optionally carrying
+    /// over the surplus of the archived `source`
+    pub fn rollover_week_ix(
+        &self,
+        previous: &FundedPool,
+        week_index: u32,
+        source: Option<&FundedPool>,
+    ) -> Instruction {
+        let prize_pool = Self::weekly_pool_address(week_index, &previous.category);
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::RolloverWeek {
+                config: Self::config_address(),
+                previous_pool: previous.prize_pool,
+                prize_pool,
+                mint: self.mint,
+                vault: Self::vault_address(&prize_pool),
+                global_stats: Self::global_stats_address(),
+                mint_stats: Self::mint_stats_address(&self.mint),
+                payer: self.authority.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rollover_source: source.map(|pool| pool.prize_pool),
+                rollover_source_vault: source.map(|pool| pool.token_account),
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::RolloverWeek {}.data(),
+        }
+    }
+
+    /// Fixture handle for the numbered pool of `week_index`, which must exist
+    pub fn numbered_pool(&self, week_index: u32, category: &str) -> FundedPool {
+        let prize_pool = Self::weekly_pool_address(week_index, category);
+        let state: pardon_prizes::PrizePool = self.account(&prize_pool);
+        FundedPool {
+            week_id: state.week_id,
+            category: category.to_string(),
+            prize_pool,
+            token_account: Self::vault_address(&prize_pool),
+            mint: state.mint,
+        }
+    }
+
+    pub fn vault_address(prize_pool: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault", prize_pool.as_ref()], &pardon_prizes::ID).0
+    }
+
+    pub fn funder_record_address(prize_pool: &Pubkey, funder: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"funder", prize_pool.as_ref(), funder.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    pub fn fund_pool_ix(
+        &self,
+        pool: &FundedPool,
+        funder: &Pubkey,
+        funder_token_account: Pubkey,
+        amount: u64,
+        memo: &str,
+    ) -> Instruction {
+        Self::fund_vault_ix(pool, pool.token_account, funder, funder_token_account, amount, memo)
+    }
+
+    pub fn mint_vault_address(prize_pool: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"mint_vault", prize_pool.as_ref(), mint.as_ref()],
+            &pardon_prizes::ID,
+        )
+        .0
+    }
+
+    /// fund_pool into `vault`, the pool's own or one of its mint sub-vaults
+    pub fn fund_vault_ix(
+        pool: &FundedPool,
+        vault: Pubkey,
+        funder: &Pubkey,
+        funder_token_account: Pubkey,
+        amount: u64,
+        memo: &str,
+    ) -> Instruction {
+        Instruction {
+            program_id: pardon_prizes::ID,
+            accounts: pardon_prizes::accounts::FundPool {
+                config: Self::config_address(),
+                prize_pool: pool.prize_pool,
+                vault,
+                funder_record: Self::funder_record_address(&pool.prize_pool, funder),
+                week_stats: Self::week_stats_address(&pool.prize_pool),
+                funder_token_account,
+                funder: *funder,
+                instructions: solana_sdk::sysvar::instructions::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                event_authority: Self::event_authority_address(),
+                program: pardon_prizes::ID,
+            }
+            .to_account_metas(None),
+            data: pardon_prizes::instruction::FundPool {
+                amount,
+                memo: memo.to_string(),
+            }
+            .data(),
+        }
+    }
+
+    /// Initializes the pool PDA and its vault, then deposits `amount` via fund_pool
+    pub fn funded_pool(&mut self, week_id: &str, amount: u64) -> FundedPool {
+        self.funded_pool_with(week_id, MAX_RANK, amount)
+    }
+
+    pub fn funded_pool_with(&mut self, week_id: &str, max_winners: u8, amount: u64) -> FundedPool {
+        self.funded_category_pool(week_id, CATEGORY, max_winners, &[], amount)
+    }
+
+    pub fn funded_category_pool(
+        &mut self,
+        week_id: &str,
+        category: &str,
+        max_winners: u8,
+        rank_shares_bps: &[u16],
+        amount: u64,
+    ) -> FundedPool {
+        let ix = self.initialize_prize_pool_ix(week_id, category, max_winners, rank_shares_bps);
+        self.send(&[ix], &[]).expect("initialize_prize_pool");
+
+        let prize_pool = Self::prize_pool_address(week_id, category);
+        let pool = FundedPool {
+            week_id: week_id.to_string(),
+            category: category.to_string(),
+            prize_pool,
+            token_account: Self::vault_address(&prize_pool),
+            mint: self.mint,
+        };
+
+        if amount > 0 {
+            let funder = self.authority.pubkey();
+            let funder_token_account = self.create_token_account(&funder, amount);
+            let ix = self.fund_pool_ix(&pool, &funder, funder_token_account, amount, "fixture");
+            self.send(&[ix], &[]).expect("fund_pool");
+        }
+        pool
+    }
+
+    /// Signs with the authority plus `extra_signers` and submits the transaction
+    pub fn send(&mut self, ixs: &[Instruction], extra_signers: &[&Keypair]) -> TransactionResult {
+        let mut signers = vec![&self.authority];
+        signers.extend_from_slice(extra_signers);
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.authority.pubkey()),
+            &signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(tx);
+        self.svm.expire_blockhash();
+        result
+    }
+
+    /// Writes a Civic gateway token for `wallet` in `network`; `state` 0 is active
+    pub fn set_gateway_token(
+        &mut self,
+        wallet: &Pubkey,
+        network: &Pubkey,
+        state: u8,
+        expire_time: Option<i64>,
+    ) -> Pubkey {
+        let mut data = vec![0, 0];
+        data.extend_from_slice(wallet.as_ref());
+        data.push(0);
+        data.extend_from_slice(network.as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.push(state);
+        match expire_time {
+            Some(expire_time) => {
+                data.push(1);
+                data.extend_from_slice(&expire_time.to_le_bytes());
+            }
+            None => data.push(0),
+        }
+        let address = Pubkey::new_unique();
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm
+            .set_account(
+                address,
+                Account {
+                    lamports,
+                    data,
+                    owner: GATEWAY_PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+        address
+    }
+
+    /**
+     * Like send, with the compute limit raised to the 1.4M CU transaction
+     * ceiling; returns the units the transaction consumed
+     */
+    pub fn send_metered(&mut self, ixs: &[Instruction], extra_signers: &[&Keypair]) -> u64 {
+        let limit = ComputeBudgetInstruction::set_compute_unit_limit(MAX_TX_COMPUTE_UNITS);
+        let mut metered = vec![limit];
+        metered.extend_from_slice(ixs);
+        match self.send(&metered, extra_signers) {
+            Ok(meta) => meta.compute_units_consumed,
+            Err(failed) => panic!("{:?}\n{}", failed.err, failed.meta.logs.join("\n")),
+        }
+    }
+
+    /// Simulates `ix` signed by the authority and decodes the program's return data
+    pub fn simulate<T: AnchorDeserialize>(&mut self, ix: Instruction) -> T {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.authority.pubkey()),
+            &[&self.authority],
+            self.svm.latest_blockhash(),
+        );
+        let meta = self.svm.simulate_transaction(tx.into()).expect("simulate");
+        T::deserialize(&mut meta.return_data.data.as_slice()).expect("return data")
+    }
+
+    fn set_token_program_account(&mut self, address: Pubkey, data: Vec<u8>) {
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm
+            .set_account(
+                address,
+                Account {
+                    lamports,
+                    data,
+                    owner: spl_token::ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+}
+
+pub fn solo(wallet: Pubkey, rank: u8, score: u8) -> WinnerEntry {
+    WinnerEntry { wallet, rank, score, team: None }
+}
+
+/// Remaining accounts for a solo winner without a prize split
+pub fn solo_accounts(wallet: &Pubkey, token_account: Pubkey) -> [Pubkey; 2] {
+    [TestContext::player_profile_address(wallet), token_account]
+}
+
+/// `destinations` are appended as remaining accounts, in the order the program consumes them;
+/// the staker rewards, jackpot and streak vaults and the price update are passed once they exist
+pub fn distribute_ix(
+    ctx: &TestContext,
+    pool: &FundedPool,
+    winners: Vec<WinnerEntry>,
+    nonce: u64,
+    destinations: &[Pubkey],
+) -> Instruction {
+    relayed_distribute_ix(ctx, pool, ctx.authority.pubkey(), None, winners, nonce, destinations)
+}
+
+/// The judge enclave's signature over `winners` followed by `relayer`'s distribute_prizes
+pub fn enclave_distribute_ixs(
+    ctx: &TestContext,
+    pool: &FundedPool,
+    enclave: &Keypair,
+    relayer: &Pubkey,
+    winners: Vec<WinnerEntry>,
+    nonce: u64,
+    destinations: &[Pubkey],
+) -> [Instruction; 2] {
+    let winners_hash = solana_sdk::hash::hash(&winners.try_to_vec().unwrap()).to_bytes();
+    let message =
+        pardon_prizes::enclave_distribution_message(&pool.prize_pool, nonce, &winners_hash);
+    let instructions = Some(solana_sdk::sysvar::instructions::ID);
+    let distribute =
+        relayed_distribute_ix(ctx, pool, *relayer, instructions, winners, nonce, destinations);
+    [TestContext::ed25519_ix(enclave, &message), distribute]
+}
+
+fn relayed_distribute_ix(
+    ctx: &TestContext,
+    pool: &FundedPool,
+    authority: Pubkey,
+    instructions: Option<Pubkey>,
+    winners: Vec<WinnerEntry>,
+    nonce: u64,
+    destinations: &[Pubkey],
+) -> Instruction {
+    let mut accounts = pardon_prizes::accounts::DistributePrizes {
+        config: TestContext::config_address(),
+        prize_pool: pool.prize_pool,
+        distribution_record: TestContext::distribution_record_address(&pool.prize_pool, nonce),
+        week_stats: TestContext::week_stats_address(&pool.prize_pool),
+        global_stats: TestContext::global_stats_address(),
+        mint_stats: TestContext::mint_stats_address(&pool.mint),
+        prize_pool_token_account: pool.token_account,
+        creator_token_account: ctx.creator_token_account,
+        authority,
+        token_program: spl_token::ID,
+        system_program: system_program::ID,
+        staker_rewards_vault: ctx
+            .svm
+            .get_account(&TestContext::staker_rewards_vault_address())
+            .map(|_| TestContext::staker_rewards_vault_address()),
+        jackpot_vault: ctx
+            .svm
+            .get_account(&TestContext::jackpot_vault_address())
+            .map(|_| TestContext::jackpot_vault_address()),
+        streak_vault: ctx
+            .svm
+            .get_account(&TestContext::streak_vault_address())
+            .map(|_| TestContext::streak_vault_address()),
+        price_update: ctx
+            .svm
+            .get_account(&TestContext::price_update_address())
+            .map(|_| TestContext::price_update_address()),
+        insurance_vault: ctx
+            .svm
+            .get_account(&TestContext::insurance_vault_address())
+            .map(|_| TestContext::insurance_vault_address()),
+        winner_list: ctx
+            .svm
+            .get_account(&TestContext::winner_list_address(&pool.prize_pool))
+            .map(|_| TestContext::winner_list_address(&pool.prize_pool)),
+        instructions,
+        event_authority: TestContext::event_authority_address(),
+        program: pardon_prizes::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(destinations.iter().map(|&key| AccountMeta::new(key, false)));
+
+    Instruction {
+        program_id: pardon_prizes::ID,
+        accounts,
+        data: pardon_prizes::instruction::DistributePrizes { winners, nonce }.data(),
+    }
+}
+
+/// BN254 G1 generator (1, 2) in EIP-197 encoding
+pub const G1_GENERATOR: [u8; 64] = {
+    let mut point = [0; 64];
+    point[31] = 1;
+    point[63] = 2;
+    point
+};
+
+/// BN254 G2 generator in EIP-197 encoding
+pub const G2_GENERATOR: [u8; 128] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
+/// `scalar` times the G1 generator
+pub fn g1_mul(scalar: u64) -> [u8; 64] {
+    let mut input = G1_GENERATOR.to_vec();
+    input.extend_from_slice(&[0; 24]);
+    input.extend_from_slice(&scalar.to_be_bytes());
+    alt_bn128_multiplication(&input).unwrap().try_into().unwrap()
+}
+
+pub fn g1_add(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    alt_bn128_addition(&[&a[..], &b[..]].concat()).unwrap().try_into().unwrap()
+}
+
+/**
+ * A verifying key and a proof for `inputs` built from known discrete logs
+ * instead of a circuit: every G2 point is the generator, so the pairing
+ * check comes down to A = alpha + vk_x + C
+ */
+pub fn known_log_proof(inputs: &[[u8; 32]]) -> (ScoringVerifyingKey, ScoringProof) {
+    let key = ScoringVerifyingKey {
+        alpha_g1: g1_mul(5),
+        beta_g2: G2_GENERATOR,
+        gamma_g2: G2_GENERATOR,
+        delta_g2: G2_GENERATOR,
+        ic: std::array::from_fn(|i| g1_mul(i as u64 + 2)),
+    };
+    let c = g1_mul(7);
+    let vk_x = input_point(&key.ic, inputs).unwrap();
+    let a = g1_add(&g1_add(&key.alpha_g1, &vk_x), &c);
+    (key, ScoringProof { a, b: G2_GENERATOR, c })
+}
+
+fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(&[pardon_prizes::ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+/// Installs the program under the upgradeable loader with `upgrade_authority`,
+/// which initialize_config checks against
+fn deploy_upgradeable(svm: &mut LiteSVM, elf: &[u8], upgrade_authority: &Pubkey) {
+    let programdata_address = program_data_address();
+
+    let mut programdata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(*upgrade_authority),
+    })
+    .unwrap();
+    programdata.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+    programdata.extend_from_slice(elf);
+    let lamports = svm.minimum_balance_for_rent_exemption(programdata.len());
+    svm.set_account(
+        programdata_address,
+        Account {
+            lamports,
+            data: programdata,
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let program = bincode::serialize(&UpgradeableLoaderState::Program { programdata_address }).unwrap();
+    let lamports = svm.minimum_balance_for_rent_exemption(program.len());
+    svm.set_account(
+        pardon_prizes::ID,
+        Account {
+            lamports,
+            data: program,
+            owner: bpf_loader_upgradeable::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
@@ -113,6 +113,19 @@ else
     exit 1
 fi
 
+# Prize program suites, including the LiteSVM ones that need the SBF build
+if [ "${SKIP_PROGRAM_TESTS:-0}" != "1" ]; then
+    echo -e "${BLUE}Running prize program tests...${NC}"
+    if ! command -v anchor &> /dev/null; then
+        echo -e "${RED}ERROR: anchor is not installed${NC}"
+        echo -e "${YELLOW}Install with:${NC} ${CYAN}avm install 0.30.1 && avm use 0.30.1${NC}"
+        echo -e "${YELLOW}Or skip with:${NC} ${CYAN}SKIP_PROGRAM_TESTS=1 $0${NC}"
+        exit 1
+    fi
+    (cd pardon-prizes && anchor build && PARDON_REQUIRE_PROGRAM=1 cargo test)
+    echo -e "${GREEN}✓ Prize program tests passed${NC}"
+fi
+
 # ✅ CRITICAL FIX: Always kill orphaned agent processes to prevent duplicate registrations
 echo -e "${YELLOW}🧹 Cleaning up any orphaned agent processes...${NC}"
 pkill -9 -f "python.*agents/.*/main.py" 2>/dev/null || true
@@ -0,0 +1,16 @@
+import { NextResponse } from 'next/server';
+
+export const dynamic = 'force-dynamic';
+
+/**
+ * GET /api/healthz
+ * Liveness probe: answers as long as the server process can handle requests.
+ * Deliberately checks no dependencies, so an RPC or database outage doesn't
+ * get the pod restarted; use /api/readyz for those
+ */
+export async function GET() {
+  return NextResponse.json({
+    status: 'ok',
+    timestamp: new Date().toISOString()
+  });
+}
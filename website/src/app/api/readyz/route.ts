@@ -0,0 +1,97 @@
+import { NextResponse } from 'next/server';
+import { Keypair } from '@solana/web3.js';
+import bs58 from 'bs58';
+import { prisma } from '@/lib/prisma';
+import { createRobustConnection } from '@/lib/solana-retry';
+
+export const dynamic = 'force-dynamic';
+
+const SOLANA_RPC_URL = process.env.SOLANA_RPC_URL || '';
+const FACILITATOR_PRIVATE_KEY = process.env.FACILITATOR_SOLANA_PRIVATE_KEY;
+const CHECK_TIMEOUT_MS = 3000;
+
+interface CheckResult {
+  ok: boolean;
+  responseTime?: number;
+  error?: string;
+}
+
+async function timed(check: () => Promise<void>): Promise<CheckResult> {
+  const start = Date.now();
+  try {
+    await Promise.race([
+      check(),
+      new Promise<never>((_, reject) =>
+        setTimeout(() => reject(new Error('Check timeout')), CHECK_TIMEOUT_MS)
+      )
+    ]);
+    return { ok: true, responseTime: Date.now() - start };
+  } catch (error: any) {
+    return { ok: false, responseTime: Date.now() - start, error: error.message };
+  }
+}
+
+/** The Solana RPC answers getSlot */
+function checkRpc(): Promise<CheckResult> {
+  return timed(async () => {
+    if (!SOLANA_RPC_URL) {
+      throw new Error('SOLANA_RPC_URL not set');
+    }
+    await createRobustConnection(SOLANA_RPC_URL).getSlot();
+  });
+}
+
+/** Migrations have been applied, and none is failed or half-applied */
+function checkDatabase(): Promise<CheckResult> {
+  return timed(async () => {
+    const [row] = await prisma.$queryRaw<{ applied: bigint; unfinished: bigint }[]>`
+      SELECT
+        COUNT(*) FILTER (WHERE finished_at IS NOT NULL AND rolled_back_at IS NULL) AS applied,
+        COUNT(*) FILTER (WHERE finished_at IS NULL AND rolled_back_at IS NULL) AS unfinished
+      FROM _prisma_migrations
+    `;
+    if (Number(row.applied) === 0) {
+      throw new Error('No migrations applied');
+    }
+    if (Number(row.unfinished) > 0) {
+      throw new Error(`${row.unfinished} migration(s) failed or still running`);
+    }
+  });
+}
+
+/** The facilitator keypair that signs settlements decodes */
+async function checkKeypair(): Promise<CheckResult> {
+  try {
+    if (!FACILITATOR_PRIVATE_KEY) {
+      throw new Error('FACILITATOR_SOLANA_PRIVATE_KEY not set');
+    }
+    Keypair.fromSecretKey(bs58.decode(FACILITATOR_PRIVATE_KEY));
+    return { ok: true };
+  } catch (error: any) {
+    return { ok: false, error: error.message };
+  }
+}
+
+/**
+ * GET /api/readyz
+ * Readiness probe: 200 only once the RPC is reachable, the database is
+ * migrated and the signing keypair is loaded, 503 otherwise so the pod is
+ * taken out of rotation without being restarted
+ */
+export async function GET() {
+  const [rpc, database, keypair] = await Promise.all([
+    checkRpc(),
+    checkDatabase(),
+    checkKeypair()
+  ]);
+  const ready = rpc.ok && database.ok && keypair.ok;
+
+  return NextResponse.json(
+    {
+      status: ready ? 'ready' : 'not_ready',
+      timestamp: new Date().toISOString(),
+      checks: { rpc, database, keypair }
+    },
+    { status: ready ? 200 : 503 }
+  );
+}